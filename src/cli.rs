@@ -0,0 +1,920 @@
+// 커맨드라인 정의 모듈 (clap derive)
+// --help 문구는 메시지 카탈로그(i18n)에서 현재 언어로 채움 - 필드의 문서 주석은 코드 설명용
+//
+//   nbmon [전역 옵션] [명령] [명령 옵션]
+//   명령을 생략하면 향상된 TUI(tui)를 실행
+//   여기서 기본값을 정하지 않은 옵션은 설정 파일(config.toml)의 값 또는 화면별 기본값을 사용
+
+use clap::{error::ErrorKind, ArgAction, Args, CommandFactory, FromArgMatches, Parser, Subcommand};
+use std::ffi::OsString;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use nbmon::export::check;
+use nbmon::export::push::{BatchOptions, Endpoint};
+use nbmon::i18n::{self, Lang, Msg};
+use nbmon::network::filter::Pattern;
+use nbmon::utils::format::Scale;
+
+/// Cross-platform network bandwidth monitor (Linux nload/bmon 스타일의 실시간 트래픽 모니터)
+#[derive(Parser, Debug)]
+#[command(
+    name = "nbmon",
+    version,
+    disable_version_flag = true,
+    disable_help_flag = true,
+    disable_help_subcommand = true
+)]
+pub struct Cli {
+    #[command(flatten)]
+    pub global: GlobalArgs,
+
+    /// 명령을 생략했을 때의 TUI 옵션
+    #[command(flatten)]
+    pub tui: TuiArgs,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// 버전 정보 출력
+    #[arg(short = 'v', long = "version", id = "version", action = ArgAction::Version)]
+    _version: Option<bool>,
+
+    /// 도움말 출력 (문구를 번역하기 위해 clap의 기본 플래그 대신 직접 정의)
+    #[arg(short = 'h', long = "help", id = "help", action = ArgAction::Help, global = true)]
+    _help: Option<bool>,
+}
+
+/// 모든 명령에서 사용할 수 있는 옵션
+#[derive(Args, Debug)]
+pub struct GlobalArgs {
+    /// 대상 인터페이스 (TUI는 처음 선택, export/record/simple/check는 필터 대신 이 인터페이스만, report/query는 필터)
+    #[arg(short, long, alias = "iface", value_name = "NAME", global = true)]
+    pub interface: Option<String>,
+
+    /// 샘플링 간격 (초, 소수 허용, 기본 1)
+    #[arg(long, value_name = "SECS", value_parser = parse_interval, global = true)]
+    pub interval: Option<Duration>,
+
+    /// 인터페이스마다 보관할 원본 샘플 수 (기본: 향상된 TUI 600, 클래식 60)
+    #[arg(long, value_name = "SAMPLES", value_parser = clap::value_parser!(u64).range(1..), global = true)]
+    pub history: Option<u64>,
+
+    /// 속도 표시 단위
+    #[arg(long, value_name = "UNITS", value_parser = ["bytes", "bits"], global = true)]
+    pub units: Option<String>,
+
+    /// 단위 접두사 (si: kB/Mbit 1000 단위, iec: KiB/Mibit 1024 단위; 기본: bytes는 iec, bits는 si)
+    #[arg(long, value_name = "PREFIX", value_parser = ["si", "iec"], global = true)]
+    pub prefix: Option<String>,
+
+    /// 속도를 항상 같은 접두사로 표시 (auto, 1, k, M, G, T; 예: --units bits --scale M → 항상 Mbit/s)
+    #[arg(long, value_name = "SCALE", value_parser = parse_scale, global = true)]
+    pub scale: Option<Scale>,
+
+    /// Public IP를 외부 서비스에서 조회하지 않음
+    #[arg(long, global = true)]
+    pub no_public_ip: bool,
+
+    /// 이 패턴과 일치하는 인터페이스만 표시 (glob 또는 re:정규식, 여러 번 지정 가능)
+    #[arg(long, value_name = "PATTERN", value_parser = Pattern::from_str, global = true)]
+    pub include: Vec<Pattern>,
+
+    /// 이 패턴과 일치하는 인터페이스는 숨김 (glob 또는 re:정규식, 여러 번 지정 가능)
+    #[arg(long, value_name = "PATTERN", value_parser = Pattern::from_str, global = true)]
+    pub exclude: Vec<Pattern>,
+
+    /// 가상 인터페이스(veth, docker, VPN 등) 숨김
+    #[arg(long, global = true)]
+    pub hide_virtual: bool,
+
+    /// DOWN 상태 인터페이스도 표시
+    #[arg(long, global = true)]
+    pub show_down: bool,
+
+    /// 루프백 인터페이스도 표시
+    #[arg(long, global = true)]
+    pub show_loopback: bool,
+
+    /// 설정 파일 경로 (기본: $XDG_CONFIG_HOME/nbmon/config.toml, 없으면 기본 설정)
+    #[arg(long, value_name = "PATH", global = true)]
+    pub config: Option<PathBuf>,
+
+    /// 메시지 언어 (기본: LC_ALL, LC_MESSAGES, LANG 순으로 결정)
+    /// 도움말도 번역해야 하므로 실제 값은 해석 전에 lang_from_args로 읽음
+    #[arg(long, value_name = "LANG", value_parser = ["en", "ko"], global = true)]
+    pub lang: Option<String>,
+
+    /// 직접 샘플링하지 않고 실행 중인 데몬에 붙음 (소켓 생략 시 기본 경로, 예: --attach=/run/nbmon.sock)
+    #[arg(long, value_name = "SOCKET", num_args = 0..=1, require_equals = true, global = true)]
+    pub attach: Option<Option<PathBuf>>,
+}
+
+impl Cli {
+    /// 커맨드라인 해석 (잘못된 경우 사용법을 출력하고 종료 코드 2로 종료)
+    /// 도움말은 i18n::set_lang으로 정한 언어로 출력
+    pub fn parse_args() -> Self {
+        let command = localized_command(i18n::lang());
+        command
+            .clone()
+            .try_get_matches_from(legacy_args(std::env::args_os()))
+            .and_then(|matches| Self::from_arg_matches(&matches))
+            .and_then(Self::validated)
+            .unwrap_or_else(|e| {
+                // check 명령은 플러그인 규칙에 따라 사용법 에러도 UNKNOWN(3)으로 종료
+                if e.use_stderr() && is_check_command(command, std::env::args_os()) {
+                    let _ = e.print();
+                    std::process::exit(check::Status::Unknown.code());
+                }
+                e.exit()
+            })
+    }
+
+    // 명령 없이 쓰는 TUI 옵션(--csv)은 다른 명령 앞에 올 수 없음
+    // (전역 옵션은 명령 앞에 와도 되므로 clap의 args_conflicts_with_subcommands는 쓰지 않음)
+    fn validated(self) -> Result<Self, clap::Error> {
+        let conflict =
+            |message: String| Self::command().error(ErrorKind::ArgumentConflict, message);
+        let before_command = |option: &str, usage: &str| {
+            conflict(i18n::tf(Msg::ErrOptionBeforeCommand, &[&option, &usage]))
+        };
+        let together = |first: &str, second: &str| {
+            conflict(i18n::tf(Msg::ErrOptionsConflict, &[&first, &second]))
+        };
+
+        if self.command.is_some() && self.tui.csv.is_some() {
+            return Err(before_command("--csv", "nbmon tui --csv <FILE>"));
+        }
+        let tui = match &self.command {
+            Some(Command::Tui(args) | Command::Classic(args)) => args,
+            _ => &self.tui,
+        };
+        if tui.remote.is_some() && self.global.attach.is_some() {
+            return Err(together("--remote", "--attach"));
+        }
+        if tui.snmp.is_some() && self.global.attach.is_some() {
+            return Err(together("--snmp", "--attach"));
+        }
+        if self.command.is_some() && self.tui.remote.is_some() {
+            return Err(before_command(
+                "--remote",
+                "nbmon tui --remote <HOST[:PORT]>",
+            ));
+        }
+        if self.command.is_some() && self.tui.snmp.is_some() {
+            return Err(before_command("--snmp", "nbmon tui --snmp <HOST[:PORT]>"));
+        }
+        Ok(self)
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// 향상된 TUI - nload 스타일의 실시간 그래프 (기본)
+    Tui(TuiArgs),
+    /// 클래식 TUI - 단순한 리스트 형태의 인터페이스
+    Classic(TuiArgs),
+    /// 단순 콘솔 모드 - 인터페이스 정보와 5회 측정값 출력 후 종료
+    Simple,
+    /// 저장된 트래픽 집계를 표로 출력 (기본: 일별)
+    Report(ReportArgs),
+    /// 저장된 분 단위 속도 기록을 구간별로 집계 (기본: 지난 1시간 평균)
+    Query(QueryArgs),
+    /// 매 틱의 통계를 JSON Lines / sar 스타일 표 / CSV로 출력
+    Export(ExportArgs),
+    /// TUI 없이 집계/속도 기록 (--csv로 CSV도 기록)
+    Record(RecordArgs),
+    /// 인터페이스별 청구 주기 데이터 한도 설정/확인
+    Quota(QuotaArgs),
+    /// vnstat --json / sar -n DEV 기록을 집계에 가져오기
+    Import(ImportArgs),
+    /// Nagios/Icinga 점검 플러그인 - 한 인터페이스를 측정하고 0/1/2/3으로 종료
+    Check(CheckArgs),
+    /// HTTP로 Prometheus 메트릭 제공 (/metrics)
+    Serve(ServeArgs),
+    /// 카운터와 속도를 TCP/UDP로 InfluxDB/Telegraf 또는 Graphite에 보내기
+    Push(PushArgs),
+    /// OTLP/HTTP로 OpenTelemetry 컬렉터에 메트릭 보내기
+    Otlp(OtlpArgs),
+    /// MQTT 브로커에 속도와 누적 값 발행 (Home Assistant 자동 발견 포함)
+    Mqtt(MqttArgs),
+    /// 백그라운드에서 모든 인터페이스를 샘플링하고 Unix 소켓으로 상태 제공
+    Daemon(DaemonArgs),
+    /// 데몬과 같은 API를 TCP로 제공 (tui --remote로 원격에서 보기)
+    Agent(AgentArgs),
+    /// 설정 파일 [[hosts]]의 에이전트들을 한 표에 (가장 바쁜 인터페이스부터, Enter로 호스트 열기)
+    Dashboard(DashboardArgs),
+    /// 브라우저용 실시간 대시보드 제공 (인터페이스 선택, 다운로드/업로드 그래프, 합계 표)
+    Web(WebArgs),
+}
+
+#[derive(Args, Debug, Default)]
+pub struct TuiArgs {
+    /// 매 틱마다 인터페이스별 카운터와 속도를 CSV 파일에 추가
+    #[arg(long, value_name = "FILE")]
+    pub csv: Option<PathBuf>,
+    /// 직접 샘플링하지 않고 원격 에이전트(nbmon agent)의 인터페이스 표시 (기본 포트 9960)
+    #[arg(long, value_name = "HOST[:PORT]")]
+    pub remote: Option<String>,
+    /// 에이전트 인증에 사용할 사전 공유 키 파일 (--remote와 함께)
+    #[arg(long, value_name = "FILE", requires = "remote")]
+    pub psk_file: Option<PathBuf>,
+    /// 직접 샘플링하지 않고 SNMP 에이전트(스위치, 라우터)의 IF-MIB 포트 표시 (기본 포트 161)
+    #[arg(long, value_name = "HOST[:PORT]", conflicts_with = "remote")]
+    pub snmp: Option<String>,
+    /// SNMPv2c 커뮤니티 (기본: public)
+    #[arg(long, value_name = "COMMUNITY", requires = "snmp")]
+    pub community: Option<String>,
+    /// SNMPv3 사용자 이름 (지정하면 v2c 대신 v3 사용)
+    #[arg(
+        long,
+        value_name = "USER",
+        requires = "snmp",
+        conflicts_with = "community"
+    )]
+    pub snmp_user: Option<String>,
+    /// SNMPv3 인증 프로토콜
+    #[arg(long, value_name = "PROTOCOL", default_value = "sha", value_parser = ["sha", "sha256"])]
+    pub snmp_auth: String,
+    /// SNMPv3 인증 비밀번호 파일 (--snmp-user와 함께)
+    #[arg(long, value_name = "FILE", requires = "snmp_user")]
+    pub snmp_auth_file: Option<PathBuf>,
+    /// SNMPv3 암호화(AES-128) 비밀번호 파일 (--snmp-auth-file과 함께)
+    #[arg(long, value_name = "FILE", requires = "snmp_auth_file")]
+    pub snmp_priv_file: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+#[group(multiple = false)]
+pub struct ReportArgs {
+    /// 시간별 합계
+    #[arg(long)]
+    pub hourly: bool,
+    /// 일별 합계 (기본)
+    #[arg(long)]
+    pub daily: bool,
+    /// 월별 합계
+    #[arg(long)]
+    pub monthly: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct QueryArgs {
+    /// 시작 시각 (now, 2h 같은 기간 전, @unix, YYYY-MM-DD HH:MM, HH:MM)
+    #[arg(long, value_name = "TIME", default_value = "1h")]
+    pub since: String,
+    /// 끝 시각 (형식은 --since와 같음)
+    #[arg(long, value_name = "TIME", default_value = "now")]
+    pub until: String,
+    /// 구간마다 값을 줄이는 방법
+    #[arg(long, value_name = "AGG", default_value = "avg", value_parser = ["p95", "avg", "max", "min", "sum"])]
+    pub agg: String,
+    /// 구간 길이 (예: 5m, 1h; 생략하면 전체 범위를 한 구간으로)
+    #[arg(long, value_name = "DURATION", value_parser = parse_bucket)]
+    pub bucket: Option<i64>,
+    /// 결과를 JSON으로 출력
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct ExportArgs {
+    /// 출력 형식 (json: JSON Lines, batch: 고정 폭 표, csv: CSV 파일)
+    #[arg(long, value_name = "FORMAT", default_value = "json", value_parser = ["json", "batch", "csv"])]
+    pub format: String,
+    /// 출력 파일 (csv 형식에서 필수, 파일 끝에 추가)
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+    /// 출력 횟수 (기본: 중단할 때까지)
+    #[arg(long, value_name = "N")]
+    pub count: Option<u64>,
+    /// batch 형식의 머리글 반복 간격 (줄 수, 0이면 한 번만)
+    #[arg(long, value_name = "N", default_value_t = nbmon::export::batch::DEFAULT_HEADER_EVERY)]
+    pub header_every: usize,
+}
+
+#[derive(Args, Debug)]
+pub struct RecordArgs {
+    /// 집계와 함께 CSV 파일에도 기록
+    #[arg(long, value_name = "FILE")]
+    pub csv: Option<PathBuf>,
+    /// 기록 횟수 (기본: 중단할 때까지)
+    #[arg(long, value_name = "N")]
+    pub count: Option<u64>,
+}
+
+#[derive(Args, Debug)]
+pub struct QuotaArgs {
+    #[command(subcommand)]
+    pub action: Option<QuotaAction>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum QuotaAction {
+    /// 현재 청구 주기 사용 현황 (기본)
+    Show,
+    /// 인터페이스의 한도 설정
+    Set {
+        /// 인터페이스 이름
+        name: String,
+        /// 한도 (예: 500GB, 1TiB)
+        limit: String,
+        /// 매월 사용량이 초기화되는 날짜 (1-31)
+        #[arg(long, value_name = "DAY", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..=31))]
+        reset_day: u32,
+        /// 한도에 포함할 방향
+        #[arg(long, value_name = "DIR", default_value = "both", value_parser = ["rx", "tx", "both"])]
+        count: String,
+    },
+    /// 인터페이스의 한도 삭제
+    Clear {
+        /// 인터페이스 이름
+        name: String,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct ImportArgs {
+    /// 기록 형식
+    #[arg(value_parser = ["vnstat", "sar"])]
+    pub format: String,
+    /// 읽을 파일 (-이면 표준 입력)
+    pub source: String,
+}
+
+// 임계값은 바이트/초 또는 초당 개수로 바꿔서 보관
+#[derive(Args, Debug)]
+pub struct CheckArgs {
+    /// 평균 수신 속도가 이 값을 넘으면 WARNING (예: 800Mbit, 100MB)
+    #[arg(long, value_name = "RATE", value_parser = parse_rate)]
+    pub warn_rx: Option<f64>,
+    /// 평균 수신 속도가 이 값을 넘으면 CRITICAL
+    #[arg(long, value_name = "RATE", value_parser = parse_rate)]
+    pub crit_rx: Option<f64>,
+    /// 평균 송신 속도가 이 값을 넘으면 WARNING
+    #[arg(long, value_name = "RATE", value_parser = parse_rate)]
+    pub warn_tx: Option<f64>,
+    /// 평균 송신 속도가 이 값을 넘으면 CRITICAL
+    #[arg(long, value_name = "RATE", value_parser = parse_rate)]
+    pub crit_tx: Option<f64>,
+    /// 초당 수신+송신 에러가 이 값을 넘으면 WARNING (예: 10/s)
+    #[arg(long, value_name = "N/s", value_parser = parse_per_sec)]
+    pub warn_errors: Option<f64>,
+    /// 초당 수신+송신 에러가 이 값을 넘으면 CRITICAL
+    #[arg(long, value_name = "N/s", value_parser = parse_per_sec)]
+    pub crit_errors: Option<f64>,
+    /// 초당 수신+송신 드롭이 이 값을 넘으면 WARNING
+    #[arg(long, value_name = "N/s", value_parser = parse_per_sec)]
+    pub warn_drops: Option<f64>,
+    /// 초당 수신+송신 드롭이 이 값을 넘으면 CRITICAL
+    #[arg(long, value_name = "N/s", value_parser = parse_per_sec)]
+    pub crit_drops: Option<f64>,
+    /// 측정 시간 (예: 10s, 1m)
+    #[arg(long, value_name = "DURATION", default_value = "10s", value_parser = parse_duration_arg)]
+    pub duration: Duration,
+}
+
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    /// 수신할 주소와 포트
+    #[arg(long, value_name = "ADDR:PORT", default_value = DEFAULT_LISTEN)]
+    pub listen: SocketAddr,
+}
+
+#[derive(Args, Debug)]
+pub struct PushArgs {
+    /// 보낼 곳 (tcp://host:port 또는 udp://host:port, 스킴이 없으면 statsd/dogstatsd는 udp, 나머지는 tcp)
+    #[arg(long, value_name = "URL")]
+    pub to: Endpoint,
+    /// 줄 형식 (influx: 라인 프로토콜, graphite: 평문, statsd/dogstatsd: 게이지와 카운터)
+    #[arg(long, value_name = "FORMAT", default_value = "influx", value_parser = ["influx", "graphite", "statsd", "dogstatsd"])]
+    pub format: String,
+    /// Influx 측정 이름 / Graphite, StatsD 이름의 첫 부분
+    #[arg(long, value_name = "NAME", default_value = "nbmon")]
+    pub metric_prefix: String,
+    /// 줄이 이만큼 모이면 바로 보냄
+    #[arg(long, value_name = "N", default_value_t = BatchOptions::default().batch_size as u64, value_parser = clap::value_parser!(u64).range(1..))]
+    pub batch_size: u64,
+    /// 모인 줄을 적어도 이 간격마다 보냄
+    #[arg(long, value_name = "DURATION", default_value = "10s", value_parser = parse_duration_arg)]
+    pub flush_interval: Duration,
+    /// 보낼 틱 수 (기본: 중단할 때까지)
+    #[arg(long, value_name = "N")]
+    pub count: Option<u64>,
+}
+
+#[derive(Args, Debug)]
+pub struct OtlpArgs {
+    /// 컬렉터 주소 (경로가 없으면 /v1/metrics를 붙임)
+    #[arg(long, value_name = "URL", default_value = DEFAULT_OTLP_ENDPOINT)]
+    pub endpoint: String,
+    /// 요청에 추가할 헤더 (여러 번 지정 가능, 예: Authorization=Bearer abc)
+    #[arg(long, value_name = "NAME=VALUE", value_parser = parse_header)]
+    pub header: Vec<(String, String)>,
+    /// 메트릭을 보내는 간격 (속도는 이 구간의 평균)
+    #[arg(long, value_name = "DURATION", default_value = "10s", value_parser = parse_duration_arg)]
+    pub export_interval: Duration,
+    /// 보낼 횟수 (기본: 중단할 때까지)
+    #[arg(long, value_name = "N")]
+    pub count: Option<u64>,
+}
+
+#[derive(Args, Debug)]
+pub struct MqttArgs {
+    /// 브로커 주소 (host 또는 host:port, 기본 포트 1883)
+    #[arg(long, value_name = "HOST[:PORT]", default_value = "127.0.0.1")]
+    pub broker: String,
+    /// 토픽의 첫 부분 (<prefix>/<host>/<iface>/rx_bps)
+    #[arg(long, value_name = "PREFIX", default_value = "nbmon")]
+    pub topic_prefix: String,
+    /// MQTT 클라이언트 ID (기본: nbmon-<host>)
+    #[arg(long, value_name = "ID")]
+    pub client_id: Option<String>,
+    /// 브로커 사용자 이름
+    #[arg(long, value_name = "NAME")]
+    pub username: Option<String>,
+    /// 브로커 비밀번호가 든 파일 (--username과 함께, ps나 셸 기록에 남지 않도록 파일로 받음)
+    #[arg(long, value_name = "FILE", requires = "username")]
+    pub password_file: Option<PathBuf>,
+    /// Home Assistant 발견 토픽 접두사
+    #[arg(long, value_name = "PREFIX", default_value = "homeassistant")]
+    pub discovery_prefix: String,
+    /// Home Assistant 발견 메시지를 보내지 않음
+    #[arg(long)]
+    pub no_discovery: bool,
+    /// 발행할 틱 수 (기본: 중단할 때까지)
+    #[arg(long, value_name = "N")]
+    pub count: Option<u64>,
+}
+
+#[derive(Args, Debug)]
+pub struct DaemonArgs {
+    /// 수신할 Unix 소켓 경로 (기본: $NBMON_SOCKET, root면 /run/nbmon.sock, 아니면 $XDG_RUNTIME_DIR/nbmon.sock)
+    #[arg(long, value_name = "PATH")]
+    pub socket: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct AgentArgs {
+    /// 수신할 주소와 포트 (다른 호스트에서 보려면 --listen 0.0.0.0:9960)
+    #[arg(long, value_name = "ADDR:PORT", default_value = DEFAULT_AGENT_LISTEN)]
+    pub listen: SocketAddr,
+    /// 연결마다 이 파일의 키로 인증 (HMAC-SHA256 챌린지, 키는 전송되지 않음)
+    #[arg(long, value_name = "FILE")]
+    pub psk_file: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct DashboardArgs {
+    /// 설정 파일의 [[hosts]] 대신 표시할 에이전트 (HOST[:PORT], 여러 번 지정 가능)
+    #[arg(long, value_name = "HOST[:PORT]")]
+    pub host: Vec<String>,
+    /// --host 에이전트의 사전 공유 키 파일
+    #[arg(long, value_name = "FILE", requires = "host")]
+    pub psk_file: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct WebArgs {
+    /// 수신할 주소와 포트 (다른 사람에게 링크를 공유하려면 --listen 0.0.0.0:8080)
+    #[arg(long, value_name = "ADDR:PORT", default_value = DEFAULT_WEB_LISTEN)]
+    pub listen: SocketAddr,
+}
+
+/// agent의 기본 수신 주소
+pub const DEFAULT_AGENT_LISTEN: &str = "127.0.0.1:9960";
+
+/// otlp의 기본 컬렉터 주소 (OTLP/HTTP 표준 포트)
+pub const DEFAULT_OTLP_ENDPOINT: &str = "http://127.0.0.1:4318";
+
+/// serve의 기본 수신 주소 (외부에 노출하려면 --listen 0.0.0.0:9959)
+pub const DEFAULT_LISTEN: &str = "127.0.0.1:9959";
+
+/// web의 기본 수신 주소
+pub const DEFAULT_WEB_LISTEN: &str = "127.0.0.1:8080";
+
+// 도움말 문구 목록: (하위 명령 경로, 옵션 id 또는 None이면 명령 설명, 메시지)
+// 전역 옵션은 최상위에만 지정하면 하위 명령으로 전파됨
+const HELP_TEXTS: &[(&[&str], Option<&str>, Msg)] = &[
+    (&[], None, Msg::CliAbout),
+    (&[], Some("help"), Msg::HelpHelp),
+    (&[], Some("version"), Msg::HelpVersion),
+    (&[], Some("interface"), Msg::HelpInterface),
+    (&[], Some("interval"), Msg::HelpInterval),
+    (&[], Some("history"), Msg::HelpHistory),
+    (&[], Some("units"), Msg::HelpUnits),
+    (&[], Some("prefix"), Msg::HelpPrefix),
+    (&[], Some("scale"), Msg::HelpScale),
+    (&[], Some("no_public_ip"), Msg::HelpNoPublicIp),
+    (&[], Some("include"), Msg::HelpInclude),
+    (&[], Some("exclude"), Msg::HelpExclude),
+    (&[], Some("hide_virtual"), Msg::HelpHideVirtual),
+    (&[], Some("show_down"), Msg::HelpShowDown),
+    (&[], Some("show_loopback"), Msg::HelpShowLoopback),
+    (&[], Some("config"), Msg::HelpConfig),
+    (&[], Some("lang"), Msg::HelpLang),
+    (&[], Some("attach"), Msg::HelpAttach),
+    (&[], Some("csv"), Msg::HelpCsv),
+    (&[], Some("remote"), Msg::HelpRemote),
+    (&[], Some("psk_file"), Msg::HelpRemotePsk),
+    (&[], Some("snmp"), Msg::HelpSnmp),
+    (&[], Some("community"), Msg::HelpSnmpCommunity),
+    (&[], Some("snmp_user"), Msg::HelpSnmpUser),
+    (&[], Some("snmp_auth"), Msg::HelpSnmpAuth),
+    (&[], Some("snmp_auth_file"), Msg::HelpSnmpAuthFile),
+    (&[], Some("snmp_priv_file"), Msg::HelpSnmpPrivFile),
+    (&["tui"], None, Msg::CmdTui),
+    (&["tui"], Some("csv"), Msg::HelpCsv),
+    (&["tui"], Some("remote"), Msg::HelpRemote),
+    (&["tui"], Some("psk_file"), Msg::HelpRemotePsk),
+    (&["tui"], Some("snmp"), Msg::HelpSnmp),
+    (&["tui"], Some("community"), Msg::HelpSnmpCommunity),
+    (&["tui"], Some("snmp_user"), Msg::HelpSnmpUser),
+    (&["tui"], Some("snmp_auth"), Msg::HelpSnmpAuth),
+    (&["tui"], Some("snmp_auth_file"), Msg::HelpSnmpAuthFile),
+    (&["tui"], Some("snmp_priv_file"), Msg::HelpSnmpPrivFile),
+    (&["classic"], None, Msg::CmdClassic),
+    (&["classic"], Some("csv"), Msg::HelpCsv),
+    (&["classic"], Some("remote"), Msg::HelpRemote),
+    (&["classic"], Some("psk_file"), Msg::HelpRemotePsk),
+    (&["classic"], Some("snmp"), Msg::HelpSnmp),
+    (&["classic"], Some("community"), Msg::HelpSnmpCommunity),
+    (&["classic"], Some("snmp_user"), Msg::HelpSnmpUser),
+    (&["classic"], Some("snmp_auth"), Msg::HelpSnmpAuth),
+    (&["classic"], Some("snmp_auth_file"), Msg::HelpSnmpAuthFile),
+    (&["classic"], Some("snmp_priv_file"), Msg::HelpSnmpPrivFile),
+    (&["simple"], None, Msg::CmdSimple),
+    (&["report"], None, Msg::CmdReport),
+    (&["report"], Some("hourly"), Msg::HelpHourly),
+    (&["report"], Some("daily"), Msg::HelpDaily),
+    (&["report"], Some("monthly"), Msg::HelpMonthly),
+    (&["query"], None, Msg::CmdQuery),
+    (&["query"], Some("since"), Msg::HelpSince),
+    (&["query"], Some("until"), Msg::HelpUntil),
+    (&["query"], Some("agg"), Msg::HelpAgg),
+    (&["query"], Some("bucket"), Msg::HelpBucket),
+    (&["query"], Some("json"), Msg::HelpJson),
+    (&["export"], None, Msg::CmdExport),
+    (&["export"], Some("format"), Msg::HelpFormat),
+    (&["export"], Some("output"), Msg::HelpOutput),
+    (&["export"], Some("count"), Msg::HelpExportCount),
+    (&["export"], Some("header_every"), Msg::HelpHeaderEvery),
+    (&["record"], None, Msg::CmdRecord),
+    (&["record"], Some("csv"), Msg::HelpRecordCsv),
+    (&["record"], Some("count"), Msg::HelpRecordCount),
+    (&["quota"], None, Msg::CmdQuota),
+    (&["quota", "show"], None, Msg::CmdQuotaShow),
+    (&["quota", "set"], None, Msg::CmdQuotaSet),
+    (&["quota", "set"], Some("name"), Msg::HelpQuotaName),
+    (&["quota", "set"], Some("limit"), Msg::HelpQuotaLimit),
+    (&["quota", "set"], Some("reset_day"), Msg::HelpResetDay),
+    (&["quota", "set"], Some("count"), Msg::HelpQuotaCount),
+    (&["quota", "clear"], None, Msg::CmdQuotaClear),
+    (&["quota", "clear"], Some("name"), Msg::HelpQuotaName),
+    (&["import"], None, Msg::CmdImport),
+    (&["import"], Some("format"), Msg::HelpImportFormat),
+    (&["import"], Some("source"), Msg::HelpImportSource),
+    (&["check"], None, Msg::CmdCheck),
+    (&["check"], Some("warn_rx"), Msg::HelpWarnRx),
+    (&["check"], Some("crit_rx"), Msg::HelpCritRx),
+    (&["check"], Some("warn_tx"), Msg::HelpWarnTx),
+    (&["check"], Some("crit_tx"), Msg::HelpCritTx),
+    (&["check"], Some("warn_errors"), Msg::HelpWarnErrors),
+    (&["check"], Some("crit_errors"), Msg::HelpCritErrors),
+    (&["check"], Some("warn_drops"), Msg::HelpWarnDrops),
+    (&["check"], Some("crit_drops"), Msg::HelpCritDrops),
+    (&["check"], Some("duration"), Msg::HelpDuration),
+    (&["serve"], None, Msg::CmdServe),
+    (&["serve"], Some("listen"), Msg::HelpListen),
+    (&["push"], None, Msg::CmdPush),
+    (&["push"], Some("to"), Msg::HelpPushTo),
+    (&["push"], Some("format"), Msg::HelpPushFormat),
+    (&["push"], Some("metric_prefix"), Msg::HelpMetricPrefix),
+    (&["push"], Some("batch_size"), Msg::HelpBatchSize),
+    (&["push"], Some("flush_interval"), Msg::HelpFlushInterval),
+    (&["push"], Some("count"), Msg::HelpPushCount),
+    (&["otlp"], None, Msg::CmdOtlp),
+    (&["otlp"], Some("endpoint"), Msg::HelpOtlpEndpoint),
+    (&["otlp"], Some("header"), Msg::HelpOtlpHeader),
+    (&["otlp"], Some("export_interval"), Msg::HelpExportInterval),
+    (&["otlp"], Some("count"), Msg::HelpOtlpCount),
+    (&["mqtt"], None, Msg::CmdMqtt),
+    (&["mqtt"], Some("broker"), Msg::HelpBroker),
+    (&["mqtt"], Some("topic_prefix"), Msg::HelpTopicPrefix),
+    (&["mqtt"], Some("client_id"), Msg::HelpClientId),
+    (&["mqtt"], Some("username"), Msg::HelpMqttUsername),
+    (&["mqtt"], Some("password_file"), Msg::HelpMqttPassword),
+    (
+        &["mqtt"],
+        Some("discovery_prefix"),
+        Msg::HelpDiscoveryPrefix,
+    ),
+    (&["mqtt"], Some("no_discovery"), Msg::HelpNoDiscovery),
+    (&["mqtt"], Some("count"), Msg::HelpMqttCount),
+    (&["daemon"], None, Msg::CmdDaemon),
+    (&["daemon"], Some("socket"), Msg::HelpSocket),
+    (&["agent"], None, Msg::CmdAgent),
+    (&["agent"], Some("listen"), Msg::HelpAgentListen),
+    (&["agent"], Some("psk_file"), Msg::HelpAgentPsk),
+    (&["dashboard"], None, Msg::CmdDashboard),
+    (&["dashboard"], Some("host"), Msg::HelpDashboardHost),
+    (&["dashboard"], Some("psk_file"), Msg::HelpDashboardPsk),
+    (&["web"], None, Msg::CmdWeb),
+    (&["web"], Some("listen"), Msg::HelpWebListen),
+];
+
+// 도움말 문구를 지정한 언어로 바꾼 clap 명령 정의
+fn localized_command(lang: Lang) -> clap::Command {
+    let command = Cli::command().after_help(i18n::text(lang, Msg::CliAfterHelp));
+    HELP_TEXTS
+        .iter()
+        .fold(command, |command, &(path, arg, msg)| {
+            set_help(command, path, arg, i18n::text(lang, msg))
+        })
+}
+
+// 하위 명령 경로를 따라 내려가서 명령 설명 또는 옵션 도움말을 바꿈
+fn set_help(
+    command: clap::Command,
+    path: &[&str],
+    arg: Option<&'static str>,
+    text: &'static str,
+) -> clap::Command {
+    match (path.split_first(), arg) {
+        (Some((name, rest)), _) => {
+            command.mut_subcommand(*name, |sub| set_help(sub, rest, arg, text))
+        }
+        (None, Some(id)) => command.mut_arg(id, |a| a.help(text)),
+        (None, None) => command.about(text),
+    }
+}
+
+// 에러를 무시하고 해석했을 때 check 명령인지
+/// nbmon check 명령인지 (언어를 정하기 전이므로 지역화하지 않은 정의로 확인)
+pub fn is_check(args: impl IntoIterator<Item = OsString>) -> bool {
+    is_check_command(Cli::command(), legacy_args(args))
+}
+
+fn is_check_command(command: clap::Command, args: impl IntoIterator<Item = OsString>) -> bool {
+    command
+        .ignore_errors(true)
+        .try_get_matches_from(args)
+        .is_ok_and(|matches| matches.subcommand_name() == Some("check"))
+}
+
+// 하위 명령이 생기기 전의 표기와 그에 해당하는 하위 명령 (도움말에는 나오지 않는 별칭)
+const LEGACY_FLAGS: &[(&str, &[&str])] = &[
+    ("--simple", &["simple"]),
+    ("--classic", &["classic"]),
+    ("--json", &["export", "--format", "json"]),
+    ("--batch", &["export", "--format", "batch"]),
+];
+
+/// 예전 표기(nbmon --simple, --classic, --json, --batch)를 하위 명령으로 바꾼 인자 목록
+/// 하위 명령보다 먼저 나온 첫 번째 예전 플래그만 바꾸고, 하위 명령은 프로그램 이름 바로 뒤로 옮김
+/// (나머지 옵션은 모두 하위 명령 뒤에 와도 되므로 nbmon --interval 2 --json --count 5도 그대로 동작)
+pub fn legacy_args(args: impl IntoIterator<Item = OsString>) -> Vec<OsString> {
+    let mut args: Vec<OsString> = args.into_iter().collect();
+    let command = Cli::command();
+    let is_subcommand = |arg: &OsString| {
+        command
+            .get_subcommands()
+            .any(|sub| arg.to_str() == Some(sub.get_name()))
+    };
+
+    let mut found = None;
+    for (position, arg) in args.iter().enumerate().skip(1) {
+        if arg == "--" || is_subcommand(arg) {
+            break;
+        }
+        if let Some(&(_, replacement)) = LEGACY_FLAGS.iter().find(|(flag, _)| arg == flag) {
+            found = Some((position, replacement));
+            break;
+        }
+    }
+
+    if let Some((position, replacement)) = found {
+        args.remove(position);
+        let insert_at = 1.min(args.len());
+        args.splice(insert_at..insert_at, replacement.iter().map(OsString::from));
+    }
+    args
+}
+
+/// 커맨드라인에서 --lang 값만 미리 읽음 (도움말을 출력하기 전에 언어를 정해야 하므로)
+/// 잘못된 값은 None으로 두고 이후 clap 해석에서 사용법 에러로 보고됨
+pub fn lang_from_args(args: impl IntoIterator<Item = OsString>) -> Option<Lang> {
+    let mut args = args
+        .into_iter()
+        .map(|arg| arg.to_string_lossy().into_owned());
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            break;
+        }
+        let value = match arg.strip_prefix("--lang") {
+            Some("") => args.next(),
+            Some(rest) if rest.starts_with('=') => Some(rest[1..].to_string()),
+            _ => continue,
+        };
+        return value.as_deref().and_then(Lang::parse);
+    }
+    None
+}
+
+// --interval 값 (초, 소수 허용)
+fn parse_interval(value: &str) -> Result<Duration, String> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|secs| secs.is_finite() && *secs > 0.0)
+        .map(Duration::from_secs_f64)
+        .ok_or_else(|| "expected seconds greater than 0 (e.g. 1 or 0.5)".to_string())
+}
+
+// --scale 값
+fn parse_scale(value: &str) -> Result<Scale, String> {
+    Scale::parse(value).ok_or_else(|| "expected auto, 1, k, M, G or T".to_string())
+}
+
+// check 임계값 속도 (예: 800Mbit, 100MB/s)
+fn parse_rate(value: &str) -> Result<f64, String> {
+    nbmon::utils::format::parse_rate(value)
+        .ok_or_else(|| "expected a rate such as 800Mbit, 1Gbps or 100MB".to_string())
+}
+
+// check 임계값 초당 개수 (예: 10/s, 0.5)
+fn parse_per_sec(value: &str) -> Result<f64, String> {
+    value
+        .strip_suffix("/s")
+        .unwrap_or(value)
+        .parse::<f64>()
+        .ok()
+        .filter(|count| count.is_finite() && *count >= 0.0)
+        .ok_or_else(|| "expected a count per second such as 10/s".to_string())
+}
+
+// 초 단위 기간 (최소 1초; check --duration, push --flush-interval)
+fn parse_duration_arg(value: &str) -> Result<Duration, String> {
+    nbmon::utils::time::parse_duration(value)
+        .filter(|secs| *secs >= 1)
+        .map(|secs| Duration::from_secs(secs as u64))
+        .ok_or_else(|| "expected at least 1s (e.g. 10s, 1m)".to_string())
+}
+
+// --header 값 (NAME=VALUE)
+fn parse_header(value: &str) -> Result<(String, String), String> {
+    value
+        .split_once('=')
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .filter(|(name, _)| !name.is_empty())
+        .ok_or_else(|| "expected NAME=VALUE".to_string())
+}
+
+// --bucket 값 (최소 1분)
+fn parse_bucket(value: &str) -> Result<i64, String> {
+    nbmon::utils::time::parse_duration(value)
+        .filter(|secs| *secs >= 60)
+        .ok_or_else(|| "expected at least 1m (e.g. 5m, 1h)".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    // 모든 옵션과 명령의 도움말이 번역되었는지 (영어 도움말에 한글 문서 주석이 남지 않음)
+    #[test]
+    fn test_help_is_localized() {
+        fn check(command: &clap::Command) {
+            let is_korean = |text: String| text.chars().any(|c| ('가'..='힣').contains(&c));
+            let about = command.get_about().map(|s| s.to_string());
+            assert!(
+                !about.is_some_and(is_korean),
+                "{} about",
+                command.get_name()
+            );
+            for arg in command.get_arguments() {
+                let help = arg.get_help().map(|s| s.to_string());
+                assert!(
+                    !help.is_some_and(is_korean),
+                    "{} {}",
+                    command.get_name(),
+                    arg.get_id()
+                );
+            }
+            command.get_subcommands().for_each(check);
+        }
+
+        let mut command = localized_command(Lang::En);
+        command.build();
+        check(&command);
+        assert!(command
+            .render_help()
+            .to_string()
+            .contains("Keyboard shortcuts"));
+    }
+
+    #[test]
+    fn test_lang_from_args() {
+        let args = |list: &[&str]| list.iter().map(OsString::from).collect::<Vec<_>>();
+        assert_eq!(
+            lang_from_args(args(&["nbmon", "--lang", "ko"])),
+            Some(Lang::Ko)
+        );
+        assert_eq!(
+            lang_from_args(args(&["nbmon", "report", "--lang=en"])),
+            Some(Lang::En)
+        );
+        assert_eq!(lang_from_args(args(&["nbmon", "--", "--lang", "ko"])), None);
+        assert_eq!(lang_from_args(args(&["nbmon"])), None);
+    }
+
+    #[test]
+    fn test_global_options_after_subcommand() {
+        let cli =
+            Cli::try_parse_from(["nbmon", "export", "--format", "batch", "-i", "eth0"]).unwrap();
+        assert_eq!(cli.global.interface.as_deref(), Some("eth0"));
+        assert!(matches!(cli.command, Some(Command::Export(_))));
+
+        assert!(Cli::try_parse_from(["nbmon", "--bogus"]).is_err());
+        assert!(Cli::try_parse_from(["nbmon", "--exclude", "re:("]).is_err());
+        let cli =
+            Cli::try_parse_from(["nbmon", "--exclude", "cali*", "--exclude", "re:^veth"]).unwrap();
+        assert_eq!(cli.global.exclude.len(), 2);
+        assert!(Cli::try_parse_from(["nbmon", "--config", "a.toml", "report"]).is_ok());
+        assert!(Cli::try_parse_from(["nbmon", "--csv", "a.csv", "simple"])
+            .unwrap()
+            .validated()
+            .is_err());
+    }
+
+    #[test]
+    fn test_legacy_flags() {
+        let parse = |list: &[&str]| {
+            Cli::try_parse_from(legacy_args(list.iter().map(OsString::from))).unwrap()
+        };
+        assert!(matches!(
+            parse(&["nbmon", "--simple"]).command,
+            Some(Command::Simple)
+        ));
+        let cli = parse(&["nbmon", "-i", "eth0", "--classic", "--csv", "a.csv"]);
+        assert_eq!(cli.global.interface.as_deref(), Some("eth0"));
+        let Some(Command::Classic(args)) = cli.command else {
+            panic!("expected classic command");
+        };
+        assert!(args.csv.is_some());
+
+        for (flag, format) in [("--json", "json"), ("--batch", "batch")] {
+            let cli = parse(&["nbmon", "--interval", "2", flag, "--count", "5"]);
+            assert_eq!(cli.global.interval, Some(Duration::from_secs(2)));
+            let Some(Command::Export(args)) = cli.command else {
+                panic!("expected export command for {}", flag);
+            };
+            assert_eq!(args.format, format);
+            assert_eq!(args.count, Some(5));
+        }
+
+        // 하위 명령 자신의 --json은 그대로 (query --json)
+        assert!(matches!(
+            parse(&["nbmon", "query", "--json"]).command,
+            Some(Command::Query(_))
+        ));
+    }
+
+    #[test]
+    fn test_check_arguments() {
+        let cli = Cli::try_parse_from([
+            "nbmon",
+            "check",
+            "--iface",
+            "eth0",
+            "--warn-rx",
+            "800Mbit",
+            "--crit-errors",
+            "10/s",
+        ])
+        .unwrap();
+        let Some(Command::Check(args)) = cli.command else {
+            panic!("expected check command");
+        };
+        assert_eq!(args.warn_rx, Some(1e8));
+        assert_eq!(args.crit_errors, Some(10.0));
+        assert_eq!(args.duration, Duration::from_secs(10));
+
+        // 잘못된 값이 있어도 check 명령인지 알아내서 UNKNOWN으로 종료할 수 있음
+        let args = |list: &[&str]| list.iter().map(OsString::from).collect::<Vec<_>>();
+        let bad = args(&["nbmon", "-i", "eth0", "check", "--warn-rx", "fast"]);
+        assert!(Cli::try_parse_from(&bad).is_err());
+        assert!(is_check_command(Cli::command(), bad));
+        assert!(!is_check_command(
+            Cli::command(),
+            args(&["nbmon", "report", "--bogus"])
+        ));
+    }
+}
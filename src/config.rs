@@ -0,0 +1,465 @@
+// 설정 파일 모듈 ($XDG_CONFIG_HOME/nbmon/config.toml)
+// 커맨드라인 옵션의 기본값을 파일로 지정 - 같은 항목을 커맨드라인에서 주면 커맨드라인이 우선
+// 알 수 없는 키나 잘못된 값은 무시하지 않고 줄 번호와 함께 AppError::Configuration으로 보고
+//
+// 예:
+//   interval = 0.5
+//   history = 1200
+//   interface = "eth0"
+//   units = "bits"
+//   prefix = "si"
+//   scale = "M"
+//
+//   [filters]
+//   exclude = ["veth*", "re:^cali"]
+//   show_down = true
+//
+//   [colors]
+//   download = "lightgreen"
+//   upload = "#ff8800"
+//
+//   [keys]
+//   quit = "x"
+//   refresh = "space"
+//
+//   [public_ip]
+//   enabled = false
+//
+//   [[hosts]]
+//   name = "router"
+//   address = "10.0.0.1"
+//   psk_file = "/etc/nbmon/router.psk"
+
+use anyhow::{Context, Result};
+use ratatui::style::Color;
+use serde::{de, Deserialize, Deserializer};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::error::AppError;
+use crate::i18n::{tf, Msg};
+use crate::network::filter::InterfaceFilter;
+use crate::ui::MonitorOptions;
+use crate::utils::format::{Prefix, Scale, Units};
+
+/// 설정 디렉터리 안의 설정 파일 이름
+pub const CONFIG_FILE: &str = "config.toml";
+
+/// 설정 파일 전체
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// 통계 업데이트 간격 (초, 소수 허용)
+    #[serde(deserialize_with = "deserialize_interval")]
+    pub interval: Option<Duration>,
+    /// 인터페이스마다 보관할 원본 샘플 수
+    #[serde(deserialize_with = "deserialize_history")]
+    pub history: Option<usize>,
+    /// 처음 선택할 인터페이스
+    pub interface: Option<String>,
+    /// 속도 표시 단위
+    pub units: Option<Units>,
+    /// 단위 접두사 (si 또는 iec)
+    pub prefix: Option<Prefix>,
+    /// 속도 표시 배율 (auto 또는 고정 접두사)
+    pub scale: Option<Scale>,
+    pub filters: InterfaceFilter,
+    pub colors: Colors,
+    pub keys: KeyBindings,
+    pub public_ip: PublicIpConfig,
+    /// nbmon dashboard가 연결할 에이전트 목록
+    pub hosts: Vec<HostConfig>,
+}
+
+/// 화면 색상 ([colors]) - 이름(green), 인덱스(0-255) 또는 #rrggbb
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Colors {
+    #[serde(deserialize_with = "deserialize_color")]
+    pub download: Color,
+    #[serde(deserialize_with = "deserialize_color")]
+    pub upload: Color,
+    /// 인터페이스 이름과 제목
+    #[serde(deserialize_with = "deserialize_color")]
+    pub title: Color,
+    /// "MAC:", "IP:" 같은 항목 이름
+    #[serde(deserialize_with = "deserialize_color")]
+    pub label: Color,
+}
+
+impl Default for Colors {
+    fn default() -> Self {
+        Self {
+            download: Color::Green,
+            upload: Color::Red,
+            title: Color::Cyan,
+            label: Color::Yellow,
+        }
+    }
+}
+
+/// 단축키 ([keys]) - 한 글자 또는 "space"
+/// 화살표 키는 항상 이전/다음 인터페이스로 동작
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBindings {
+    pub quit: char,
+    pub next_interface: char,
+    pub previous_interface: char,
+    pub refresh: char,
+    pub reset_history: char,
+    pub zoom_in: char,
+    pub zoom_out: char,
+    pub toggle_patterns: char,
+    pub toggle_virtual: char,
+    pub toggle_down: char,
+    pub toggle_loopback: char,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            quit: 'q',
+            next_interface: 'l',
+            previous_interface: 'h',
+            refresh: ' ',
+            reset_history: 'r',
+            zoom_in: '+',
+            zoom_out: '-',
+            toggle_patterns: 'f',
+            toggle_virtual: 'v',
+            toggle_down: 'd',
+            toggle_loopback: 'o',
+        }
+    }
+}
+
+impl KeyBindings {
+    /// 도움말에 표시할 키 이름
+    pub fn label(key: char) -> String {
+        match key {
+            ' ' => "Space".to_string(),
+            key => key.to_string(),
+        }
+    }
+
+    /// 필터 토글 키 안내 (예: "f/v/d/o: patterns/virtual/down/loopback")
+    pub fn filter_hint(&self) -> String {
+        let keys = format!(
+            "{}/{}/{}/{}",
+            Self::label(self.toggle_patterns),
+            Self::label(self.toggle_virtual),
+            Self::label(self.toggle_down),
+            Self::label(self.toggle_loopback)
+        );
+        tf(Msg::FilterHint, &[&keys])
+    }
+
+    // (동작 이름, 키) 목록 - 중복 검사용
+    fn actions(&self) -> [(&'static str, char); 11] {
+        [
+            ("quit", self.quit),
+            ("next_interface", self.next_interface),
+            ("previous_interface", self.previous_interface),
+            ("refresh", self.refresh),
+            ("reset_history", self.reset_history),
+            ("zoom_in", self.zoom_in),
+            ("zoom_out", self.zoom_out),
+            ("toggle_patterns", self.toggle_patterns),
+            ("toggle_virtual", self.toggle_virtual),
+            ("toggle_down", self.toggle_down),
+            ("toggle_loopback", self.toggle_loopback),
+        ]
+    }
+}
+
+// [keys] 표는 모든 키를 읽은 뒤 한 키가 두 동작에 지정되지 않았는지 확인
+impl<'de> Deserialize<'de> for KeyBindings {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(default, deny_unknown_fields)]
+        struct RawKeys {
+            #[serde(deserialize_with = "deserialize_key")]
+            quit: char,
+            #[serde(deserialize_with = "deserialize_key")]
+            next_interface: char,
+            #[serde(deserialize_with = "deserialize_key")]
+            previous_interface: char,
+            #[serde(deserialize_with = "deserialize_key")]
+            refresh: char,
+            #[serde(deserialize_with = "deserialize_key")]
+            reset_history: char,
+            #[serde(deserialize_with = "deserialize_key")]
+            zoom_in: char,
+            #[serde(deserialize_with = "deserialize_key")]
+            zoom_out: char,
+            #[serde(deserialize_with = "deserialize_key")]
+            toggle_patterns: char,
+            #[serde(deserialize_with = "deserialize_key")]
+            toggle_virtual: char,
+            #[serde(deserialize_with = "deserialize_key")]
+            toggle_down: char,
+            #[serde(deserialize_with = "deserialize_key")]
+            toggle_loopback: char,
+        }
+
+        impl Default for RawKeys {
+            fn default() -> Self {
+                let keys = KeyBindings::default();
+                Self {
+                    quit: keys.quit,
+                    next_interface: keys.next_interface,
+                    previous_interface: keys.previous_interface,
+                    refresh: keys.refresh,
+                    reset_history: keys.reset_history,
+                    zoom_in: keys.zoom_in,
+                    zoom_out: keys.zoom_out,
+                    toggle_patterns: keys.toggle_patterns,
+                    toggle_virtual: keys.toggle_virtual,
+                    toggle_down: keys.toggle_down,
+                    toggle_loopback: keys.toggle_loopback,
+                }
+            }
+        }
+
+        let raw = RawKeys::deserialize(deserializer)?;
+        let keys = KeyBindings {
+            quit: raw.quit,
+            next_interface: raw.next_interface,
+            previous_interface: raw.previous_interface,
+            refresh: raw.refresh,
+            reset_history: raw.reset_history,
+            zoom_in: raw.zoom_in,
+            zoom_out: raw.zoom_out,
+            toggle_patterns: raw.toggle_patterns,
+            toggle_virtual: raw.toggle_virtual,
+            toggle_down: raw.toggle_down,
+            toggle_loopback: raw.toggle_loopback,
+        };
+
+        let actions = keys.actions();
+        for (i, (action, key)) in actions.iter().enumerate() {
+            if let Some((other, _)) = actions[i + 1..].iter().find(|(_, k)| k == key) {
+                return Err(de::Error::custom(format!(
+                    "key `{}` is bound to both {} and {}",
+                    KeyBindings::label(*key),
+                    action,
+                    other
+                )));
+            }
+        }
+        Ok(keys)
+    }
+}
+
+/// Public IP 조회 설정 ([public_ip])
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PublicIpConfig {
+    /// 외부 서비스로 Public IP를 조회할지 여부
+    pub enabled: bool,
+    /// 다시 조회하는 간격 (초)
+    #[serde(deserialize_with = "deserialize_refresh_secs")]
+    pub refresh_secs: u64,
+}
+
+impl Default for PublicIpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            refresh_secs: 300,
+        }
+    }
+}
+
+/// 대시보드에 표시할 원격 에이전트 ([[hosts]])
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HostConfig {
+    /// 표시 이름 (기본: 주소)
+    pub name: Option<String>,
+    /// host 또는 host:port (포트를 생략하면 에이전트 기본 포트)
+    pub address: String,
+    /// 에이전트가 --psk-file로 인증을 요구할 때 같은 키 파일
+    pub psk_file: Option<PathBuf>,
+}
+
+impl HostConfig {
+    /// 주소만으로 만든 항목 (dashboard --host)
+    pub fn new(address: &str) -> Self {
+        Self {
+            name: None,
+            address: address.to_string(),
+            psk_file: None,
+        }
+    }
+
+    /// 화면에 표시할 이름
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.address)
+    }
+}
+
+impl Config {
+    /// 기본 설정 파일 경로
+    /// 우선순위: $XDG_CONFIG_HOME/nbmon → ~/.config/nbmon (Windows: %APPDATA%\nbmon)
+    pub fn default_path() -> Result<PathBuf> {
+        if let Some(dir) = std::env::var_os("XDG_CONFIG_HOME").filter(|d| !d.is_empty()) {
+            return Ok(PathBuf::from(dir).join("nbmon").join(CONFIG_FILE));
+        }
+
+        #[cfg(windows)]
+        let base = std::env::var_os("APPDATA").map(PathBuf::from);
+
+        #[cfg(not(windows))]
+        let base = std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config"));
+
+        base.map(|dir| dir.join("nbmon").join(CONFIG_FILE))
+            .context("Cannot determine the config directory (set XDG_CONFIG_HOME)")
+    }
+
+    /// 설정 파일 읽기 (파일이 반드시 있어야 함)
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+        Ok(Self::parse(&text, &path.display().to_string())?)
+    }
+
+    /// 기본 경로의 설정 파일 읽기 (파일이 없으면 기본 설정)
+    pub fn load_default() -> Result<Self> {
+        let Ok(path) = Self::default_path() else {
+            return Ok(Self::default());
+        };
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Self::load(&path)
+    }
+
+    /// TOML 문자열 해석 - 에러 메시지에 "<origin>:<줄>: " 위치를 붙임
+    pub fn parse(text: &str, origin: &str) -> Result<Self, AppError> {
+        toml::from_str(text).map_err(|e| {
+            let message = e.message().trim_end();
+            let location = match e.span() {
+                // 1부터 시작하는 줄 번호
+                Some(span) => format!("{}:{}", origin, line_of(text, span.start)),
+                None => origin.to_string(),
+            };
+            AppError::Configuration(format!("{}: {}", location, message))
+        })
+    }
+
+    /// 설정 파일의 값으로 채운 실행 옵션 (커맨드라인 옵션은 이후에 덮어씀)
+    pub fn monitor_options(&self) -> MonitorOptions {
+        let defaults = MonitorOptions::default();
+        MonitorOptions {
+            interval: self.interval.unwrap_or(defaults.interval),
+            history: self.history,
+            interface: self.interface.clone(),
+            filters: self.filters.clone(),
+            colors: self.colors.clone(),
+            keys: self.keys,
+            attach: None,
+        }
+    }
+}
+
+// 바이트 위치가 속한 줄 번호 (1부터)
+fn line_of(text: &str, offset: usize) -> usize {
+    text[..offset.min(text.len())].matches('\n').count() + 1
+}
+
+// interval = 0.5 (0보다 큰 초)
+fn deserialize_interval<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Duration>, D::Error> {
+    let secs = f64::deserialize(deserializer)?;
+    if !(secs.is_finite() && secs > 0.0) {
+        return Err(de::Error::custom(format!(
+            "interval must be greater than 0 seconds, got {}",
+            secs
+        )));
+    }
+    Ok(Some(Duration::from_secs_f64(secs)))
+}
+
+// history = 600 (1 이상)
+fn deserialize_history<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<usize>, D::Error> {
+    match usize::deserialize(deserializer)? {
+        0 => Err(de::Error::custom("history must be at least 1 sample")),
+        samples => Ok(Some(samples)),
+    }
+}
+
+// refresh_secs = 300 (1 이상)
+fn deserialize_refresh_secs<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    match u64::deserialize(deserializer)? {
+        0 => Err(de::Error::custom("refresh_secs must be at least 1")),
+        secs => Ok(secs),
+    }
+}
+
+// 색상 이름, 인덱스 또는 #rrggbb
+fn deserialize_color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    value.parse().map_err(|_| {
+        de::Error::custom(format!(
+            "invalid colour `{}` (use a name like green, an index 0-255 or #rrggbb)",
+            value
+        ))
+    })
+}
+
+// 한 글자 또는 "space"
+fn deserialize_key<'de, D: Deserializer<'de>>(deserializer: D) -> Result<char, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    if value.eq_ignore_ascii_case("space") {
+        return Ok(' ');
+    }
+
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(key), None) if !key.is_control() => Ok(key),
+        _ => Err(de::Error::custom(format!(
+            "invalid key `{}` (use a single character or \"space\")",
+            value
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_full_config() {
+        let config = Config::parse(
+            "interval = 0.5\nunits = \"bits\"\nscale = \"M\"\n\n[colors]\nupload = \"#ff8800\"\n\n[keys]\nrefresh = \"space\"\nquit = \"x\"\n\n[[hosts]]\nname = \"router\"\naddress = \"10.0.0.1\"\n\n[[hosts]]\naddress = \"nas:7000\"\n",
+            "test",
+        )
+        .unwrap();
+        assert_eq!(config.interval, Some(Duration::from_millis(500)));
+        assert_eq!(config.units, Some(Units::Bits));
+        assert_eq!(config.scale, Some(Scale::Fixed(2)));
+        assert_eq!(config.prefix, None);
+        assert_eq!(config.colors.upload, Color::Rgb(0xff, 0x88, 0x00));
+        assert_eq!(config.colors.download, Color::Green);
+        assert_eq!(config.keys.quit, 'x');
+        assert!(config.public_ip.enabled);
+        assert_eq!(config.hosts.len(), 2);
+        assert_eq!(config.hosts[0].display_name(), "router");
+        assert_eq!(config.hosts[1], HostConfig::new("nas:7000"));
+    }
+
+    #[test]
+    fn test_errors_report_line_numbers() {
+        let error = |text: &str| Config::parse(text, "config.toml").unwrap_err().to_string();
+
+        assert!(error("interval = 1\nintervl = 2\n").contains("config.toml:2:"));
+        assert!(error("\n\ninterval = 0\n").contains("config.toml:3:"));
+        assert!(error("[colors]\ndownload = \"greenish\"\n").contains("config.toml:2:"));
+        assert!(error("[keys]\nquit = \"r\"\n").contains("bound to both"));
+        assert!(error("[filters]\nexclude = [\"re:(\"]\n").contains("config.toml:2:"));
+    }
+}
@@ -0,0 +1,158 @@
+// 원격 에이전트 (nbmon agent) - 데몬과 같은 요청/응답을 TCP로 제공
+// 연결하면 에이전트가 먼저 hello를 보내고, 사전 공유 키(PSK)가 설정되어 있으면 인증을 요구
+//
+//   ← {"type":"hello","version":1,"nonce":"9f2c..."}
+//   → {"cmd":"auth","mac":"<hex(HMAC-SHA256(PSK, nonce))>"}
+//   ← {"type":"authenticated"}
+//
+// 키 자체는 오가지 않지만 이후 내용은 암호화되지 않으므로 신뢰할 수 있는 망이나 터널에서 사용
+
+use anyhow::{bail, Result};
+use ring::{hmac, rand::SecureRandom};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use super::protocol::{self, Request, Response, MAX_REQUEST_LINE};
+use super::server::{self, State};
+
+/// 에이전트의 기본 포트 (serve의 9959 다음)
+pub const DEFAULT_PORT: u16 = 9960;
+
+/// hello에 담는 프로토콜 버전
+pub const PROTOCOL_VERSION: u32 = 1;
+
+// 인증을 마치기까지 기다리는 시간
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+// 인증 후 요청이 없으면 연결을 닫는 시간 (클라이언트는 틱마다 요청하므로 넉넉하게)
+const IDLE_TIMEOUT: Duration = Duration::from_secs(600);
+// 챌린지 길이 (바이트)
+const NONCE_LEN: usize = 32;
+
+/// 챌린지에 대한 응답 (hex(HMAC-SHA256(키, nonce)))
+pub fn sign(psk: &[u8], nonce: &str) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, psk);
+    hex(hmac::sign(&key, nonce.as_bytes()).as_ref())
+}
+
+// 응답 검증 (상수 시간 비교)
+fn verify(psk: &[u8], nonce: &str, mac: &str) -> bool {
+    let Some(mac) = unhex(mac) else {
+        return false;
+    };
+    let key = hmac::Key::new(hmac::HMAC_SHA256, psk);
+    hmac::verify(&key, nonce.as_bytes(), &mac).is_ok()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn unhex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) || !text.is_ascii() {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&text[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// 연결 직후 에이전트 쪽 절차 - hello를 보내고, 키가 있으면 인증 요청을 확인
+/// 인증에 실패하면 에러 응답을 보내고 Err (연결을 닫아야 함)
+pub fn accept<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    psk: Option<&[u8]>,
+) -> Result<()> {
+    let nonce = match psk {
+        Some(_) => {
+            let mut bytes = [0u8; NONCE_LEN];
+            ring::rand::SystemRandom::new()
+                .fill(&mut bytes)
+                .map_err(|_| anyhow::anyhow!("Failed to generate a nonce"))?;
+            Some(hex(&bytes))
+        }
+        None => None,
+    };
+    let hello = Response::Hello {
+        version: PROTOCOL_VERSION,
+        nonce: nonce.clone(),
+    };
+    protocol::write_message(writer, &hello)?;
+
+    let (Some(psk), Some(nonce)) = (psk, nonce) else {
+        return Ok(());
+    };
+    match protocol::read_message::<_, Request>(reader, Some(MAX_REQUEST_LINE))? {
+        Some(Request::Auth { mac }) if verify(psk, &nonce, &mac) => {
+            protocol::write_message(writer, &Response::Authenticated)
+        }
+        Some(_) => {
+            let message = "authentication failed".to_string();
+            protocol::write_message(writer, &Response::Error { message })?;
+            bail!("authentication failed");
+        }
+        None => bail!("connection closed before authentication"),
+    }
+}
+
+/// 연결마다 스레드에서 인증 후 요청 처리 (연결 하나의 에러는 무시하고 계속 받음)
+pub fn serve(listener: TcpListener, state: Arc<Mutex<State>>, psk: Option<Arc<[u8]>>) {
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                crate::debug_log!("Failed to accept connection: {}", e);
+                continue;
+            }
+        };
+        let state = Arc::clone(&state);
+        let psk = psk.clone();
+        thread::spawn(move || {
+            if let Err(e) = handle(stream, &state, psk.as_deref()) {
+                crate::debug_log!("Agent connection error: {}", e);
+            }
+        });
+    }
+}
+
+fn handle(stream: TcpStream, state: &Mutex<State>, psk: Option<&[u8]>) -> Result<()> {
+    stream.set_nodelay(true)?;
+    stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = &stream;
+    accept(&mut reader, &mut writer, psk)?;
+
+    stream.set_read_timeout(Some(IDLE_TIMEOUT))?;
+    server::serve_connection(reader, writer, state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::client::Client;
+    use crate::network::sampler::Sampler;
+
+    #[test]
+    fn test_authentication() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let state = Arc::new(Mutex::new(State::new(
+            Sampler::new(Vec::new(), 1, Duration::from_secs(1)),
+            None,
+        )));
+        let psk: Arc<[u8]> = Arc::from(&b"correct horse"[..]);
+        thread::spawn(move || serve(listener, state, Some(psk)));
+
+        let mut client = Client::connect_tcp(&address, Some(b"correct horse")).unwrap();
+        assert!(client.interfaces().unwrap().is_empty());
+
+        let error =
+            |psk: Option<&[u8]>| format!("{:#}", Client::connect_tcp(&address, psk).err().unwrap());
+        assert!(error(Some(b"wrong")).contains("authentication failed"));
+        assert!(error(None).contains("pre-shared key"));
+    }
+}
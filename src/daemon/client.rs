@@ -0,0 +1,149 @@
+// 클라이언트 쪽 - 데몬에 요청을 보내고, 데몬의 상태를 따라가는 Sampler를 만듦
+// TUI와 헤드리스 명령은 이 Sampler를 직접 샘플링하는 Sampler와 똑같이 사용
+
+use anyhow::{bail, Context, Result};
+use std::io::{BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use super::agent;
+use super::protocol::{self, Request, Response, MAX_REQUEST_LINE, MAX_RESPONSE_LINE};
+use crate::i18n::{t, Msg};
+use crate::network::{
+    interface::NetworkInterface,
+    sampler::{Sampler, StatsSource, Update},
+};
+
+// 데몬이 응답하지 않을 때 기다리는 시간
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 요청을 차례로 보내고 응답을 받는 연결
+pub struct Client {
+    reader: BufReader<Box<dyn Read + Send>>,
+    writer: Box<dyn Write + Send>,
+}
+
+impl Client {
+    /// 읽기/쓰기 스트림으로 클라이언트 생성
+    pub fn new(reader: Box<dyn Read + Send>, writer: Box<dyn Write + Send>) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            writer,
+        }
+    }
+
+    /// Unix 소켓으로 데몬에 연결
+    #[cfg(unix)]
+    pub fn connect_unix(path: &std::path::Path) -> Result<Self> {
+        use std::os::unix::net::UnixStream;
+
+        let stream = UnixStream::connect(path)
+            .with_context(|| format!("Failed to connect to {}", path.display()))?;
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+        Ok(Self::new(Box::new(stream.try_clone()?), Box::new(stream)))
+    }
+
+    /// TCP로 원격 에이전트에 연결하고 hello/인증 절차를 마침 (agent.rs)
+    pub fn connect_tcp(address: &str, psk: Option<&[u8]>) -> Result<Self> {
+        let stream = address
+            .to_socket_addrs()
+            .with_context(|| format!("Failed to resolve {}", address))?
+            .find_map(|addr| TcpStream::connect_timeout(&addr, IO_TIMEOUT).ok())
+            .with_context(|| format!("Failed to connect to {}", address))?;
+        stream.set_nodelay(true)?;
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+        let mut client = Self::new(Box::new(stream.try_clone()?), Box::new(stream));
+
+        // 인증 전이므로 hello는 요청과 같은 길이까지만 받음
+        let nonce = match protocol::read_message(&mut client.reader, Some(MAX_REQUEST_LINE))? {
+            Some(Response::Hello { nonce, .. }) => nonce,
+            _ => bail!("{} is not an nbmon agent", address),
+        };
+        if let Some(nonce) = nonce {
+            let psk = psk.context("the agent requires a pre-shared key (--psk-file)")?;
+            let mac = agent::sign(psk, &nonce);
+            match client.request(&Request::Auth { mac })? {
+                Response::Authenticated => {}
+                _ => bail!("unexpected response to auth"),
+            }
+        }
+        Ok(client)
+    }
+
+    /// 요청을 보내고 응답을 받음 (Error 응답은 에러로)
+    pub fn request(&mut self, request: &Request) -> Result<Response> {
+        protocol::write_message(&mut self.writer, request).context("Failed to send request")?;
+        match protocol::read_message(&mut self.reader, Some(MAX_RESPONSE_LINE))? {
+            Some(Response::Error { message }) => bail!(message),
+            Some(response) => Ok(response),
+            None => bail!("daemon closed the connection"),
+        }
+    }
+
+    /// 데몬이 샘플링 중인 인터페이스 목록
+    pub fn interfaces(&mut self) -> Result<Vec<NetworkInterface>> {
+        match self.request(&Request::Interfaces)? {
+            Response::Interfaces { interfaces, .. } => Ok(interfaces),
+            _ => bail!("unexpected response to interfaces"),
+        }
+    }
+}
+
+// 매 틱 데몬에서 새 틱의 갱신을 받아오는 공급원
+struct DaemonSource {
+    client: Client,
+    interfaces: Vec<String>,
+    /// 마지막으로 반영한 데몬 틱
+    tick: u64,
+}
+
+impl StatsSource for DaemonSource {
+    fn fetch(&mut self) -> Result<Option<Update>> {
+        let request = Request::Update {
+            interfaces: self.interfaces.clone(),
+            since: self.tick,
+        };
+        match self.client.request(&request)? {
+            // 데몬이 아직 다음 샘플을 만들지 않았으면 그대로
+            Response::Update { tick, .. } if tick == self.tick => Ok(None),
+            Response::Update { tick, update } => {
+                self.tick = tick;
+                Ok(Some(update))
+            }
+            _ => bail!("unexpected response to update"),
+        }
+    }
+}
+
+/// 데몬에 붙어서 데몬의 상태(히스토리 포함)로 시작하는 Sampler 생성
+/// select: 데몬의 인터페이스 목록에서 사용할 인터페이스를 고름
+/// (빈 목록은 데몬이 모든 인터페이스로 받아들이므로 고른 것이 없으면 에러)
+pub fn attach(
+    mut client: Client,
+    select: impl FnOnce(Vec<NetworkInterface>) -> Result<Vec<NetworkInterface>>,
+) -> Result<Sampler> {
+    let names: Vec<String> = select(client.interfaces()?)?
+        .into_iter()
+        .map(|interface| interface.name)
+        .collect();
+    if names.is_empty() {
+        bail!(t(Msg::NoInterfacesMatch));
+    }
+
+    let request = Request::Snapshot {
+        interfaces: names.clone(),
+    };
+    let (tick, snapshot) = match client.request(&request)? {
+        Response::Snapshot { tick, snapshot } => (tick, snapshot),
+        _ => bail!("unexpected response to snapshot"),
+    };
+
+    let source = DaemonSource {
+        client,
+        interfaces: names,
+        tick,
+    };
+    Ok(Sampler::from_snapshot(snapshot, Box::new(source)))
+}
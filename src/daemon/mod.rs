@@ -0,0 +1,114 @@
+// 백그라운드 샘플링 데몬 (nbmon daemon)
+// 데몬 하나가 모든 인터페이스를 계속 샘플링하고 히스토리를 유지하며, Unix 소켓으로 요청에 응답
+// TUI와 헤드리스 명령은 --attach로 데몬에 붙어서 직접 샘플링하는 대신 데몬의 상태를 사용
+// (TUI를 열자마자 그래프가 채워져 있고, 여러 사용자가 샘플러 하나를 공유)
+
+pub mod agent; // TCP로 같은 API를 제공하는 원격 에이전트와 PSK 인증 (agent.rs)
+pub mod client; // 데몬에 요청을 보내고 데몬을 따라가는 Sampler를 만드는 클라이언트 (client.rs)
+pub mod protocol; // 줄 단위 JSON 요청/응답 형식 (protocol.rs)
+pub mod server; // 공유 상태와 연결별 요청 처리 (server.rs)
+
+use std::path::PathBuf;
+
+/// 시스템 전체 데몬의 소켓 경로 (root로 실행한 데몬)
+pub const SYSTEM_SOCKET: &str = "/run/nbmon.sock";
+
+/// 기본 소켓 경로
+/// 우선순위: $NBMON_SOCKET → root면 /run/nbmon.sock → $XDG_RUNTIME_DIR/nbmon.sock → /tmp/nbmon-<uid>.sock
+pub fn default_socket_path() -> PathBuf {
+    if let Some(path) = std::env::var_os("NBMON_SOCKET").filter(|p| !p.is_empty()) {
+        return PathBuf::from(path);
+    }
+
+    #[cfg(unix)]
+    {
+        // SAFETY: geteuid는 실패하지 않고 메모리를 건드리지 않음
+        let uid = unsafe { libc::geteuid() };
+        if uid == 0 {
+            return PathBuf::from(SYSTEM_SOCKET);
+        }
+        if let Some(dir) = std::env::var_os("XDG_RUNTIME_DIR").filter(|d| !d.is_empty()) {
+            return PathBuf::from(dir).join("nbmon.sock");
+        }
+        std::env::temp_dir().join(format!("nbmon-{}.sock", uid))
+    }
+
+    #[cfg(not(unix))]
+    std::env::temp_dir().join("nbmon.sock")
+}
+
+/// --attach에 경로를 주지 않았을 때 찾아볼 소켓 (자신의 데몬 → 시스템 데몬)
+pub fn attach_candidates() -> Vec<PathBuf> {
+    let mut candidates = vec![default_socket_path()];
+    let system = PathBuf::from(SYSTEM_SOCKET);
+    if !candidates.contains(&system) {
+        candidates.push(system);
+    }
+    candidates
+}
+
+#[cfg(unix)]
+pub use unix::{bind, serve};
+
+#[cfg(unix)]
+mod unix {
+    use anyhow::{bail, Context, Result};
+    use std::fs;
+    use std::io::BufReader;
+    use std::os::unix::fs::PermissionsExt;
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::Path;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    use super::server::{self, State};
+
+    /// 소켓 파일을 만들고 수신 시작
+    /// 이전 데몬이 남긴 소켓 파일은 지우지만, 응답하는 데몬이 있으면 에러
+    pub fn bind(path: &Path) -> Result<UnixListener> {
+        if path.exists() {
+            if UnixStream::connect(path).is_ok() {
+                bail!("another daemon is already listening on {}", path.display());
+            }
+            fs::remove_file(path)
+                .with_context(|| format!("Failed to remove stale socket {}", path.display()))?;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+
+        let listener = UnixListener::bind(path)
+            .with_context(|| format!("Failed to listen on {}", path.display()))?;
+        // 읽기 전용 API이므로 다른 사용자도 붙을 수 있게 함
+        fs::set_permissions(path, fs::Permissions::from_mode(0o666))
+            .with_context(|| format!("Failed to set permissions on {}", path.display()))?;
+        Ok(listener)
+    }
+
+    /// 연결마다 스레드에서 요청 처리 (연결 하나의 에러는 무시하고 계속 받음)
+    pub fn serve(listener: UnixListener, state: Arc<Mutex<State>>) -> Result<()> {
+        for stream in listener.incoming() {
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(e) => {
+                    crate::debug_log!("Failed to accept connection: {}", e);
+                    continue;
+                }
+            };
+            let state = Arc::clone(&state);
+            thread::spawn(move || {
+                let result = stream
+                    .try_clone()
+                    .map_err(anyhow::Error::from)
+                    .and_then(|reader| {
+                        server::serve_connection(BufReader::new(reader), &stream, &state)
+                    });
+                if let Err(e) = result {
+                    crate::debug_log!("Daemon connection error: {}", e);
+                }
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,194 @@
+// 데몬 요청/응답 형식
+// 한 줄에 JSON 객체 하나 (줄바꿈으로 구분), 연결 하나로 요청을 여러 번 보낼 수 있음
+//
+//   → {"cmd":"current","interfaces":["eth0"]}
+//   ← {"type":"current","tick":42,"interfaces":["eth0"],"stats":[...],"bandwidth":[...]}
+//   → {"cmd":"history","interface":"eth0","range":"10m"}
+//   ← {"type":"history","interface":"eth0","range":"10m","download":[{"min":0,"avg":10,"max":20},...],"upload":[...]}
+//
+// interfaces를 생략하거나 비워 두면 모든 인터페이스
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, Read, Write};
+
+use crate::network::{
+    history::Bucket,
+    interface::NetworkInterface,
+    sampler::{Snapshot, Update},
+    stats::{BandwidthStats, InterfaceStats},
+};
+use crate::storage::accounting::Traffic;
+
+/// 요청 한 줄의 최대 길이
+pub const MAX_REQUEST_LINE: u64 = 64 * 1024;
+
+/// 응답 한 줄의 최대 길이 - 스냅샷은 인터페이스마다 롤업을 포함한 24시간 히스토리를 담으므로 넉넉하게
+/// (기본 설정에서 인터페이스 하나에 수백 KiB - 원격 에이전트가 메모리를 다 쓰게 하지 못하도록 하는 상한)
+pub const MAX_RESPONSE_LINE: u64 = 64 * 1024 * 1024;
+
+/// 클라이언트 → 데몬
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "cmd", rename_all = "snake_case")]
+pub enum Request {
+    /// 샘플링 중인 인터페이스 목록
+    Interfaces,
+    /// 인터페이스별 마지막 카운터와 속도
+    Current {
+        #[serde(default)]
+        interfaces: Vec<String>,
+    },
+    /// 한 인터페이스의 속도 히스토리 (range: 1m, 10m, 1h, 24h)
+    History { interface: String, range: String },
+    /// 인터페이스별 누적 카운터와 오늘/이번 달 집계
+    Totals {
+        #[serde(default)]
+        interfaces: Vec<String>,
+    },
+    /// 히스토리를 포함한 상태 전체 (클라이언트가 처음 붙을 때)
+    Snapshot {
+        #[serde(default)]
+        interfaces: Vec<String>,
+    },
+    /// since 틱 이후의 갱신 (스냅샷 이후 매 틱)
+    Update {
+        #[serde(default)]
+        interfaces: Vec<String>,
+        since: u64,
+    },
+    /// 에이전트의 챌린지에 대한 응답 (agent.rs)
+    Auth { mac: String },
+}
+
+/// 인터페이스 하나의 누적 값
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InterfaceTotals {
+    pub interface: String,
+    /// 커널 카운터 (부팅 이후)
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    /// 집계 데이터베이스의 오늘/이번 달 합계 (집계가 없으면 0)
+    pub today: Traffic,
+    pub month: Traffic,
+}
+
+/// 데몬 → 클라이언트 (tick: 데몬이 시작된 뒤 샘플링한 횟수)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Response {
+    Interfaces {
+        tick: u64,
+        interfaces: Vec<NetworkInterface>,
+    },
+    Current {
+        tick: u64,
+        interfaces: Vec<String>,
+        stats: Vec<InterfaceStats>,
+        bandwidth: Vec<Option<BandwidthStats>>,
+    },
+    History {
+        interface: String,
+        range: String,
+        download: Vec<Bucket>,
+        upload: Vec<Bucket>,
+    },
+    Totals {
+        totals: Vec<InterfaceTotals>,
+    },
+    Snapshot {
+        tick: u64,
+        snapshot: Snapshot,
+    },
+    Update {
+        tick: u64,
+        update: Update,
+    },
+    Error {
+        message: String,
+    },
+    /// 에이전트가 연결 직후 보내는 인사 (nonce가 있으면 인증 필요)
+    Hello {
+        version: u32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        nonce: Option<String>,
+    },
+    Authenticated,
+}
+
+/// 값 하나를 JSON 한 줄로 기록
+pub fn write_message<W: Write, T: Serialize>(out: &mut W, message: &T) -> Result<()> {
+    let mut line = serde_json::to_vec(message).context("Failed to encode message")?;
+    line.push(b'\n');
+    out.write_all(&line)?;
+    out.flush()?;
+    Ok(())
+}
+
+/// JSON 한 줄을 읽음 (연결이 닫혔으면 None)
+/// limit: 줄 길이 제한 (넘으면 에러)
+pub fn read_message<R: BufRead, T: for<'de> Deserialize<'de>>(
+    reader: &mut R,
+    limit: Option<u64>,
+) -> Result<Option<T>> {
+    let mut line = String::new();
+    let read = match limit {
+        Some(limit) => reader.take(limit + 1).read_line(&mut line)?,
+        None => reader.read_line(&mut line)?,
+    };
+    if read == 0 {
+        return Ok(None);
+    }
+    if limit.is_some_and(|limit| read as u64 > limit) {
+        anyhow::bail!("message is longer than {} bytes", limit.unwrap_or_default());
+    }
+    serde_json::from_str(line.trim_end())
+        .map(Some)
+        .context("Malformed message")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_message_format() {
+        let request: Request =
+            serde_json::from_str(r#"{"cmd":"update","since":7,"interfaces":["eth0"]}"#).unwrap();
+        assert_eq!(
+            request,
+            Request::Update {
+                interfaces: vec!["eth0".to_string()],
+                since: 7
+            }
+        );
+        let request: Request = serde_json::from_str(r#"{"cmd":"totals"}"#).unwrap();
+        assert_eq!(request, Request::Totals { interfaces: vec![] });
+
+        let mut out = Vec::new();
+        write_message(&mut out, &Request::Interfaces).unwrap();
+        write_message(
+            &mut out,
+            &Request::History {
+                interface: "eth0".to_string(),
+                range: "1m".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out.clone()).unwrap(),
+            "{\"cmd\":\"interfaces\"}\n{\"cmd\":\"history\",\"interface\":\"eth0\",\"range\":\"1m\"}\n"
+        );
+
+        let mut reader = Cursor::new(out);
+        let first: Option<Request> = read_message(&mut reader, Some(MAX_REQUEST_LINE)).unwrap();
+        assert_eq!(first, Some(Request::Interfaces));
+        let _: Option<Request> = read_message(&mut reader, None).unwrap();
+        let end: Option<Request> = read_message(&mut reader, None).unwrap();
+        assert_eq!(end, None);
+
+        let long = format!("{{\"cmd\":\"{}\"}}\n", "x".repeat(100));
+        let result: Result<Option<Request>> = read_message(&mut Cursor::new(long), Some(50));
+        assert!(result.is_err());
+    }
+}
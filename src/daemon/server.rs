@@ -0,0 +1,345 @@
+// 데몬 쪽 - 샘플링 상태를 공유하고 연결마다 요청에 응답
+// 샘플링 스레드가 틱마다 상태를 갱신하고, 연결 스레드들은 잠깐씩 잠가서 읽기만 함
+
+use anyhow::Result;
+use std::io::{BufRead, Write};
+use std::sync::{Mutex, PoisonError};
+
+use super::protocol::{self, InterfaceTotals, Request, Response, MAX_REQUEST_LINE};
+use crate::network::{
+    history::TimeRange,
+    sampler::{sample_ages, Sampler, Update},
+    stats::{BandwidthStats, InterfaceStats},
+};
+use crate::storage::accounting::{Period, Recorder};
+use crate::utils::time::{self, DateTime};
+
+/// 데몬이 공유하는 상태
+pub struct State {
+    sampler: Sampler,
+    /// 시작한 뒤 샘플링한 횟수 (클라이언트가 놓친 샘플을 계산하는 기준)
+    tick: u64,
+    /// 트래픽 집계 기록 (데이터 디렉터리가 없으면 None)
+    recorder: Option<Recorder>,
+}
+
+impl State {
+    pub fn new(sampler: Sampler, recorder: Option<Recorder>) -> Self {
+        Self {
+            sampler,
+            tick: 0,
+            recorder,
+        }
+    }
+
+    /// 샘플링 중인 인터페이스 수
+    pub fn interface_count(&self) -> usize {
+        self.sampler.interfaces().len()
+    }
+
+    /// 한 틱 샘플링하고 집계 기록 갱신
+    pub fn tick(&mut self) -> Result<()> {
+        self.sampler.update()?;
+        self.tick += 1;
+        if let Some(recorder) = &mut self.recorder {
+            recorder.maybe_flush(&self.sampler);
+        }
+        Ok(())
+    }
+
+    /// 요청 하나에 대한 응답 (잘못된 요청은 Error 응답)
+    pub fn handle(&self, request: &Request) -> Response {
+        self.try_handle(request)
+            .unwrap_or_else(|message| Response::Error { message })
+    }
+
+    fn try_handle(&self, request: &Request) -> Result<Response, String> {
+        let sampler = &self.sampler;
+        Ok(match request {
+            Request::Interfaces => Response::Interfaces {
+                tick: self.tick,
+                interfaces: sampler.interfaces().to_vec(),
+            },
+            Request::Current { interfaces } => {
+                let indices = self.indices(interfaces)?;
+                Response::Current {
+                    tick: self.tick,
+                    interfaces: indices
+                        .iter()
+                        .map(|&i| sampler.interfaces()[i].name.clone())
+                        .collect(),
+                    stats: indices
+                        .iter()
+                        .map(|&i| sampler.all_stats()[i].clone())
+                        .collect(),
+                    bandwidth: indices
+                        .iter()
+                        .map(|&i| sampler.all_bandwidth()[i].clone())
+                        .collect(),
+                }
+            }
+            Request::History { interface, range } => {
+                let i = self.indices(std::slice::from_ref(interface))?[0];
+                let time_range = TimeRange::parse(range)
+                    .ok_or_else(|| format!("unknown range {} (use 1m, 10m, 1h or 24h)", range))?;
+                let history = sampler.history(i).ok_or("no history")?;
+                Response::History {
+                    interface: interface.clone(),
+                    range: range.clone(),
+                    download: history.download().range(time_range),
+                    upload: history.upload().range(time_range),
+                }
+            }
+            Request::Totals { interfaces } => Response::Totals {
+                totals: self.totals(&self.indices(interfaces)?),
+            },
+            Request::Snapshot { interfaces } => Response::Snapshot {
+                tick: self.tick,
+                snapshot: sampler.snapshot(&self.indices(interfaces)?),
+            },
+            Request::Update { interfaces, since } => Response::Update {
+                tick: self.tick,
+                update: self.update_since(&self.indices(interfaces)?, *since),
+            },
+            // 인증은 연결 직후에만 (agent.rs)
+            Request::Auth { .. } => return Err("unexpected auth request".to_string()),
+        })
+    }
+
+    // 이름 목록을 Sampler의 인덱스로 (비어 있으면 모든 인터페이스)
+    fn indices(&self, names: &[String]) -> Result<Vec<usize>, String> {
+        let interfaces = self.sampler.interfaces();
+        if names.is_empty() {
+            return Ok((0..interfaces.len()).collect());
+        }
+        names
+            .iter()
+            .map(|name| {
+                interfaces
+                    .iter()
+                    .position(|interface| interface.name == *name)
+                    .ok_or_else(|| format!("unknown interface {}", name))
+            })
+            .collect()
+    }
+
+    // since 틱 이후의 갱신 - 놓친 틱 수만큼 1초 해상도 히스토리의 최근 샘플을 함께 보냄
+    fn update_since(&self, indices: &[usize], since: u64) -> Update {
+        // 데몬이 다시 시작되어 틱이 줄었으면 마지막 샘플 하나만
+        let missed = match self.tick.checked_sub(since) {
+            Some(missed) => missed as usize,
+            None => 1,
+        };
+        let sampler = &self.sampler;
+        let stats: Vec<InterfaceStats> = indices
+            .iter()
+            .map(|&i| sampler.all_stats()[i].clone())
+            .collect();
+        Update {
+            ages: sample_ages(&stats),
+            stats,
+            bandwidth: indices
+                .iter()
+                .map(|&i| sampler.all_bandwidth()[i].clone())
+                .collect(),
+            samples: indices
+                .iter()
+                .map(|&i| {
+                    let Some(history) = sampler.history(i) else {
+                        return Vec::new();
+                    };
+                    history
+                        .download()
+                        .recent(missed)
+                        .into_iter()
+                        .zip(history.upload().recent(missed))
+                        .map(|(download, upload)| BandwidthStats {
+                            download_rate: download as f64,
+                            upload_rate: upload as f64,
+                            ..Default::default()
+                        })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    // 커널 카운터와 집계 데이터베이스의 오늘/이번 달 합계
+    fn totals(&self, indices: &[usize]) -> Vec<InterfaceTotals> {
+        let now = DateTime::local(time::now_unix());
+        let (day, month) = (now.day_key(), now.month_key());
+        let database = self.recorder.as_ref().and_then(Recorder::database);
+
+        indices
+            .iter()
+            .map(|&i| {
+                let interface = &self.sampler.interfaces()[i];
+                let stats = &self.sampler.all_stats()[i];
+                let sum = |period: Period, key: &str| {
+                    database
+                        .map(|db| db.period_total(&interface.name, period, key))
+                        .unwrap_or_default()
+                };
+                InterfaceTotals {
+                    interface: interface.name.clone(),
+                    rx_bytes: stats.bytes_received,
+                    tx_bytes: stats.bytes_sent,
+                    today: sum(Period::Daily, &day),
+                    month: sum(Period::Monthly, &month),
+                }
+            })
+            .collect()
+    }
+}
+
+/// 연결 하나 - 연결이 닫힐 때까지 요청을 읽고 응답
+/// 응답을 만드는 동안만 상태를 잠그고, 쓰는 동안에는 잠그지 않음
+pub fn serve_connection<R: BufRead, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    state: &Mutex<State>,
+) -> Result<()> {
+    loop {
+        let response =
+            match protocol::read_message::<_, Request>(&mut reader, Some(MAX_REQUEST_LINE)) {
+                Ok(Some(request)) => state
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .handle(&request),
+                Ok(None) => return Ok(()),
+                // 잘못된 요청에는 에러를 알리고 연결을 닫음 (줄 경계를 믿을 수 없으므로)
+                Err(e) => {
+                    let message = format!("{:#}", e);
+                    return protocol::write_message(&mut writer, &Response::Error { message });
+                }
+            };
+        protocol::write_message(&mut writer, &response)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::interface::NetworkInterface;
+    use crate::network::sampler::{Snapshot, StatsSource};
+    use crate::network::{history::InterfaceHistory, stats::InterfaceStats};
+    use crate::storage::accounting::Traffic;
+    use std::time::Duration;
+
+    // 정해진 갱신을 차례로 돌려주는 공급원
+    struct Script(Vec<Update>);
+
+    impl StatsSource for Script {
+        fn fetch(&mut self) -> Result<Option<Update>> {
+            Ok((!self.0.is_empty()).then(|| self.0.remove(0)))
+        }
+    }
+
+    fn rates(download: f64) -> BandwidthStats {
+        BandwidthStats {
+            download_rate: download,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_requests() {
+        let eth0 = NetworkInterface::new(2, "eth0".to_string(), "eth0".to_string());
+        let snapshot = Snapshot {
+            interfaces: vec![eth0],
+            stats: vec![InterfaceStats::new(2)],
+            bandwidth: vec![None],
+            histories: vec![InterfaceHistory::new(60, Duration::from_secs(1))],
+            ages: Vec::new(),
+        };
+        let updates = (1..=3)
+            .map(|i| Update {
+                stats: vec![InterfaceStats {
+                    bytes_received: i * 100,
+                    ..InterfaceStats::new(2)
+                }],
+                bandwidth: vec![Some(rates(i as f64 * 10.0))],
+                samples: vec![vec![rates(i as f64 * 10.0)]],
+                ages: Vec::new(),
+            })
+            .collect();
+        let sampler = Sampler::from_snapshot(snapshot, Box::new(Script(updates)));
+        let mut state = State::new(sampler, None);
+        for _ in 0..3 {
+            state.tick().unwrap();
+        }
+
+        let Response::Update { tick, update } = state.handle(&Request::Update {
+            interfaces: vec![],
+            since: 1,
+        }) else {
+            panic!("expected update");
+        };
+        assert_eq!(tick, 3);
+        assert_eq!(update.stats[0].bytes_received, 300);
+        // 틱 1 이후의 두 샘플
+        let samples: Vec<f64> = update.samples[0].iter().map(|s| s.download_rate).collect();
+        assert_eq!(samples, [20.0, 30.0]);
+
+        let Response::History { download, .. } = state.handle(&Request::History {
+            interface: "eth0".to_string(),
+            range: "1m".to_string(),
+        }) else {
+            panic!("expected history");
+        };
+        assert_eq!(download.len(), 3);
+
+        let Response::Totals { totals } = state.handle(&Request::Totals { interfaces: vec![] })
+        else {
+            panic!("expected totals");
+        };
+        assert_eq!(totals[0].rx_bytes, 300);
+        assert_eq!(totals[0].today, Traffic::default());
+
+        let Response::Error { message } = state.handle(&Request::Current {
+            interfaces: vec!["wlan9".to_string()],
+        }) else {
+            panic!("expected error");
+        };
+        assert_eq!(message, "unknown interface wlan9");
+
+        // 스냅샷(롤업 누적값 포함)이 JSON을 거쳐 그대로 돌아옴
+        let line = serde_json::to_string(&state.handle(&Request::Snapshot { interfaces: vec![] }))
+            .unwrap();
+        let Response::Snapshot { tick, snapshot } = serde_json::from_str(&line).unwrap() else {
+            panic!("expected snapshot");
+        };
+        assert_eq!(tick, 3);
+        assert_eq!(
+            snapshot.histories[0]
+                .download()
+                .range(TimeRange::OneMinute)
+                .len(),
+            3
+        );
+        // 받는 쪽의 카운터 시각은 받은 시각이 아니라 데몬이 카운터를 읽은 시각
+        assert_eq!(snapshot.ages.len(), 1);
+        let snapshot = Snapshot {
+            ages: vec![1_500],
+            ..snapshot
+        };
+        let attached = Sampler::from_snapshot(snapshot, Box::new(Script(Vec::new())));
+        let read_at = attached.all_stats()[0].timestamp.unwrap();
+        assert!(read_at.elapsed() >= Duration::from_millis(1_500));
+
+        // 연결 하나로 여러 요청, 잘못된 줄에는 에러 후 종료
+        let input =
+            "{\"cmd\":\"interfaces\"}\n{\"cmd\":\"current\"}\nnot json\n{\"cmd\":\"interfaces\"}\n";
+        let mut output = Vec::new();
+        serve_connection(input.as_bytes(), &mut output, &Mutex::new(state)).unwrap();
+        let lines: Vec<serde_json::Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0]["type"], "interfaces");
+        assert_eq!(lines[1]["type"], "current");
+        assert_eq!(lines[2]["type"], "error");
+    }
+}
@@ -0,0 +1,227 @@
+// sar 스타일 배치 출력 모듈 (nbmon --batch)
+// 매 틱마다 인터페이스별로 고정 폭 열 한 줄을 출력하고, N줄마다 머리글을 반복
+// 스크립트나 tmux 창에서 `sar -n DEV` 대신 쓸 수 있도록 함
+// 속도 열은 단위 체계를 따르고, 배율이 auto이면 sar처럼 k(Ki) 단위로 고정
+
+use crossterm::style::Stylize;
+use std::fmt::Write;
+
+use crate::network::{interface::NetworkInterface, stats::InterfaceStats};
+use crate::utils::format::{Scale, UnitSystem};
+
+/// 머리글을 다시 출력하는 기본 간격 (데이터 줄 수)
+pub const DEFAULT_HEADER_EVERY: usize = 20;
+
+// 배율이 auto일 때 속도 열에 쓰는 접두사 지수 (k/Ki)
+const DEFAULT_RATE_EXPONENT: usize = 1;
+
+// 열 너비
+const TIME_WIDTH: usize = 10;
+const IFACE_WIDTH: usize = 12;
+const RATE_WIDTH: usize = 10;
+const COUNT_WIDTH: usize = 8;
+
+/// 한 인터페이스의 한 틱 (두 카운터 사이의 변화량)
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchRow {
+    pub time: String,
+    pub interface: String,
+    pub rx_bytes_per_sec: f64,
+    pub tx_bytes_per_sec: f64,
+    pub rx_packets_per_sec: f64,
+    pub tx_packets_per_sec: f64,
+    /// 구간 동안 새로 생긴 수신+송신 에러 수
+    pub errors: u64,
+    /// 구간 동안 새로 버려진 수신+송신 패킷 수
+    pub drops: u64,
+}
+
+impl BatchRow {
+    /// 이전/현재 카운터로 한 줄 계산 (시간 정보가 없거나 경과 시간이 0이면 None)
+    pub fn between(
+        time: &str,
+        interface: &NetworkInterface,
+        previous: &InterfaceStats,
+        current: &InterfaceStats,
+    ) -> Option<Self> {
+        let elapsed = current
+            .timestamp?
+            .duration_since(previous.timestamp?)
+            .as_secs_f64();
+        if elapsed <= 0.0 {
+            return None;
+        }
+
+        // 카운터가 줄었으면(인터페이스 재생성 등) 0으로 봄
+        let delta = |current: u64, previous: u64| current.saturating_sub(previous);
+        let per_sec = |current: u64, previous: u64| delta(current, previous) as f64 / elapsed;
+
+        Some(Self {
+            time: time.to_string(),
+            interface: interface.name.clone(),
+            rx_bytes_per_sec: per_sec(current.bytes_received, previous.bytes_received),
+            tx_bytes_per_sec: per_sec(current.bytes_sent, previous.bytes_sent),
+            rx_packets_per_sec: per_sec(current.packets_received, previous.packets_received),
+            tx_packets_per_sec: per_sec(current.packets_sent, previous.packets_sent),
+            errors: delta(current.errors_in, previous.errors_in)
+                + delta(current.errors_out, previous.errors_out),
+            drops: delta(current.drops_in, previous.drops_in)
+                + delta(current.drops_out, previous.drops_out),
+        })
+    }
+}
+
+/// 머리글 반복과 색상 여부를 관리하는 표 출력기
+pub struct BatchTable {
+    header_every: usize, // 0이면 처음 한 번만
+    lines_since_header: Option<usize>,
+    color: bool,
+    rate_label: String, // 속도 열 단위 (예: "KiB/s", "Mbit/s")
+    rate_divisor: f64,  // 바이트/초를 열 단위로 바꿀 때 나누는 값
+}
+
+impl BatchTable {
+    /// header_every: 머리글 사이의 데이터 줄 수 (0이면 처음 한 번만)
+    /// color: 표준 출력이 터미널일 때만 true로 설정
+    /// units: 속도 열의 단위 체계
+    pub fn new(header_every: usize, color: bool, units: UnitSystem) -> Self {
+        let exponent = match units.scale {
+            Scale::Fixed(exponent) => exponent,
+            Scale::Auto => DEFAULT_RATE_EXPONENT,
+        };
+        let (rate_label, rate_divisor) = units.rate_unit(exponent);
+        Self {
+            header_every,
+            lines_since_header: None,
+            color,
+            rate_label,
+            rate_divisor,
+        }
+    }
+
+    /// 한 틱의 줄들을 문자열로 만듦 (필요하면 머리글 포함)
+    pub fn render(&mut self, rows: &[BatchRow]) -> String {
+        let mut out = String::new();
+
+        for row in rows {
+            let header_due = match self.lines_since_header {
+                None => true,
+                Some(lines) => self.header_every > 0 && lines >= self.header_every,
+            };
+            if header_due {
+                self.write_header(&mut out);
+                self.lines_since_header = Some(0);
+            }
+
+            self.write_row(&mut out, row);
+            self.lines_since_header = self.lines_since_header.map(|lines| lines + 1);
+        }
+        out
+    }
+
+    fn write_header(&self, out: &mut String) {
+        let header = format!(
+            "{:<tw$}{:<iw$}{:>rw$}{:>rw$}{:>rw$}{:>rw$}{:>cw$}{:>cw$}",
+            "time",
+            "iface",
+            format!("rx{}", self.rate_label),
+            format!("tx{}", self.rate_label),
+            "rxpck/s",
+            "txpck/s",
+            "errs",
+            "drops",
+            tw = TIME_WIDTH,
+            iw = IFACE_WIDTH,
+            rw = RATE_WIDTH,
+            cw = COUNT_WIDTH
+        );
+        // writeln!은 String에 대해 실패하지 않으므로 결과를 무시
+        if self.color {
+            let _ = writeln!(out, "{}", header.bold());
+        } else {
+            let _ = writeln!(out, "{}", header);
+        }
+    }
+
+    fn write_row(&self, out: &mut String, row: &BatchRow) {
+        // 긴 인터페이스 이름은 열 정렬이 깨지지 않도록 자름
+        let interface: String = row.interface.chars().take(IFACE_WIDTH - 1).collect();
+
+        let _ = write!(
+            out,
+            "{:<tw$}{:<iw$}{:>rw$.2}{:>rw$.2}{:>rw$.2}{:>rw$.2}",
+            row.time,
+            interface,
+            row.rx_bytes_per_sec / self.rate_divisor,
+            row.tx_bytes_per_sec / self.rate_divisor,
+            row.rx_packets_per_sec,
+            row.tx_packets_per_sec,
+            tw = TIME_WIDTH,
+            iw = IFACE_WIDTH,
+            rw = RATE_WIDTH
+        );
+
+        // 에러/드롭이 있으면 눈에 띄도록 빨간색
+        for count in [row.errors, row.drops] {
+            let cell = format!("{:>cw$}", count, cw = COUNT_WIDTH);
+            if self.color && count > 0 {
+                let _ = write!(out, "{}", cell.red());
+            } else {
+                out.push_str(&cell);
+            }
+        }
+        out.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(interface: &str) -> BatchRow {
+        BatchRow {
+            time: "12:00:01".to_string(),
+            interface: interface.to_string(),
+            rx_bytes_per_sec: 1536.0,
+            tx_bytes_per_sec: 256.0,
+            rx_packets_per_sec: 10.0,
+            tx_packets_per_sec: 3.0,
+            errors: 0,
+            drops: 2,
+        }
+    }
+
+    #[test]
+    fn test_header_repeats_and_columns_align() {
+        let mut table = BatchTable::new(2, false, UnitSystem::default());
+        let first = table.render(&[row("eth0"), row("wlan0")]);
+        let second = table.render(&[row("eth0")]);
+
+        let lines: Vec<&str> = first.lines().chain(second.lines()).collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].starts_with("time"));
+        assert!(lines[3].starts_with("time"));
+
+        // 모든 줄의 길이가 같음 (고정 폭)
+        assert!(lines.iter().all(|line| line.len() == lines[0].len()));
+        assert!(lines[1].ends_with("       0       2"));
+        assert!(lines[0].contains("rxKiB/s"));
+        assert!(lines[1].contains("      1.50      0.25"));
+    }
+
+    #[test]
+    fn test_fixed_scale_columns() {
+        use crate::utils::format::{Prefix, Units};
+
+        let units = UnitSystem::new(Units::Bits, Some(Prefix::Si), Scale::Fixed(2));
+        let mut table = BatchTable::new(0, false, units);
+        let output = table.render(&[row("eth0")]);
+        assert!(output.starts_with("time      iface         rxMbit/s  txMbit/s"));
+        // 1536 B/s = 0.012288 Mbit/s
+        assert!(output
+            .lines()
+            .nth(1)
+            .unwrap()
+            .contains("      0.01      0.00"));
+    }
+}
@@ -0,0 +1,330 @@
+// Nagios/Icinga 플러그인 출력 모듈 (nbmon check)
+// 한 인터페이스를 일정 시간 측정한 평균을 임계값과 비교해서 표준 플러그인 형식의 한 줄과 종료 코드를 만듦
+// 모니터링 서버가 해석하는 출력이므로 메시지 언어(--lang)와 관계없이 항상 영어
+//
+//   NBMON WARNING - eth0 rx 850.0 Mbit/s (> 800.0 Mbit/s), tx 1.2 Mbit/s, errors 0.00/s, drops 0.00/s | rx=106250000B;100000000;118750000;0;125000000 ...
+//
+// 성능 데이터(perfdata)의 속도는 단위 설정과 관계없이 바이트/초 (UOM "B")
+
+use crate::network::{interface::NetworkInterface, stats::InterfaceStats};
+use crate::utils::format::UnitSystem;
+
+/// 출력 앞에 붙는 서비스 이름
+const SERVICE: &str = "NBMON";
+
+/// 플러그인 상태 (심각한 순서로 정렬됨)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    Ok,
+    Warning,
+    Critical,
+    Unknown,
+}
+
+impl Status {
+    /// 플러그인 종료 코드 (0: OK, 1: WARNING, 2: CRITICAL, 3: UNKNOWN)
+    pub fn code(self) -> i32 {
+        match self {
+            Status::Ok => 0,
+            Status::Warning => 1,
+            Status::Critical => 2,
+            Status::Unknown => 3,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Status::Ok => "OK",
+            Status::Warning => "WARNING",
+            Status::Critical => "CRITICAL",
+            Status::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+/// 한 지표의 경고/위험 임계값 (값이 임계값보다 크면 경보)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Threshold {
+    pub warn: Option<f64>,
+    pub crit: Option<f64>,
+}
+
+impl Threshold {
+    pub fn status(&self, value: f64) -> Status {
+        if self.crit.is_some_and(|crit| value > crit) {
+            Status::Critical
+        } else if self.warn.is_some_and(|warn| value > warn) {
+            Status::Warning
+        } else {
+            Status::Ok
+        }
+    }
+
+    // 경보를 일으킨 임계값
+    fn limit(&self, status: Status) -> Option<f64> {
+        match status {
+            Status::Critical => self.crit,
+            Status::Warning => self.warn,
+            _ => None,
+        }
+    }
+}
+
+/// 지표별 임계값 (속도는 바이트/초, 에러와 드롭은 초당 개수)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Thresholds {
+    pub rx: Threshold,
+    pub tx: Threshold,
+    pub errors: Threshold,
+    pub drops: Threshold,
+}
+
+/// 측정 구간 동안의 평균값
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub interface: String,
+    pub is_up: bool,
+    pub rx_bytes_per_sec: f64,
+    pub tx_bytes_per_sec: f64,
+    /// 수신+송신 에러 (초당)
+    pub errors_per_sec: f64,
+    /// 수신+송신 드롭 (초당)
+    pub drops_per_sec: f64,
+    /// 링크 속도 (바이트/초, 모르면 None) - perfdata의 최댓값
+    pub link_bytes_per_sec: Option<f64>,
+}
+
+impl Measurement {
+    /// 구간 시작/끝 카운터로 평균 계산 (시간 정보가 없거나 경과 시간이 0이면 None)
+    pub fn between(
+        interface: &NetworkInterface,
+        previous: &InterfaceStats,
+        current: &InterfaceStats,
+    ) -> Option<Self> {
+        let elapsed = current
+            .timestamp?
+            .duration_since(previous.timestamp?)
+            .as_secs_f64();
+        if elapsed <= 0.0 {
+            return None;
+        }
+
+        // 카운터가 줄었으면(인터페이스 재생성 등) 0으로 봄
+        let per_sec =
+            |current: u64, previous: u64| current.saturating_sub(previous) as f64 / elapsed;
+
+        Some(Self {
+            interface: interface.name.clone(),
+            // Linux의 루프백은 operstate가 unknown이라 DOWN으로 보이므로 항상 UP으로 취급
+            is_up: interface.is_up || interface.is_loopback,
+            rx_bytes_per_sec: per_sec(current.bytes_received, previous.bytes_received),
+            tx_bytes_per_sec: per_sec(current.bytes_sent, previous.bytes_sent),
+            errors_per_sec: per_sec(current.errors_in, previous.errors_in)
+                + per_sec(current.errors_out, previous.errors_out),
+            drops_per_sec: per_sec(current.drops_in, previous.drops_in)
+                + per_sec(current.drops_out, previous.drops_out),
+            link_bytes_per_sec: (interface.speed > 0).then(|| interface.speed as f64 / 8.0),
+        })
+    }
+}
+
+/// 판정 결과 (상태와 플러그인 출력 한 줄)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckResult {
+    pub status: Status,
+    pub output: String,
+}
+
+impl CheckResult {
+    /// 측정값을 임계값과 비교 (DOWN 상태인 인터페이스는 항상 CRITICAL)
+    pub fn evaluate(measurement: &Measurement, thresholds: &Thresholds, units: UnitSystem) -> Self {
+        // (이름, 값, 임계값, 속도인지) - 속도가 아니면 초당 개수
+        let metrics = [
+            ("rx", measurement.rx_bytes_per_sec, &thresholds.rx, true),
+            ("tx", measurement.tx_bytes_per_sec, &thresholds.tx, true),
+            (
+                "errors",
+                measurement.errors_per_sec,
+                &thresholds.errors,
+                false,
+            ),
+            ("drops", measurement.drops_per_sec, &thresholds.drops, false),
+        ];
+        let format = |value: f64, is_rate: bool| {
+            if is_rate {
+                units.format_rate(value)
+            } else {
+                format!("{:.2}/s", value)
+            }
+        };
+
+        let mut status = Status::Ok;
+        let mut details = Vec::new();
+        let mut perfdata = Vec::new();
+        for (name, value, threshold, is_rate) in metrics {
+            let metric_status = threshold.status(value);
+            status = status.max(metric_status);
+            details.push(match threshold.limit(metric_status) {
+                Some(limit) => format!(
+                    "{} {} (> {})",
+                    name,
+                    format(value, is_rate),
+                    format(limit, is_rate)
+                ),
+                None => format!("{} {}", name, format(value, is_rate)),
+            });
+
+            let (uom, max) = if is_rate {
+                ("B", measurement.link_bytes_per_sec)
+            } else {
+                ("", None)
+            };
+            perfdata.push(perfdata_item(name, value, uom, threshold, max));
+        }
+
+        let mut summary = details.join(", ");
+        if !measurement.is_up {
+            status = Status::Critical;
+            summary = format!("down, {}", summary);
+        }
+
+        Self {
+            status,
+            output: format!(
+                "{} {} - {} {} | {}",
+                SERVICE,
+                status.label(),
+                measurement.interface,
+                summary,
+                perfdata.join(" ")
+            ),
+        }
+    }
+
+    /// 측정하지 못했을 때의 결과
+    pub fn unknown(message: &str) -> Self {
+        Self {
+            status: Status::Unknown,
+            output: format!("{} {} - {}", SERVICE, Status::Unknown.label(), message),
+        }
+    }
+}
+
+// perfdata 한 항목: 'label'=value[UOM];[warn];[crit];[min];[max]
+fn perfdata_item(
+    label: &str,
+    value: f64,
+    uom: &str,
+    threshold: &Threshold,
+    max: Option<f64>,
+) -> String {
+    let number = |value: Option<f64>| value.map(|v| format!("{}", round(v))).unwrap_or_default();
+    format!(
+        "{}={}{};{};{};0;{}",
+        label,
+        round(value),
+        uom,
+        number(threshold.warn),
+        number(threshold.crit),
+        number(max)
+    )
+}
+
+// 바이트 단위는 정수, 초당 개수는 소수 둘째 자리까지 (불필요한 0은 생략)
+fn round(value: f64) -> f64 {
+    if value >= 100.0 {
+        value.round()
+    } else {
+        (value * 100.0).round() / 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::format::{Scale, Units};
+    use std::time::{Duration, Instant};
+
+    fn measurement(rx: f64, errors: f64) -> Measurement {
+        Measurement {
+            interface: "eth0".to_string(),
+            is_up: true,
+            rx_bytes_per_sec: rx,
+            tx_bytes_per_sec: 1_000.0,
+            errors_per_sec: errors,
+            drops_per_sec: 0.0,
+            link_bytes_per_sec: Some(125_000_000.0),
+        }
+    }
+
+    #[test]
+    fn test_evaluate_thresholds() {
+        let units = UnitSystem::new(Units::Bits, None, Scale::parse("M").unwrap());
+        let thresholds = Thresholds {
+            rx: Threshold {
+                warn: Some(100_000_000.0),
+                crit: Some(118_750_000.0),
+            },
+            errors: Threshold {
+                warn: Some(10.0),
+                crit: None,
+            },
+            ..Default::default()
+        };
+
+        let ok = CheckResult::evaluate(&measurement(1_250_000.0, 0.0), &thresholds, units);
+        assert_eq!(ok.status, Status::Ok);
+        assert_eq!(
+            ok.output,
+            "NBMON OK - eth0 rx 10.0 Mbit/s, tx 0.0 Mbit/s, errors 0.00/s, drops 0.00/s \
+             | rx=1250000B;100000000;118750000;0;125000000 tx=1000B;;;0;125000000 \
+             errors=0;10;;0; drops=0;;;0;"
+        );
+
+        let warning = CheckResult::evaluate(&measurement(106_250_000.0, 0.5), &thresholds, units);
+        assert_eq!(warning.status, Status::Warning);
+        assert!(warning
+            .output
+            .starts_with("NBMON WARNING - eth0 rx 850.0 Mbit/s (> 800.0 Mbit/s),"));
+
+        // 가장 심각한 상태가 전체 상태
+        let critical = CheckResult::evaluate(&measurement(120_000_000.0, 12.5), &thresholds, units);
+        assert_eq!(critical.status, Status::Critical);
+        assert!(critical.output.contains("(> 950.0 Mbit/s)"));
+        assert!(critical.output.contains("errors 12.50/s (> 10.00/s)"));
+        assert_eq!(critical.status.code(), 2);
+
+        let mut down = measurement(0.0, 0.0);
+        down.is_up = false;
+        let down = CheckResult::evaluate(&down, &thresholds, units);
+        assert_eq!(down.status, Status::Critical);
+        assert!(down.output.starts_with("NBMON CRITICAL - eth0 down, rx"));
+    }
+
+    #[test]
+    fn test_measurement_between() {
+        let mut interface = NetworkInterface::new(1, "eth0".to_string(), "eth0".to_string());
+        interface.is_up = true;
+        let start = Instant::now();
+        let previous = InterfaceStats {
+            bytes_received: 1_000,
+            errors_in: 5,
+            timestamp: Some(start),
+            ..InterfaceStats::new(1)
+        };
+        let current = InterfaceStats {
+            bytes_received: 21_000,
+            errors_in: 25,
+            drops_out: 4,
+            timestamp: Some(start + Duration::from_secs(10)),
+            ..InterfaceStats::new(1)
+        };
+
+        let measurement = Measurement::between(&interface, &previous, &current).unwrap();
+        assert_eq!(measurement.rx_bytes_per_sec, 2_000.0);
+        assert_eq!(measurement.errors_per_sec, 2.0);
+        assert_eq!(measurement.drops_per_sec, 0.4);
+        assert_eq!(measurement.link_bytes_per_sec, None);
+        assert!(Measurement::between(&interface, &current, &current).is_none());
+    }
+}
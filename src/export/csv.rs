@@ -0,0 +1,134 @@
+// CSV 내보내기 모듈
+// 매 틱마다 인터페이스별로 한 행(타임스탬프, 커널 카운터, 계산된 속도)을 파일 끝에 추가
+// 한 틱의 행들은 한 번에 쓰고 바로 flush하므로 프로세스가 강제 종료되어도 파일이 깨지지 않음
+
+use anyhow::{bail, Context, Result};
+use std::fmt::Write as _;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::network::sampler::Sampler;
+use crate::utils::time::DateTime;
+
+/// CSV 열 이름 (순서 고정 - 새 열은 항상 끝에 추가)
+pub const CSV_COLUMNS: &[&str] = &[
+    "timestamp",
+    "time",
+    "interface",
+    "index",
+    "bytes_received",
+    "bytes_sent",
+    "packets_received",
+    "packets_sent",
+    "errors_in",
+    "errors_out",
+    "rx_bytes_per_sec",
+    "tx_bytes_per_sec",
+    "drops_in",
+    "drops_out",
+];
+
+/// 틱마다 행을 추가하는 CSV 기록기
+pub struct CsvWriter {
+    path: PathBuf,
+    file: File,
+}
+
+impl CsvWriter {
+    /// 파일을 추가 모드로 열기
+    /// 새 파일(또는 빈 파일)이면 머리글을 쓰고, 기존 파일이면 머리글이 같은지 확인
+    pub fn open(path: &Path) -> Result<Self> {
+        let header = CSV_COLUMNS.join(",");
+
+        if let Ok(existing) = File::open(path) {
+            let mut first_line = String::new();
+            BufReader::new(existing)
+                .read_line(&mut first_line)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            if !first_line.is_empty() && first_line.trim_end() != header {
+                bail!(
+                    "{} already has different CSV columns; use a new file",
+                    path.display()
+                );
+            }
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+
+        if file.metadata().map(|meta| meta.len() == 0).unwrap_or(false) {
+            writeln!(file, "{}", header)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+        })
+    }
+
+    /// 현재 틱의 모든 인터페이스 행을 기록
+    /// 첫 틱처럼 속도를 아직 계산하지 못한 인터페이스는 속도 열을 비워 둠
+    pub fn write_tick(&mut self, sampler: &Sampler) -> Result<()> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs_f64())
+            .unwrap_or(0.0);
+        let time = DateTime::local(now as i64).to_string();
+
+        let mut rows = String::new();
+        let rows_iter = sampler
+            .interfaces()
+            .iter()
+            .zip(sampler.all_stats())
+            .zip(sampler.all_bandwidth());
+        for ((interface, stats), bandwidth) in rows_iter {
+            let (rx_rate, tx_rate) = match bandwidth {
+                Some(bandwidth) => (
+                    format!("{:.1}", bandwidth.download_rate),
+                    format!("{:.1}", bandwidth.upload_rate),
+                ),
+                None => (String::new(), String::new()),
+            };
+
+            // writeln!은 String에 대해 실패하지 않으므로 결과를 무시
+            let _ = writeln!(
+                rows,
+                "{:.3},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+                now,
+                time,
+                escape(&interface.name),
+                interface.index,
+                stats.bytes_received,
+                stats.bytes_sent,
+                stats.packets_received,
+                stats.packets_sent,
+                stats.errors_in,
+                stats.errors_out,
+                rx_rate,
+                tx_rate,
+                stats.drops_in,
+                stats.drops_out
+            );
+        }
+
+        self.file
+            .write_all(rows.as_bytes())
+            .and_then(|()| self.file.flush())
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+}
+
+// 쉼표/따옴표/줄바꿈이 들어 있는 값은 따옴표로 감쌈 (RFC 4180)
+fn escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
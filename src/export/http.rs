@@ -0,0 +1,250 @@
+// 최소한의 HTTP/1.1 서버 모듈
+// 메트릭 노출(nbmon serve)처럼 GET 요청 몇 종류만 처리하면 되는 곳에서 웹 프레임워크 없이 사용
+// 연결마다 스레드 하나가 요청 하나를 처리하고 연결을 닫음 (keep-alive 없음)
+// 웹 대시보드(nbmon web)처럼 연결을 열어 두고 계속 보내야 하면 이벤트 스트림(Server-Sent Events)으로 응답
+
+use anyhow::Result;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+// 느린 클라이언트가 스레드를 오래 붙잡지 않도록 하는 읽기/쓰기 제한 시간
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+// 요청 헤더 줄 수 제한 (헤더 내용은 사용하지 않음)
+const MAX_HEADER_LINES: usize = 100;
+
+// 요청 줄과 헤더 한 줄의 최대 길이 (줄바꿈 없이 계속 보내도 메모리가 늘지 않도록)
+const MAX_LINE: u64 = 8 * 1024;
+
+/// 요청 줄에서 필요한 부분만 해석한 요청
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    /// 쿼리 문자열을 뺀 경로 (예: "/metrics")
+    pub path: String,
+    /// ? 뒤의 쿼리 문자열 (없으면 빈 문자열)
+    pub query: String,
+}
+
+/// 응답 (본문은 한 번에 보내고 연결을 닫음)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    pub fn ok(content_type: &'static str, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status: 200,
+            content_type,
+            body: body.into(),
+        }
+    }
+
+    pub fn not_found() -> Self {
+        Self::error(404)
+    }
+
+    pub fn method_not_allowed() -> Self {
+        Self::error(405)
+    }
+
+    fn error(status: u16) -> Self {
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            body: format!("{} {}\n", status, reason(status)).into_bytes(),
+        }
+    }
+}
+
+/// 이벤트 스트림에 이어서 쓰는 함수 (클라이언트가 연결을 끊으면 쓰기 에러로 끝남)
+pub type EventStream = Box<dyn FnOnce(&mut dyn Write) -> io::Result<()> + Send>;
+
+/// 핸들러의 응답 - 한 번에 보내는 응답 또는 연결을 넘겨받아 계속 쓰는 이벤트 스트림
+pub enum Reply {
+    Response(Response),
+    Events(EventStream),
+}
+
+impl From<Response> for Reply {
+    fn from(response: Response) -> Self {
+        Reply::Response(response)
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Error",
+    }
+}
+
+/// 요청 줄과 헤더를 읽음 (본문은 읽지 않음)
+pub fn read_request<R: BufRead>(reader: &mut R) -> io::Result<Request> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "malformed HTTP request");
+
+    let mut line = String::new();
+    read_line(reader, &mut line)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target), Some(_version)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let request = Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        query: query.to_string(),
+    };
+
+    // 빈 줄까지 헤더를 읽고 버림
+    for _ in 0..MAX_HEADER_LINES {
+        if read_line(reader, &mut line)? == 0 || line.trim_end().is_empty() {
+            return Ok(request);
+        }
+    }
+    Err(invalid())
+}
+
+// 한 줄을 읽음 (MAX_LINE을 넘으면 InvalidData - 400으로 응답)
+fn read_line<R: BufRead>(reader: &mut R, line: &mut String) -> io::Result<usize> {
+    line.clear();
+    let read = reader.by_ref().take(MAX_LINE + 1).read_line(line)?;
+    if read as u64 > MAX_LINE {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "HTTP request line is too long",
+        ));
+    }
+    Ok(read)
+}
+
+/// 상태 줄, 헤더, 본문을 기록
+pub fn write_response<W: Write>(out: &mut W, response: &Response) -> io::Result<()> {
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        reason(response.status),
+        response.content_type,
+        response.body.len()
+    );
+    if response.status == 405 {
+        head.push_str("Allow: GET\r\n");
+    }
+    head.push_str("\r\n");
+    out.write_all(head.as_bytes())?;
+    out.write_all(&response.body)?;
+    out.flush()
+}
+
+/// 이벤트 스트림의 상태 줄과 헤더 (본문 길이 없이 연결을 닫을 때까지 이어짐)
+pub fn write_event_stream_head<W: Write>(out: &mut W) -> io::Result<()> {
+    out.write_all(
+        b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\n\
+          Connection: close\r\n\r\n",
+    )?;
+    out.flush()
+}
+
+/// 이벤트 하나 (data는 한 줄이어야 함 - JSON은 serde_json이 줄바꿈 없이 만듦)
+pub fn write_event<W: Write + ?Sized>(out: &mut W, event: &str, data: &str) -> io::Result<()> {
+    // 이벤트가 여러 번에 나뉘어 쓰이지 않도록 한 번에 기록
+    out.write_all(format!("event: {}\ndata: {}\n\n", event, data).as_bytes())?;
+    out.flush()
+}
+
+/// 연결을 받을 때마다 스레드에서 handler로 응답 (GET 이외의 요청은 405)
+/// 연결 하나의 에러는 다른 연결에 영향을 주지 않으므로 무시하고 계속 받음
+pub fn serve<F, R>(listener: TcpListener, handler: F) -> Result<()>
+where
+    F: Fn(&Request) -> R + Send + Sync + 'static,
+    R: Into<Reply>,
+{
+    let handler = Arc::new(handler);
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                crate::debug_log!("Failed to accept connection: {}", e);
+                continue;
+            }
+        };
+        let handler = Arc::clone(&handler);
+        thread::spawn(move || {
+            if let Err(e) = handle_connection(stream, handler.as_ref()) {
+                crate::debug_log!("HTTP connection error: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
+
+fn handle_connection<F, R>(mut stream: TcpStream, handler: &F) -> io::Result<()>
+where
+    F: Fn(&Request) -> R,
+    R: Into<Reply>,
+{
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+    let reply = match read_request(&mut BufReader::new(&stream)) {
+        Ok(request) if request.method == "GET" => handler(&request).into(),
+        Ok(_) => Reply::Response(Response::method_not_allowed()),
+        Err(e) if e.kind() == io::ErrorKind::InvalidData => Reply::Response(Response::error(400)),
+        Err(e) => return Err(e),
+    };
+    match reply {
+        Reply::Response(response) => write_response(&mut stream, &response),
+        Reply::Events(events) => {
+            write_event_stream_head(&mut stream)?;
+            events(&mut stream)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_request_and_response() {
+        let raw = "GET /metrics?name=eth0 HTTP/1.1\r\nHost: localhost\r\nAccept: */*\r\n\r\n";
+        let request = read_request(&mut Cursor::new(raw)).unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/metrics");
+        assert_eq!(request.query, "name=eth0");
+
+        assert!(read_request(&mut Cursor::new("garbage\r\n\r\n")).is_err());
+        // 줄바꿈 없이 긴 요청 줄이나 헤더는 끝까지 읽지 않고 거부
+        let long = format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_LINE as usize));
+        let error = read_request(&mut Cursor::new(long)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let long = format!(
+            "GET / HTTP/1.1\r\nX: {}\r\n\r\n",
+            "a".repeat(MAX_LINE as usize)
+        );
+        assert!(read_request(&mut Cursor::new(long)).is_err());
+
+        let mut out = Vec::new();
+        write_response(&mut out, &Response::ok("text/plain", "hi")).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 2\r\n\
+             Connection: close\r\n\r\nhi"
+        );
+
+        let mut out = Vec::new();
+        write_event(&mut out, "tick", "{\"a\":1}").unwrap();
+        assert_eq!(out, b"event: tick\ndata: {\"a\":1}\n\n");
+    }
+}
@@ -0,0 +1,73 @@
+// JSON Lines 내보내기 모듈
+// 매 틱마다 모든 인터페이스의 메타데이터, 커널 카운터, 속도를 JSON 객체 한 줄로 출력
+// jq, Vector, fluent-bit 등으로 바로 파이프할 수 있도록 한 줄에 한 객체, 매 줄 flush
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::network::{
+    interface::NetworkInterface,
+    sampler::Sampler,
+    stats::{BandwidthStats, InterfaceStats},
+};
+
+/// 출력 형식 버전 - 필드를 제거하거나 의미를 바꾸면 증가 (필드 추가는 같은 버전)
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// 한 틱의 전체 스냅샷 (JSON 한 줄)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// 형식 버전 (SCHEMA_VERSION)
+    pub schema: u32,
+    /// 수집 시각 (Unix 초, 밀리초 포함)
+    pub timestamp: f64,
+    pub interfaces: Vec<InterfaceSnapshot>,
+}
+
+/// 인터페이스 하나의 상태
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterfaceSnapshot {
+    pub interface: NetworkInterface,
+    pub counters: InterfaceStats,
+    /// 속도 (첫 틱처럼 아직 계산하지 못했으면 null)
+    pub rates: Option<BandwidthStats>,
+}
+
+impl Snapshot {
+    /// Sampler의 현재 상태로 스냅샷 생성
+    pub fn from_sampler(sampler: &Sampler) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| (d.as_millis() as f64) / 1000.0)
+            .unwrap_or(0.0);
+
+        let interfaces = sampler
+            .interfaces()
+            .iter()
+            .zip(sampler.all_stats())
+            .zip(sampler.all_bandwidth())
+            .map(|((interface, counters), rates)| InterfaceSnapshot {
+                interface: interface.clone(),
+                counters: counters.clone(),
+                rates: rates.clone(),
+            })
+            .collect();
+
+        Self {
+            schema: SCHEMA_VERSION,
+            timestamp,
+            interfaces,
+        }
+    }
+
+    /// JSON 한 줄로 기록하고 flush (한 번의 write로 기록하여 줄이 잘리지 않도록 함)
+    pub fn write_line<W: Write>(&self, out: &mut W) -> Result<()> {
+        let mut line = serde_json::to_vec(self).context("Failed to serialize snapshot")?;
+        line.push(b'\n');
+        out.write_all(&line)
+            .and_then(|()| out.flush())
+            .context("Failed to write JSON output")
+    }
+}
@@ -0,0 +1,13 @@
+// 수집한 통계를 파일이나 표준 출력으로 내보내는 모듈
+// TUI와 헤드리스 모드가 같은 Sampler 데이터를 같은 형식으로 기록하도록 함
+
+pub mod batch; // sar 스타일 고정 폭 표 출력 (batch.rs)
+pub mod check; // Nagios/Icinga 플러그인 형식의 상태 판정과 perfdata (check.rs)
+pub mod csv; // 틱마다 인터페이스별 한 행씩 추가하는 CSV 기록 (csv.rs)
+pub mod http; // GET 요청만 처리하는 최소한의 HTTP/1.1 서버 (http.rs)
+pub mod json; // 틱마다 JSON 객체 한 줄을 출력하는 JSON Lines 스트림 (json.rs)
+pub mod mqtt; // Home Assistant 자동 발견을 포함한 MQTT 3.1.1 QoS 0 발행 (mqtt.rs)
+pub mod otlp; // OTLP/HTTP JSON으로 OpenTelemetry 컬렉터에 보내는 메트릭 내보내기 (otlp.rs)
+pub mod prometheus; // /metrics용 Prometheus 텍스트 노출 형식 (prometheus.rs)
+pub mod push; // Influx 라인 프로토콜 / Graphite 평문을 TCP/UDP로 묶어 보내는 푸시 출력 (push.rs)
+pub mod web; // 이벤트 스트림으로 실시간 그래프를 보내는 웹 대시보드 (web.rs, web.html)
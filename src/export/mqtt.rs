@@ -0,0 +1,516 @@
+// MQTT 발행 모듈 (nbmon mqtt)
+// 공유기, 홈랩 장비처럼 Prometheus 없이 MQTT 브로커만 있는 곳에서 대시보드(Home Assistant 등)로 보내도록
+// QoS 0 발행만 하면 되므로 MQTT 3.1.1의 CONNECT/PUBLISH/DISCONNECT만 직접 구현
+//
+//   nbmon/<host>/status           online / offline (retained, 비정상 종료 시 브로커가 유언 메시지로 offline)
+//   nbmon/<host>/<iface>/rx_bps   수신 속도 (비트/초)
+//   nbmon/<host>/<iface>/rx_bytes 수신 누적 바이트
+//   homeassistant/sensor/nbmon_<host>_<iface>_rx_bps/config  Home Assistant 자동 발견 (retained)
+
+use anyhow::{bail, Context, Result};
+use serde_json::json;
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use crate::network::{
+    interface::NetworkInterface,
+    sampler::Sampler,
+    stats::{BandwidthStats, InterfaceStats},
+};
+use crate::utils::net;
+
+/// MQTT 기본 포트
+pub const DEFAULT_PORT: u16 = 1883;
+
+// 연결/읽기/쓰기 제한 시간
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+// 가용성 메시지
+const ONLINE: &str = "online";
+const OFFLINE: &str = "offline";
+
+// CONNECT 가변 헤더의 프로토콜 이름과 레벨 (3.1.1)
+const PROTOCOL_NAME: &[u8] = b"MQTT";
+const PROTOCOL_LEVEL: u8 = 4;
+
+// 고정 헤더의 패킷 종류
+const CONNECT: u8 = 0x10;
+const CONNACK: u8 = 0x20;
+const PUBLISH: u8 = 0x30;
+const DISCONNECT: u8 = 0xE0;
+
+/// 발행할 메시지 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub topic: String,
+    pub payload: String,
+    /// 브로커가 마지막 값을 보관했다가 새 구독자에게 바로 보냄
+    pub retain: bool,
+}
+
+/// 센서 하나의 토픽 이름과 Home Assistant 표시 정보
+struct Sensor {
+    field: &'static str,
+    label: &'static str,
+    unit: &'static str,
+    device_class: &'static str,
+    state_class: &'static str,
+    /// 값 (속도가 아직 없으면 None)
+    value: fn(&InterfaceStats, Option<&BandwidthStats>) -> Option<String>,
+}
+
+// 인터페이스마다 발행하는 값 (속도는 비트/초, 누적은 바이트)
+const SENSORS: [Sensor; 4] = [
+    Sensor {
+        field: "rx_bps",
+        label: "download",
+        unit: "bit/s",
+        device_class: "data_rate",
+        state_class: "measurement",
+        value: |_, r| r.map(|r| format!("{:.0}", r.download_rate * 8.0)),
+    },
+    Sensor {
+        field: "tx_bps",
+        label: "upload",
+        unit: "bit/s",
+        device_class: "data_rate",
+        state_class: "measurement",
+        value: |_, r| r.map(|r| format!("{:.0}", r.upload_rate * 8.0)),
+    },
+    Sensor {
+        field: "rx_bytes",
+        label: "received",
+        unit: "B",
+        device_class: "data_size",
+        state_class: "total_increasing",
+        value: |s, _| Some(s.bytes_received.to_string()),
+    },
+    Sensor {
+        field: "tx_bytes",
+        label: "sent",
+        unit: "B",
+        device_class: "data_size",
+        state_class: "total_increasing",
+        value: |s, _| Some(s.bytes_sent.to_string()),
+    },
+];
+
+/// 토픽과 발견 메시지를 만드는 설정
+#[derive(Debug, Clone)]
+pub struct Topics {
+    /// 토픽의 첫 부분 (기본 "nbmon")
+    prefix: String,
+    host: String,
+    /// Home Assistant 발견 접두사 (None이면 발견 메시지를 보내지 않음)
+    discovery_prefix: Option<String>,
+}
+
+impl Topics {
+    pub fn new(prefix: &str, host: &str, discovery_prefix: Option<&str>) -> Self {
+        Self {
+            prefix: prefix.trim_end_matches('/').to_string(),
+            host: topic_level(host),
+            discovery_prefix: discovery_prefix.map(|p| p.trim_end_matches('/').to_string()),
+        }
+    }
+
+    /// 가용성 토픽 (retained online/offline)
+    pub fn status(&self) -> String {
+        format!("{}/{}/status", self.prefix, self.host)
+    }
+
+    pub fn availability(&self, online: bool) -> Message {
+        Message {
+            topic: self.status(),
+            payload: if online { ONLINE } else { OFFLINE }.to_string(),
+            retain: true,
+        }
+    }
+
+    fn state_topic(&self, interface: &str, field: &str) -> String {
+        format!(
+            "{}/{}/{}/{}",
+            self.prefix,
+            self.host,
+            topic_level(interface),
+            field
+        )
+    }
+
+    /// Sampler의 현재 상태를 값 메시지로 변환
+    pub fn states(&self, sampler: &Sampler) -> Vec<Message> {
+        self.interface_states(
+            sampler.interfaces(),
+            sampler.all_stats(),
+            sampler.all_bandwidth(),
+        )
+    }
+
+    /// 인터페이스마다 속도와 누적 값 메시지 (세 목록은 같은 순서)
+    pub fn interface_states(
+        &self,
+        interfaces: &[NetworkInterface],
+        stats: &[InterfaceStats],
+        bandwidth: &[Option<BandwidthStats>],
+    ) -> Vec<Message> {
+        let mut messages = Vec::new();
+        for ((interface, stats), rates) in interfaces.iter().zip(stats).zip(bandwidth) {
+            for sensor in &SENSORS {
+                if let Some(value) = (sensor.value)(stats, rates.as_ref()) {
+                    messages.push(Message {
+                        topic: self.state_topic(&interface.name, sensor.field),
+                        payload: value,
+                        retain: false,
+                    });
+                }
+            }
+        }
+        messages
+    }
+
+    /// 연결 직후 보낼 메시지 (발견 메시지 후 online)
+    pub fn announcements(&self, interfaces: &[NetworkInterface]) -> Vec<Message> {
+        let mut messages = self.discovery(interfaces);
+        messages.push(self.availability(true));
+        messages
+    }
+
+    /// Home Assistant MQTT 발견 메시지 (인터페이스마다 센서 4개, retained)
+    pub fn discovery(&self, interfaces: &[NetworkInterface]) -> Vec<Message> {
+        let Some(discovery_prefix) = &self.discovery_prefix else {
+            return Vec::new();
+        };
+        let device_id = object_id(&format!("nbmon_{}", self.host));
+        let device = json!({
+            "identifiers": [device_id],
+            "name": format!("nbmon {}", self.host),
+            "manufacturer": "nbmon",
+            "sw_version": env!("CARGO_PKG_VERSION"),
+        });
+
+        let mut messages = Vec::new();
+        for interface in interfaces {
+            for sensor in &SENSORS {
+                let unique_id = object_id(&format!(
+                    "{}_{}_{}",
+                    device_id, interface.name, sensor.field
+                ));
+                let config = json!({
+                    "name": format!("{} {}", interface.name, sensor.label),
+                    "unique_id": unique_id,
+                    "state_topic": self.state_topic(&interface.name, sensor.field),
+                    "availability_topic": self.status(),
+                    "unit_of_measurement": sensor.unit,
+                    "device_class": sensor.device_class,
+                    "state_class": sensor.state_class,
+                    "device": device,
+                });
+                messages.push(Message {
+                    topic: format!("{}/sensor/{}/config", discovery_prefix, unique_id),
+                    payload: config.to_string(),
+                    retain: true,
+                });
+            }
+        }
+        messages
+    }
+}
+
+// 토픽 한 단계 (구분자 /와 와일드카드 + #는 _로)
+fn topic_level(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '/' | '+' | '#' => '_',
+            c if c.is_whitespace() => '_',
+            c => c,
+        })
+        .collect()
+}
+
+// Home Assistant object_id (영숫자, -, _만)
+fn object_id(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// 브로커 주소 (host 또는 host:port, mqtt:// 접두사 허용)
+pub fn broker_address(value: &str) -> String {
+    let address = value.strip_prefix("mqtt://").unwrap_or(value);
+    net::with_default_port(address.trim_end_matches('/'), DEFAULT_PORT)
+}
+
+/// 연결 설정
+#[derive(Debug, Clone)]
+pub struct ConnectOptions {
+    pub client_id: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// 이 시간의 1.5배 동안 패킷이 없으면 브로커가 연결을 끊고 유언 메시지를 발행
+    /// PINGREQ를 보내지 않으므로 발행 간격보다 길어야 함
+    pub keep_alive: Duration,
+    /// 비정상 종료 시 브로커가 발행할 메시지
+    pub will: Option<Message>,
+}
+
+/// QoS 0 발행만 하는 MQTT 3.1.1 클라이언트
+pub struct Client {
+    stream: TcpStream,
+}
+
+impl Client {
+    /// 브로커에 연결하고 CONNACK를 확인
+    pub fn connect(address: &str, options: &ConnectOptions) -> Result<Self> {
+        let mut last_error = None;
+        let mut stream = None;
+        for socket in address
+            .to_socket_addrs()
+            .with_context(|| format!("Failed to resolve {}", address))?
+        {
+            match TcpStream::connect_timeout(&socket, IO_TIMEOUT) {
+                Ok(s) => {
+                    stream = Some(s);
+                    break;
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+        let mut stream = match (stream, last_error) {
+            (Some(stream), _) => stream,
+            (None, Some(e)) => {
+                return Err(e).with_context(|| format!("Failed to connect to {}", address))
+            }
+            (None, None) => bail!("{} did not resolve to any address", address),
+        };
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+        stream
+            .write_all(&connect_packet(options))
+            .with_context(|| format!("Failed to send CONNECT to {}", address))?;
+        let mut connack = [0u8; 4];
+        stream
+            .read_exact(&mut connack)
+            .with_context(|| format!("No CONNACK from {}", address))?;
+        if connack[0] != CONNACK || connack[1] != 2 {
+            bail!("{} sent an unexpected reply to CONNECT", address);
+        }
+        if connack[3] != 0 {
+            bail!(
+                "{} refused the connection: {}",
+                address,
+                connack_reason(connack[3])
+            );
+        }
+
+        Ok(Self { stream })
+    }
+
+    pub fn publish(&mut self, message: &Message) -> Result<()> {
+        self.stream
+            .write_all(&publish_packet(message))
+            .with_context(|| format!("Failed to publish to {}", message.topic))
+    }
+
+    /// 정상 종료 (브로커는 유언 메시지를 발행하지 않음)
+    pub fn disconnect(mut self) -> Result<()> {
+        self.stream
+            .write_all(&[DISCONNECT, 0])
+            .context("Failed to send DISCONNECT")
+    }
+}
+
+// CONNACK 반환 코드 설명
+fn connack_reason(code: u8) -> &'static str {
+    match code {
+        1 => "unacceptable protocol version",
+        2 => "client identifier rejected",
+        3 => "server unavailable",
+        4 => "bad user name or password",
+        5 => "not authorized",
+        _ => "unknown reason",
+    }
+}
+
+// CONNECT 패킷 (clean session, 유언 메시지는 QoS 0)
+fn connect_packet(options: &ConnectOptions) -> Vec<u8> {
+    let mut flags = 0x02; // clean session
+    let mut body = Vec::new();
+    put_bytes(&mut body, PROTOCOL_NAME);
+    body.push(PROTOCOL_LEVEL);
+    if let Some(will) = &options.will {
+        flags |= 0x04;
+        if will.retain {
+            flags |= 0x20;
+        }
+    }
+    if options.username.is_some() {
+        flags |= 0x80;
+        if options.password.is_some() {
+            flags |= 0x40;
+        }
+    }
+    body.push(flags);
+    let keep_alive = options.keep_alive.as_secs().min(u16::MAX as u64) as u16;
+    body.extend_from_slice(&keep_alive.to_be_bytes());
+
+    // 페이로드: 클라이언트 ID, 유언 토픽/메시지, 사용자 이름, 비밀번호 순서
+    put_bytes(&mut body, options.client_id.as_bytes());
+    if let Some(will) = &options.will {
+        put_bytes(&mut body, will.topic.as_bytes());
+        put_bytes(&mut body, will.payload.as_bytes());
+    }
+    if let Some(username) = &options.username {
+        put_bytes(&mut body, username.as_bytes());
+        if let Some(password) = &options.password {
+            put_bytes(&mut body, password.as_bytes());
+        }
+    }
+    packet(CONNECT, body)
+}
+
+// QoS 0 PUBLISH 패킷 (패킷 ID 없음)
+fn publish_packet(message: &Message) -> Vec<u8> {
+    let mut body = Vec::new();
+    put_bytes(&mut body, message.topic.as_bytes());
+    body.extend_from_slice(message.payload.as_bytes());
+    packet(PUBLISH | u8::from(message.retain), body)
+}
+
+// 고정 헤더(종류 + 남은 길이) + 본문
+fn packet(header: u8, body: Vec<u8>) -> Vec<u8> {
+    let mut packet = vec![header];
+    // 남은 길이: 7비트씩, 다음 바이트가 있으면 최상위 비트 설정
+    let mut length = body.len();
+    loop {
+        let mut byte = (length % 128) as u8;
+        length /= 128;
+        if length > 0 {
+            byte |= 0x80;
+        }
+        packet.push(byte);
+        if length == 0 {
+            break;
+        }
+    }
+    packet.extend(body);
+    packet
+}
+
+// 길이(2바이트 빅 엔디언)가 앞에 붙은 문자열
+fn put_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u16).to_be_bytes());
+    out.extend_from_slice(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packets() {
+        let options = ConnectOptions {
+            client_id: "nbmon-web1".to_string(),
+            username: Some("user".to_string()),
+            password: Some("pw".to_string()),
+            keep_alive: Duration::from_secs(60),
+            will: Some(Message {
+                topic: "nbmon/web1/status".to_string(),
+                payload: "offline".to_string(),
+                retain: true,
+            }),
+        };
+        let connect = connect_packet(&options);
+        assert_eq!(connect[0], CONNECT);
+        assert_eq!(connect[1] as usize, connect.len() - 2);
+        assert_eq!(&connect[2..9], b"\x00\x04MQTT\x04");
+        // 사용자 이름 + 비밀번호 + 유언 retain + 유언 + clean session
+        assert_eq!(connect[9], 0x80 | 0x40 | 0x20 | 0x04 | 0x02);
+        assert_eq!(&connect[10..12], &[0, 60]);
+        assert_eq!(&connect[12..24], b"\x00\x0anbmon-web1");
+        assert!(connect.ends_with(b"\x00\x04user\x00\x02pw"));
+
+        let publish = publish_packet(&Message {
+            topic: "a/b".to_string(),
+            payload: "42".to_string(),
+            retain: true,
+        });
+        assert_eq!(publish, b"\x31\x07\x00\x03a/b42");
+
+        // 남은 길이가 127을 넘으면 여러 바이트
+        assert_eq!(&packet(PUBLISH, vec![0; 127])[..2], &[0x30, 127]);
+        assert_eq!(&packet(PUBLISH, vec![0; 128])[..3], &[0x30, 0x80, 0x01]);
+        assert_eq!(
+            &packet(PUBLISH, vec![0; 16_384])[..4],
+            &[0x30, 0x80, 0x80, 0x01]
+        );
+
+        assert_eq!(broker_address("mqtt://broker"), "broker:1883");
+        assert_eq!(broker_address("10.0.0.2:8883"), "10.0.0.2:8883");
+        assert_eq!(broker_address("[::1]"), "[::1]:1883");
+        assert_eq!(broker_address("[::1]:1884"), "[::1]:1884");
+    }
+
+    #[test]
+    fn test_topics_and_discovery() {
+        let mut eth0 = NetworkInterface::new(2, "eth0".to_string(), "eth0".to_string());
+        eth0.is_up = true;
+        let topics = Topics::new("nbmon", "web1.lan", Some("homeassistant"));
+        let stats = vec![InterfaceStats {
+            bytes_received: 1_500,
+            bytes_sent: 20,
+            ..InterfaceStats::new(2)
+        }];
+
+        // 첫 샘플 전에는 누적 값만
+        let states = topics.interface_states(std::slice::from_ref(&eth0), &stats, &[None]);
+        assert_eq!(states.len(), 2);
+        assert_eq!(states[0].topic, "nbmon/web1.lan/eth0/rx_bytes");
+        assert_eq!(states[0].payload, "1500");
+        assert!(!states[0].retain);
+
+        let rates = vec![Some(BandwidthStats {
+            download_rate: 1250.0,
+            ..Default::default()
+        })];
+        let states = topics.interface_states(std::slice::from_ref(&eth0), &stats, &rates);
+        assert_eq!(states[0].topic, "nbmon/web1.lan/eth0/rx_bps");
+        assert_eq!(states[0].payload, "10000");
+
+        assert_eq!(
+            topics.availability(false),
+            Message {
+                topic: "nbmon/web1.lan/status".to_string(),
+                payload: "offline".to_string(),
+                retain: true,
+            }
+        );
+
+        let discovery = topics.announcements(&[eth0]);
+        assert_eq!(discovery.len(), 5);
+        assert_eq!(discovery[4], topics.availability(true));
+        assert_eq!(
+            discovery[0].topic,
+            "homeassistant/sensor/nbmon_web1_lan_eth0_rx_bps/config"
+        );
+        assert!(discovery[0].retain);
+        let config: serde_json::Value = serde_json::from_str(&discovery[0].payload).unwrap();
+        assert_eq!(config["state_topic"], "nbmon/web1.lan/eth0/rx_bps");
+        assert_eq!(config["availability_topic"], "nbmon/web1.lan/status");
+        assert_eq!(config["unit_of_measurement"], "bit/s");
+        assert_eq!(config["device"]["identifiers"][0], "nbmon_web1_lan");
+        let config: serde_json::Value = serde_json::from_str(&discovery[2].payload).unwrap();
+        assert_eq!(config["state_class"], "total_increasing");
+
+        assert!(Topics::new("nbmon", "web1", None).discovery(&[]).is_empty());
+    }
+}
@@ -0,0 +1,480 @@
+// OpenTelemetry OTLP/HTTP 메트릭 내보내기 모듈 (nbmon otlp)
+// 컬렉터의 /v1/metrics로 ExportMetricsServiceRequest를 JSON 인코딩으로 POST
+// 카운터는 누적(cumulative) 단조 증가 Sum, 속도는 Gauge
+// 이름과 속성은 OTel 시맨틱 컨벤션의 system.network.* 메트릭을 따름
+//
+//   system.network.io      {network.interface.name="eth0", network.io.direction="receive"} 1500 (By)
+//   system.network.packets {network.interface.name="eth0", network.io.direction="transmit"} 12
+//
+// 누적 값을 보내므로 한 번 보내지 못해도 다음 전송에서 빠진 증가량이 모두 반영됨
+// 그래서 실패한 요청은 다시 보내지 않고 경고만 출력
+//
+// 커널 카운터는 부팅(또는 인터페이스 생성) 이후의 합계라서 언제부터 쌓였는지 알 수 없음
+// 그래서 계열마다 처음 본 시각을 시작 시각으로 삼고, 값이 줄면(인터페이스 재생성, 32비트 카운터 랩) 그 시각부터 새로 시작
+
+use anyhow::{bail, Context, Result};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::mpsc::{self, SyncSender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::i18n::{tf, Msg};
+use crate::network::{
+    interface::NetworkInterface,
+    sampler::Sampler,
+    stats::{BandwidthStats, InterfaceStats},
+};
+
+/// OTLP/HTTP 메트릭 경로 (엔드포인트에 경로가 없으면 붙임)
+pub const METRICS_PATH: &str = "/v1/metrics";
+
+// 요청 하나의 제한 시간
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+// 계측 범위(scope) 이름 - 리소스의 service.name과 같음
+const SCOPE: &str = "nbmon";
+
+// OTLP AggregationTemporality.CUMULATIVE
+const CUMULATIVE: u8 = 2;
+
+// 수신/송신 방향별 카운터 메트릭 하나
+struct Counter {
+    name: &'static str,
+    description: &'static str,
+    unit: &'static str,
+    receive: fn(&InterfaceStats) -> u64,
+    transmit: fn(&InterfaceStats) -> u64,
+}
+
+const COUNTERS: [Counter; 4] = [
+    Counter {
+        name: "system.network.io",
+        description: "Bytes transmitted and received.",
+        unit: "By",
+        receive: |s| s.bytes_received,
+        transmit: |s| s.bytes_sent,
+    },
+    Counter {
+        name: "system.network.packets",
+        description: "Packets transmitted and received.",
+        unit: "{packet}",
+        receive: |s| s.packets_received,
+        transmit: |s| s.packets_sent,
+    },
+    Counter {
+        name: "system.network.errors",
+        description: "Errors encountered.",
+        unit: "{error}",
+        receive: |s| s.errors_in,
+        transmit: |s| s.errors_out,
+    },
+    Counter {
+        name: "system.network.dropped",
+        description: "Packets dropped.",
+        unit: "{packet}",
+        receive: |s| s.drops_in,
+        transmit: |s| s.drops_out,
+    },
+];
+
+// 속도 게이지 (시맨틱 컨벤션에 없는 메트릭이라 nbmon. 접두사)
+const RATE_NAME: &str = "nbmon.network.io.rate";
+
+/// 엔드포인트 URL을 메트릭 URL로 (http://collector:4318 -> http://collector:4318/v1/metrics)
+/// 경로를 직접 지정했으면 그대로 사용
+pub fn metrics_url(endpoint: &str) -> String {
+    let endpoint = endpoint.trim_end_matches('/');
+    let after_scheme = endpoint
+        .split_once("://")
+        .map_or(endpoint, |(_, rest)| rest);
+    if after_scheme.contains('/') {
+        endpoint.to_string()
+    } else {
+        format!("{}{}", endpoint, METRICS_PATH)
+    }
+}
+
+/// ExportMetricsServiceRequest(JSON)를 만드는 인코더
+#[derive(Debug, Clone)]
+pub struct Encoder {
+    host: String,
+    /// 누적 Sum 계열(메트릭, 인터페이스, 방향)별 시작 시각과 마지막 값
+    series: HashMap<(&'static str, String, &'static str), (SystemTime, u64)>,
+}
+
+impl Encoder {
+    pub fn new(host: &str) -> Self {
+        Self {
+            host: host.to_string(),
+            series: HashMap::new(),
+        }
+    }
+
+    /// Sampler의 현재 상태를 요청 본문으로 변환
+    pub fn request(&mut self, sampler: &Sampler, time: SystemTime) -> Value {
+        self.encode_interfaces(
+            sampler.interfaces(),
+            sampler.all_stats(),
+            sampler.all_bandwidth(),
+            time,
+        )
+    }
+
+    /// 인터페이스 목록과 통계를 요청 본문으로 변환 (세 목록은 같은 순서)
+    pub fn encode_interfaces(
+        &mut self,
+        interfaces: &[NetworkInterface],
+        stats: &[InterfaceStats],
+        bandwidth: &[Option<BandwidthStats>],
+        time: SystemTime,
+    ) -> Value {
+        let now = unix_nanos(time);
+
+        let mut metrics: Vec<Value> = COUNTERS
+            .iter()
+            .map(|counter| {
+                let points: Vec<Value> = interfaces
+                    .iter()
+                    .zip(stats)
+                    .flat_map(|(interface, stats)| {
+                        [
+                            ("receive", (counter.receive)(stats)),
+                            ("transmit", (counter.transmit)(stats)),
+                        ]
+                        .map(|(direction, value)| {
+                            let start =
+                                self.start_time(counter.name, interface, direction, value, time);
+                            json!({
+                                "attributes": attributes(interface, direction),
+                                "startTimeUnixNano": unix_nanos(start),
+                                "timeUnixNano": now,
+                                // OTLP JSON에서 64비트 정수는 문자열
+                                "asInt": value.to_string(),
+                            })
+                        })
+                    })
+                    .collect();
+                json!({
+                    "name": counter.name,
+                    "description": counter.description,
+                    "unit": counter.unit,
+                    "sum": {
+                        "aggregationTemporality": CUMULATIVE,
+                        "isMonotonic": true,
+                        "dataPoints": points,
+                    },
+                })
+            })
+            .collect();
+
+        // 첫 샘플 전에는 속도가 없으므로 게이지를 생략
+        let rates: Vec<Value> = interfaces
+            .iter()
+            .zip(bandwidth)
+            .filter_map(|(interface, rates)| Some((interface, rates.as_ref()?)))
+            .flat_map(|(interface, rates)| {
+                [
+                    ("receive", rates.download_rate),
+                    ("transmit", rates.upload_rate),
+                ]
+                .map(|(direction, value)| {
+                    json!({
+                        "attributes": attributes(interface, direction),
+                        "timeUnixNano": now,
+                        "asDouble": value,
+                    })
+                })
+            })
+            .collect();
+        if !rates.is_empty() {
+            metrics.push(json!({
+                "name": RATE_NAME,
+                "description": "Transmit and receive rate over the last sampling interval.",
+                "unit": "By/s",
+                "gauge": { "dataPoints": rates },
+            }));
+        }
+
+        json!({
+            "resourceMetrics": [{
+                "resource": {
+                    "attributes": [
+                        string_attribute("service.name", SCOPE),
+                        string_attribute("service.version", env!("CARGO_PKG_VERSION")),
+                        string_attribute("host.name", &self.host),
+                    ],
+                },
+                "scopeMetrics": [{
+                    "scope": { "name": SCOPE, "version": env!("CARGO_PKG_VERSION") },
+                    "metrics": metrics,
+                }],
+            }],
+        })
+    }
+
+    // 누적 계열의 시작 시각 - 처음 보거나 값이 줄었으면 지금부터 새 계열
+    fn start_time(
+        &mut self,
+        metric: &'static str,
+        interface: &NetworkInterface,
+        direction: &'static str,
+        value: u64,
+        time: SystemTime,
+    ) -> SystemTime {
+        let key = (metric, interface.name.clone(), direction);
+        let entry = self.series.entry(key).or_insert((time, value));
+        if value < entry.1 {
+            entry.0 = time;
+        }
+        entry.1 = value;
+        entry.0
+    }
+}
+
+// 데이터 포인트 속성 (인터페이스 이름과 방향)
+fn attributes(interface: &NetworkInterface, direction: &str) -> Value {
+    json!([
+        string_attribute("network.interface.name", &interface.name),
+        string_attribute("network.io.direction", direction),
+    ])
+}
+
+fn string_attribute(key: &str, value: &str) -> Value {
+    json!({ "key": key, "value": { "stringValue": value } })
+}
+
+// 유닉스 시각 나노초 (OTLP JSON의 fixed64는 문자열)
+fn unix_nanos(time: SystemTime) -> String {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos()
+        .to_string()
+}
+
+/// 요청 본문을 백그라운드 스레드에서 POST하는 전송기
+/// 샘플링 루프는 본문을 넘기기만 하고 네트워크를 기다리지 않음
+pub struct Exporter {
+    sender: Option<SyncSender<Vec<u8>>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Exporter {
+    /// url: 메트릭 URL, headers: 추가 요청 헤더 (인증 토큰 등)
+    pub fn start(url: String, headers: Vec<(String, String)>) -> Result<Self> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .context("Failed to create HTTP client")?;
+
+        // 보내는 중인 요청 하나만 있으면 되므로 대기열 길이는 1
+        let (sender, receiver) = mpsc::sync_channel::<Vec<u8>>(1);
+        let worker = thread::spawn(move || {
+            for body in receiver {
+                if let Err(e) = post(&client, &url, &headers, body) {
+                    // reqwest 에러는 같은 원인을 여러 번 감싸므로 맨 위와 근본 원인만 출력
+                    let message = if e.chain().count() > 1 {
+                        format!("{}: {}", e, e.root_cause())
+                    } else {
+                        e.to_string()
+                    };
+                    eprintln!(
+                        "{}",
+                        tf(Msg::Warning, &[&tf(Msg::OtlpExportFailed, &[&message])])
+                    );
+                }
+            }
+        });
+
+        Ok(Self {
+            sender: Some(sender),
+            worker: Some(worker),
+        })
+    }
+
+    /// 본문을 대기열에 넣음 (이전 요청이 아직 밀려 있으면 건너뛰고 false)
+    /// 누적 값이므로 건너뛴 전송은 다음 전송이 대신함
+    pub fn export(&self, request: &Value) -> bool {
+        self.sender
+            .as_ref()
+            .is_some_and(|sender| sender.try_send(request.to_string().into_bytes()).is_ok())
+    }
+
+    /// 대기열에 남은 요청을 보내고 스레드를 정리
+    pub fn finish(mut self) {
+        self.sender.take();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn post(
+    client: &reqwest::blocking::Client,
+    url: &str,
+    headers: &[(String, String)],
+    body: Vec<u8>,
+) -> Result<()> {
+    let mut request = client
+        .post(url)
+        .header("Content-Type", "application/json")
+        .body(body);
+    for (name, value) in headers {
+        request = request.header(name.as_str(), value.as_str());
+    }
+
+    let response = request
+        .send()
+        .with_context(|| format!("Failed to send request to {}", url))?;
+    if !response.status().is_success() {
+        bail!("{} responded with status {}", url, response.status());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    fn sample() -> (Vec<NetworkInterface>, Vec<InterfaceStats>) {
+        let eth0 = NetworkInterface::new(2, "eth0".to_string(), "eth0".to_string());
+        let stats = InterfaceStats {
+            bytes_received: 1_500,
+            bytes_sent: 20,
+            drops_in: 3,
+            ..InterfaceStats::new(2)
+        };
+        (vec![eth0], vec![stats])
+    }
+
+    #[test]
+    fn test_encode_interfaces() {
+        let (interfaces, stats) = sample();
+        let start = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let mut encoder = Encoder::new("web1");
+        let rates = vec![Some(BandwidthStats {
+            download_rate: 12.5,
+            ..Default::default()
+        })];
+        let request =
+            encoder.encode_interfaces(&interfaces, &stats, &rates, start + Duration::from_secs(10));
+
+        let resource = &request["resourceMetrics"][0];
+        assert_eq!(
+            resource["resource"]["attributes"][2],
+            json!({ "key": "host.name", "value": { "stringValue": "web1" } })
+        );
+        let metrics = resource["scopeMetrics"][0]["metrics"].as_array().unwrap();
+        assert_eq!(metrics.len(), 5);
+
+        let io = &metrics[0];
+        assert_eq!(io["name"], "system.network.io");
+        assert_eq!(io["unit"], "By");
+        assert_eq!(io["sum"]["isMonotonic"], true);
+        assert_eq!(io["sum"]["aggregationTemporality"], 2);
+        let points = io["sum"]["dataPoints"].as_array().unwrap();
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0]["asInt"], "1500");
+        // 처음 보는 계열은 지금부터 시작 (부팅 이후 쌓인 값을 첫 구간에 넣지 않음)
+        assert_eq!(points[0]["startTimeUnixNano"], "1700000010000000000");
+        assert_eq!(points[0]["timeUnixNano"], "1700000010000000000");
+        assert_eq!(
+            points[1]["attributes"],
+            json!([
+                { "key": "network.interface.name", "value": { "stringValue": "eth0" } },
+                { "key": "network.io.direction", "value": { "stringValue": "transmit" } },
+            ])
+        );
+        assert_eq!(metrics[3]["name"], "system.network.dropped");
+        assert_eq!(metrics[3]["sum"]["dataPoints"][0]["asInt"], "3");
+
+        assert_eq!(metrics[4]["name"], RATE_NAME);
+        assert_eq!(metrics[4]["gauge"]["dataPoints"][0]["asDouble"], 12.5);
+
+        // 속도가 아직 없으면 게이지 생략
+        let later = start + Duration::from_secs(20);
+        let request = encoder.encode_interfaces(&interfaces, &stats, &[None], later);
+        let metrics = &request["resourceMetrics"][0]["scopeMetrics"][0]["metrics"];
+        assert_eq!(metrics.as_array().unwrap().len(), 4);
+        // 계열의 시작 시각은 처음 본 시각 그대로
+        let point = &metrics[0]["sum"]["dataPoints"][0];
+        assert_eq!(point["startTimeUnixNano"], "1700000010000000000");
+        assert_eq!(point["timeUnixNano"], "1700000020000000000");
+
+        // 카운터가 줄면(인터페이스 재생성 등) 그 시각부터 새 계열
+        let recreated = vec![InterfaceStats {
+            bytes_received: 100,
+            ..stats[0].clone()
+        }];
+        let last = start + Duration::from_secs(30);
+        let request = encoder.encode_interfaces(&interfaces, &recreated, &[None], last);
+        let points =
+            &request["resourceMetrics"][0]["scopeMetrics"][0]["metrics"][0]["sum"]["dataPoints"];
+        assert_eq!(points[0]["startTimeUnixNano"], "1700000030000000000");
+        assert_eq!(points[1]["startTimeUnixNano"], "1700000010000000000");
+
+        assert_eq!(
+            metrics_url("http://collector:4318"),
+            "http://collector:4318/v1/metrics"
+        );
+        assert_eq!(
+            metrics_url("https://otlp.example.com/otlp/v1/metrics"),
+            "https://otlp.example.com/otlp/v1/metrics"
+        );
+    }
+
+    #[test]
+    fn test_exporter_posts_to_stub_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = metrics_url(&format!("http://{}", listener.local_addr().unwrap()));
+
+        // 요청 하나를 받아서 요청 줄, 헤더, 본문을 돌려주는 스텁 컬렉터
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut head = Vec::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end().to_string();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+                head.push(line.to_ascii_lowercase());
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            reader
+                .get_mut()
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}")
+                .unwrap();
+            (head, body)
+        });
+
+        let (interfaces, stats) = sample();
+        let mut encoder = Encoder::new("web1");
+        let request = encoder.encode_interfaces(&interfaces, &stats, &[None], SystemTime::now());
+        let exporter = Exporter::start(
+            url,
+            vec![("Authorization".to_string(), "Bearer token".to_string())],
+        )
+        .unwrap();
+        assert!(exporter.export(&request));
+        exporter.finish();
+
+        let (head, body) = server.join().unwrap();
+        assert_eq!(head[0], "post /v1/metrics http/1.1");
+        assert!(head.contains(&"content-type: application/json".to_string()));
+        assert!(head.contains(&"authorization: bearer token".to_string()));
+        let received: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(received, request);
+    }
+}
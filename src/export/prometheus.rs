@@ -0,0 +1,215 @@
+// Prometheus 텍스트 노출 형식 모듈 (nbmon serve의 /metrics)
+// node_exporter의 netdev 수집기 대신 네트워크 메트릭만 필요한 호스트에서 쓰도록
+// InterfaceStats의 모든 카운터와 계산된 속도, 링크 속도를 인터페이스 이름/종류/MAC 레이블과 함께 출력
+//
+//   # HELP nbmon_network_receive_bytes_total Bytes received.
+//   # TYPE nbmon_network_receive_bytes_total counter
+//   nbmon_network_receive_bytes_total{interface="eth0",kind="physical",mac="00:11:22:33:44:55"} 123456
+
+use std::fmt::Write;
+
+use crate::network::{
+    interface::NetworkInterface,
+    sampler::Sampler,
+    stats::{BandwidthStats, InterfaceStats},
+};
+
+/// /metrics 응답의 Content-Type (텍스트 형식 0.0.4)
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+// 메트릭 이름 접두사
+const PREFIX: &str = "nbmon_network";
+
+// 메트릭 하나의 이름(접두사 제외), 설명, 값을 꺼내는 함수
+struct Metric<T, V> {
+    name: &'static str,
+    help: &'static str,
+    value: fn(&T) -> V,
+}
+
+// InterfaceStats의 카운터
+const COUNTERS: [Metric<InterfaceStats, u64>; 8] = [
+    Metric {
+        name: "receive_bytes_total",
+        help: "Bytes received.",
+        value: |s| s.bytes_received,
+    },
+    Metric {
+        name: "transmit_bytes_total",
+        help: "Bytes sent.",
+        value: |s| s.bytes_sent,
+    },
+    Metric {
+        name: "receive_packets_total",
+        help: "Packets received.",
+        value: |s| s.packets_received,
+    },
+    Metric {
+        name: "transmit_packets_total",
+        help: "Packets sent.",
+        value: |s| s.packets_sent,
+    },
+    Metric {
+        name: "receive_errors_total",
+        help: "Receive errors.",
+        value: |s| s.errors_in,
+    },
+    Metric {
+        name: "transmit_errors_total",
+        help: "Transmit errors.",
+        value: |s| s.errors_out,
+    },
+    Metric {
+        name: "receive_drop_total",
+        help: "Received packets dropped.",
+        value: |s| s.drops_in,
+    },
+    Metric {
+        name: "transmit_drop_total",
+        help: "Outgoing packets dropped.",
+        value: |s| s.drops_out,
+    },
+];
+
+// 계산된 속도 게이지 (첫 샘플 전에는 출력하지 않음)
+const RATES: [Metric<BandwidthStats, f64>; 2] = [
+    Metric {
+        name: "receive_bytes_per_second",
+        help: "Receive rate over the last sampling interval.",
+        value: |b| b.download_rate,
+    },
+    Metric {
+        name: "transmit_bytes_per_second",
+        help: "Transmit rate over the last sampling interval.",
+        value: |b| b.upload_rate,
+    },
+];
+
+/// Sampler의 현재 상태를 텍스트 노출 형식으로 변환
+pub fn render(sampler: &Sampler) -> String {
+    render_interfaces(
+        sampler.interfaces(),
+        sampler.all_stats(),
+        sampler.all_bandwidth(),
+    )
+}
+
+/// 인터페이스 목록과 통계를 텍스트 노출 형식으로 변환 (세 목록은 같은 순서)
+pub fn render_interfaces(
+    interfaces: &[NetworkInterface],
+    stats: &[InterfaceStats],
+    bandwidth: &[Option<BandwidthStats>],
+) -> String {
+    let labels: Vec<String> = interfaces.iter().map(labels).collect();
+    let mut out = String::new();
+
+    for metric in &COUNTERS {
+        family(&mut out, metric.name, metric.help, "counter");
+        for (labels, stats) in labels.iter().zip(stats) {
+            let value = (metric.value)(stats);
+            let _ = writeln!(out, "{}_{}{{{}}} {}", PREFIX, metric.name, labels, value);
+        }
+    }
+
+    for metric in &RATES {
+        family(&mut out, metric.name, metric.help, "gauge");
+        for (labels, rates) in labels.iter().zip(bandwidth) {
+            if let Some(rates) = rates {
+                let value = (metric.value)(rates);
+                let _ = writeln!(out, "{}_{}{{{}}} {}", PREFIX, metric.name, labels, value);
+            }
+        }
+    }
+
+    // 링크 속도를 모르는 인터페이스(가상 인터페이스 등)는 생략
+    family(
+        &mut out,
+        "speed_bytes",
+        "Link speed in bytes per second.",
+        "gauge",
+    );
+    for (labels, interface) in labels.iter().zip(interfaces) {
+        if interface.speed > 0 {
+            let _ = writeln!(
+                out,
+                "{}_speed_bytes{{{}}} {}",
+                PREFIX,
+                labels,
+                interface.speed / 8
+            );
+        }
+    }
+
+    out
+}
+
+// # HELP, # TYPE 줄
+fn family(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP {}_{} {}", PREFIX, name, help);
+    let _ = writeln!(out, "# TYPE {}_{} {}", PREFIX, name, kind);
+}
+
+// 인터페이스 레이블 (중괄호 안쪽)
+fn labels(interface: &NetworkInterface) -> String {
+    format!(
+        "interface=\"{}\",kind=\"{}\",mac=\"{}\"",
+        escape(&interface.name),
+        interface.kind(),
+        escape(&interface.mac_address)
+    )
+}
+
+// 레이블 값 이스케이프 (\, ", 줄바꿈)
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_interfaces() {
+        let mut eth0 = NetworkInterface::new(2, "eth0".to_string(), "eth0".to_string());
+        eth0.mac_address = "00:11:22:33:44:55".to_string();
+        eth0.speed = 1_000_000_000;
+        let mut odd = NetworkInterface::new(3, "we\"ird".to_string(), String::new());
+        odd.is_loopback = true;
+
+        let stats = vec![
+            InterfaceStats {
+                bytes_received: 1_500,
+                drops_out: 2,
+                ..InterfaceStats::new(2)
+            },
+            InterfaceStats::new(3),
+        ];
+        let bandwidth = vec![
+            Some(BandwidthStats {
+                download_rate: 1250.5,
+                ..Default::default()
+            }),
+            None,
+        ];
+
+        let text = render_interfaces(&[eth0, odd], &stats, &bandwidth);
+        let eth0 = r#"{interface="eth0",kind="physical",mac="00:11:22:33:44:55"}"#;
+        assert!(text.contains("# TYPE nbmon_network_receive_bytes_total counter\n"));
+        assert!(text.contains(&format!("nbmon_network_receive_bytes_total{} 1500\n", eth0)));
+        assert!(text.contains(&format!("nbmon_network_transmit_drop_total{} 2\n", eth0)));
+        assert!(text.contains(&format!(
+            "nbmon_network_receive_bytes_per_second{} 1250.5\n",
+            eth0
+        )));
+        assert!(text.contains(&format!("nbmon_network_speed_bytes{} 125000000\n", eth0)));
+        assert!(text.contains(
+            r#"nbmon_network_receive_bytes_total{interface="we\"ird",kind="loopback",mac=""} 0"#
+        ));
+
+        // 속도를 아직 계산하지 못했거나 링크 속도를 모르면 생략
+        assert_eq!(text.matches("we\\\"ird").count(), 8);
+    }
+}
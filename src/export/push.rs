@@ -0,0 +1,719 @@
+// 푸시 출력 모듈 (nbmon push)
+// 매 틱의 카운터와 속도를 InfluxDB 라인 프로토콜, Graphite 평문, StatsD/DogStatsD 형식으로 TCP/UDP 엔드포인트에 보냄
+// 샘플링 루프는 만든 줄을 큐에 넣기만 하고(가득 차면 버림) 절대 기다리지 않음
+// 전송 스레드가 줄을 모아서(batch) 보내고, 연결이 끊기면 지수 백오프로 다시 연결
+//
+//   influx:   nbmon,host=web1,interface=eth0,kind=physical rx_bytes=123i,...,rx_bytes_per_sec=12.5 1700000000000000000
+//   graphite: nbmon.web1.eth0.rx_bytes 123 1700000000
+//   statsd:   nbmon.web1.eth0.rx_bytes_per_sec:12.5|g, nbmon.web1.eth0.rx_bytes:1500|c (이전 틱과의 차이)
+//   dogstatsd: nbmon.rx_bytes_per_sec:12.5|g|#iface:eth0,kind:physical
+
+use anyhow::{bail, Context, Result};
+use std::fmt;
+use std::io::Write;
+use std::net::{TcpStream, ToSocketAddrs, UdpSocket};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, SyncSender, TrySendError};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::i18n::{tf, Msg};
+use crate::network::{
+    interface::NetworkInterface,
+    sampler::Sampler,
+    stats::{BandwidthStats, InterfaceStats},
+};
+
+// 연결/쓰기 제한 시간 (전송 스레드만 기다리고 샘플링 루프는 영향받지 않음)
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+// 재연결 대기 시간 범위 (실패할 때마다 두 배)
+const MIN_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+// UDP 데이터그램 하나의 최대 크기 (일반적인 MTU에서 조각나지 않도록)
+const MAX_DATAGRAM: usize = 1400;
+
+// 샘플링 루프와 전송 스레드 사이 큐에 쌓을 수 있는 틱 수
+const QUEUE_TICKS: usize = 64;
+
+/// 출력 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushFormat {
+    /// InfluxDB 라인 프로토콜 (Telegraf socket_listener 등)
+    Influx,
+    /// Graphite 평문 프로토콜 (carbon)
+    Graphite,
+    /// StatsD - 속도는 게이지, 카운터는 틱 사이 증가량 (인터페이스는 이름 경로에)
+    Statsd,
+    /// DogStatsD - StatsD와 같지만 인터페이스를 태그로 (Datadog 에이전트)
+    Dogstatsd,
+}
+
+impl PushFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "influx" | "influxdb" => Some(PushFormat::Influx),
+            "graphite" | "carbon" => Some(PushFormat::Graphite),
+            "statsd" => Some(PushFormat::Statsd),
+            "dogstatsd" => Some(PushFormat::Dogstatsd),
+            _ => None,
+        }
+    }
+
+    /// 스킴 없이 지정한 엔드포인트의 전송 프로토콜
+    /// (StatsD 데몬과 Datadog 에이전트의 DogStatsD는 UDP로만 받음)
+    pub fn default_protocol(self) -> Protocol {
+        match self {
+            PushFormat::Influx | PushFormat::Graphite => Protocol::Tcp,
+            PushFormat::Statsd | PushFormat::Dogstatsd => Protocol::Udp,
+        }
+    }
+}
+
+/// 전송 프로토콜
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    Tcp,
+    Udp,
+}
+
+/// 보낼 곳 (tcp://host:port 또는 udp://host:port)
+/// 스킴이 없으면 형식에 맞는 프로토콜 (with_default_protocol, 그 전에는 tcp)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Endpoint {
+    /// 스킴으로 지정한 프로토콜 (없으면 None)
+    pub protocol: Option<Protocol>,
+    /// host:port (연결할 때마다 다시 해석하므로 DNS 변경도 반영됨)
+    pub address: String,
+}
+
+impl FromStr for Endpoint {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (protocol, address) = match value.split_once("://") {
+            Some(("tcp", address)) => (Some(Protocol::Tcp), address),
+            Some(("udp", address)) => (Some(Protocol::Udp), address),
+            Some((scheme, _)) => return Err(format!("unsupported scheme `{}`", scheme)),
+            None => (None, value),
+        };
+        // 포트가 숫자로 끝나는지만 확인 (호스트 이름은 연결할 때 해석)
+        match address.rsplit_once(':') {
+            Some((host, port)) if !host.is_empty() && port.parse::<u16>().is_ok() => Ok(Self {
+                protocol,
+                address: address.to_string(),
+            }),
+            _ => Err("expected host:port (e.g. tcp://127.0.0.1:8094)".to_string()),
+        }
+    }
+}
+
+impl Endpoint {
+    /// 스킴이 없었으면 protocol로 보냄 (builder 스타일)
+    pub fn with_default_protocol(mut self, protocol: Protocol) -> Self {
+        self.protocol.get_or_insert(protocol);
+        self
+    }
+
+    /// 실제로 쓰는 전송 프로토콜
+    pub fn transport(&self) -> Protocol {
+        self.protocol.unwrap_or(Protocol::Tcp)
+    }
+}
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scheme = match self.transport() {
+            Protocol::Tcp => "tcp",
+            Protocol::Udp => "udp",
+        };
+        write!(f, "{}://{}", scheme, self.address)
+    }
+}
+
+// 필드 값 (Influx는 정수에 i 접미사를 붙여 구분)
+#[derive(Debug, Clone, Copy)]
+enum Value {
+    Int(u64),
+    Float(f64),
+}
+
+// 한 인터페이스의 필드 목록 (속도는 계산된 뒤에만)
+fn fields(stats: &InterfaceStats, rates: Option<&BandwidthStats>) -> Vec<(&'static str, Value)> {
+    let mut fields = vec![
+        ("rx_bytes", Value::Int(stats.bytes_received)),
+        ("tx_bytes", Value::Int(stats.bytes_sent)),
+        ("rx_packets", Value::Int(stats.packets_received)),
+        ("tx_packets", Value::Int(stats.packets_sent)),
+        ("rx_errors", Value::Int(stats.errors_in)),
+        ("tx_errors", Value::Int(stats.errors_out)),
+        ("rx_drops", Value::Int(stats.drops_in)),
+        ("tx_drops", Value::Int(stats.drops_out)),
+    ];
+    if let Some(rates) = rates {
+        fields.push(("rx_bytes_per_sec", Value::Float(rates.download_rate)));
+        fields.push(("tx_bytes_per_sec", Value::Float(rates.upload_rate)));
+    }
+    fields
+}
+
+/// 틱마다 보낼 줄을 만드는 변환기
+#[derive(Debug, Clone)]
+pub struct LineFormatter {
+    format: PushFormat,
+    /// Influx 측정 이름 / Graphite, StatsD 이름의 첫 부분
+    prefix: String,
+    host: String,
+    /// 직전 틱의 카운터 (StatsD 카운터 증가량 계산용, Sampler와 같은 순서)
+    previous: Vec<InterfaceStats>,
+}
+
+impl LineFormatter {
+    pub fn new(format: PushFormat, prefix: &str, host: &str) -> Self {
+        Self {
+            format,
+            prefix: prefix.to_string(),
+            host: host.to_string(),
+            previous: Vec::new(),
+        }
+    }
+
+    /// Sampler의 현재 상태를 줄 목록으로 변환 (다음 호출을 위해 카운터를 기억)
+    pub fn lines(&mut self, sampler: &Sampler, time: SystemTime) -> Vec<String> {
+        let timestamp = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let lines = sampler
+            .interfaces()
+            .iter()
+            .zip(sampler.all_stats())
+            .zip(sampler.all_bandwidth())
+            .enumerate()
+            .flat_map(|(i, ((interface, stats), rates))| {
+                let previous = self.previous.get(i);
+                self.interface_lines(interface, stats, rates.as_ref(), previous, timestamp)
+            })
+            .collect();
+        self.previous = sampler.all_stats().to_vec();
+        lines
+    }
+
+    /// 한 인터페이스의 줄 (Influx는 한 줄, Graphite와 StatsD는 필드마다 한 줄)
+    /// previous: 직전 틱의 카운터 (StatsD 카운터에만 사용, 없으면 카운터를 생략)
+    pub fn interface_lines(
+        &self,
+        interface: &NetworkInterface,
+        stats: &InterfaceStats,
+        rates: Option<&BandwidthStats>,
+        previous: Option<&InterfaceStats>,
+        timestamp: Duration,
+    ) -> Vec<String> {
+        let fields = fields(stats, rates);
+        match self.format {
+            PushFormat::Influx => {
+                let mut tags = vec![
+                    ("host", self.host.as_str()),
+                    ("interface", interface.name.as_str()),
+                    ("kind", interface.kind()),
+                ];
+                if !interface.mac_address.is_empty() {
+                    tags.push(("mac", interface.mac_address.as_str()));
+                }
+                let tags: String = tags
+                    .iter()
+                    .filter(|(_, value)| !value.is_empty())
+                    .map(|(key, value)| format!(",{}={}", key, escape_influx(value, true)))
+                    .collect();
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|(name, value)| match value {
+                        Value::Int(v) => format!("{}={}i", name, v),
+                        Value::Float(v) => format!("{}={}", name, v),
+                    })
+                    .collect();
+                vec![format!(
+                    "{}{} {} {}",
+                    escape_influx(&self.prefix, false),
+                    tags,
+                    fields.join(","),
+                    timestamp.as_nanos()
+                )]
+            }
+            PushFormat::Graphite => {
+                let path = format!(
+                    "{}.{}.{}",
+                    graphite_component(&self.prefix),
+                    graphite_component(&self.host),
+                    graphite_component(&interface.name)
+                );
+                fields
+                    .iter()
+                    .map(|(name, value)| {
+                        let value = match value {
+                            Value::Int(v) => v.to_string(),
+                            Value::Float(v) => v.to_string(),
+                        };
+                        format!("{}.{} {} {}", path, name, value, timestamp.as_secs())
+                    })
+                    .collect()
+            }
+            PushFormat::Statsd | PushFormat::Dogstatsd => {
+                // StatsD는 인터페이스를 이름 경로에, DogStatsD는 태그에 넣음
+                let (path, tags) = if self.format == PushFormat::Statsd {
+                    let path = format!(
+                        "{}.{}.{}",
+                        graphite_component(&self.prefix),
+                        graphite_component(&self.host),
+                        graphite_component(&interface.name)
+                    );
+                    (path, String::new())
+                } else {
+                    let tags = format!(
+                        "|#iface:{},kind:{}",
+                        dogstatsd_tag(&interface.name),
+                        interface.kind()
+                    );
+                    (graphite_component(&self.prefix), tags)
+                };
+
+                // 카운터는 직전 틱과의 차이 (첫 틱이나 카운터가 줄어든 경우는 생략)
+                let before = previous.map(|previous| self::fields(previous, None));
+                fields
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, (name, value))| {
+                        let (value, kind) = match value {
+                            Value::Float(v) => (v.to_string(), "g"),
+                            Value::Int(v) => match before.as_ref().map(|before| before[i].1) {
+                                Some(Value::Int(old)) if *v >= old => ((v - old).to_string(), "c"),
+                                _ => return None,
+                            },
+                        };
+                        Some(format!("{}.{}:{}|{}{}", path, name, value, kind, tags))
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+// DogStatsD 태그 값 (태그 구분자인 , | # 와 공백은 _로)
+fn dogstatsd_tag(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            ',' | '|' | '#' | ' ' => '_',
+            c => c,
+        })
+        .collect()
+}
+
+// Influx 이스케이프 (측정 이름: 쉼표/공백, 태그: 쉼표/공백/등호)
+fn escape_influx(value: &str, tag: bool) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c == ',' || c == ' ' || (tag && c == '=') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+// Graphite 경로 구성 요소 (점은 경로 구분자이므로 영숫자, -, _ 이외의 글자는 _로)
+fn graphite_component(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// 재연결 대기 시간 (실패할 때마다 두 배, 최대 MAX_BACKOFF)
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    next: Duration,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self { next: MIN_BACKOFF }
+    }
+}
+
+impl Backoff {
+    /// 이번 실패 후 기다릴 시간
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self.next;
+        self.next = (self.next * 2).min(MAX_BACKOFF);
+        delay
+    }
+
+    /// 전송에 성공하면 처음부터
+    pub fn reset(&mut self) {
+        self.next = MIN_BACKOFF;
+    }
+}
+
+/// 묶음 전송 설정
+#[derive(Debug, Clone, Copy)]
+pub struct BatchOptions {
+    /// 이만큼 줄이 모이면 바로 보냄
+    pub batch_size: usize,
+    /// 줄이 적어도 이 간격마다 보냄
+    pub flush_interval: Duration,
+    /// 보내지 못한 줄을 이만큼까지 보관 (넘치면 오래된 줄부터 버림)
+    pub buffer_limit: usize,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: 1000,
+            flush_interval: Duration::from_secs(10),
+            buffer_limit: 10_000,
+        }
+    }
+}
+
+// 열린 연결
+enum Connection {
+    Tcp(TcpStream),
+    Udp(UdpSocket),
+}
+
+impl Connection {
+    fn open(endpoint: &Endpoint) -> Result<Self> {
+        let addresses: Vec<_> = endpoint
+            .address
+            .to_socket_addrs()
+            .with_context(|| format!("Failed to resolve {}", endpoint.address))?
+            .collect();
+
+        let mut last_error = None;
+        for address in addresses {
+            let result = match endpoint.transport() {
+                Protocol::Tcp => TcpStream::connect_timeout(&address, IO_TIMEOUT).and_then(|s| {
+                    s.set_write_timeout(Some(IO_TIMEOUT))?;
+                    Ok(Connection::Tcp(s))
+                }),
+                Protocol::Udp => {
+                    let local = if address.is_ipv4() {
+                        "0.0.0.0:0"
+                    } else {
+                        "[::]:0"
+                    };
+                    UdpSocket::bind(local).and_then(|s| {
+                        s.connect(address)?;
+                        Ok(Connection::Udp(s))
+                    })
+                }
+            };
+            match result {
+                Ok(connection) => return Ok(connection),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        match last_error {
+            Some(e) => Err(e).with_context(|| format!("Failed to connect to {}", endpoint)),
+            None => bail!("{} did not resolve to any address", endpoint.address),
+        }
+    }
+
+    // 줄 목록을 보냄 (UDP는 줄이 잘리지 않도록 데이터그램 크기 안에서 묶음)
+    fn send(&mut self, lines: &[String]) -> std::io::Result<()> {
+        match self {
+            Connection::Tcp(stream) => {
+                let mut payload = lines.join("\n");
+                payload.push('\n');
+                stream.write_all(payload.as_bytes())
+            }
+            Connection::Udp(socket) => {
+                let mut datagram = String::new();
+                for line in lines {
+                    if !datagram.is_empty() && datagram.len() + line.len() + 1 > MAX_DATAGRAM {
+                        socket.send(datagram.as_bytes())?;
+                        datagram.clear();
+                    }
+                    datagram.push_str(line);
+                    datagram.push('\n');
+                }
+                if !datagram.is_empty() {
+                    socket.send(datagram.as_bytes())?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// 전송 스레드를 가진 푸시 출력기
+pub struct Pusher {
+    sender: Option<SyncSender<Vec<String>>>,
+    dropped: Arc<AtomicU64>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Pusher {
+    /// 전송 스레드 시작 (연결은 첫 전송 때 맺음)
+    pub fn start(endpoint: Endpoint, options: BatchOptions) -> Self {
+        let (sender, receiver) = mpsc::sync_channel::<Vec<String>>(QUEUE_TICKS);
+        let dropped = Arc::new(AtomicU64::new(0));
+
+        let thread_dropped = Arc::clone(&dropped);
+        let handle = thread::spawn(move || {
+            let mut worker = Worker {
+                endpoint,
+                options,
+                connection: None,
+                backoff: Backoff::default(),
+                retry_at: None,
+                pending: Vec::new(),
+                dropped: thread_dropped,
+            };
+            let mut last_flush = Instant::now();
+            loop {
+                let closed = match receiver.recv_timeout(options.flush_interval) {
+                    Ok(lines) => {
+                        worker.queue(lines);
+                        false
+                    }
+                    Err(RecvTimeoutError::Timeout) => false,
+                    Err(RecvTimeoutError::Disconnected) => true,
+                };
+                if closed
+                    || worker.pending.len() >= options.batch_size
+                    || last_flush.elapsed() >= options.flush_interval
+                {
+                    worker.flush(closed);
+                    last_flush = Instant::now();
+                }
+                if closed {
+                    // 마지막 시도에도 보내지 못한 줄은 버린 것으로 셈
+                    let unsent = worker.pending.len() as u64;
+                    worker.dropped.fetch_add(unsent, Ordering::Relaxed);
+                    break;
+                }
+            }
+        });
+
+        Self {
+            sender: Some(sender),
+            dropped,
+            handle: Some(handle),
+        }
+    }
+
+    /// 한 틱의 줄을 큐에 넣음 - 절대 기다리지 않음 (큐가 가득 차면 버리고 false)
+    pub fn push(&self, lines: Vec<String>) -> bool {
+        let Some(sender) = &self.sender else {
+            return false;
+        };
+        match sender.try_send(lines) {
+            Ok(()) => true,
+            Err(TrySendError::Full(lines) | TrySendError::Disconnected(lines)) => {
+                self.dropped
+                    .fetch_add(lines.len() as u64, Ordering::Relaxed);
+                false
+            }
+        }
+    }
+
+    /// 지금까지 버린 줄 수 (큐가 가득 찼거나 보관 한도를 넘은 경우)
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// 큐를 닫고 남은 줄을 한 번 더 보내 본 뒤 전송 스레드 종료를 기다림
+    /// 반환값: 끝내 보내지 못하고 버린 전체 줄 수
+    pub fn finish(mut self) -> u64 {
+        self.sender.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        self.dropped()
+    }
+}
+
+// 전송 스레드의 상태
+struct Worker {
+    endpoint: Endpoint,
+    options: BatchOptions,
+    connection: Option<Connection>,
+    backoff: Backoff,
+    retry_at: Option<Instant>, // 이 시각 전에는 다시 연결하지 않음
+    pending: Vec<String>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl Worker {
+    fn queue(&mut self, lines: Vec<String>) {
+        self.pending.extend(lines);
+        let excess = self.pending.len().saturating_sub(self.options.buffer_limit);
+        if excess > 0 {
+            self.pending.drain(..excess);
+            self.dropped.fetch_add(excess as u64, Ordering::Relaxed);
+        }
+    }
+
+    // 모인 줄을 batch_size씩 보냄 (실패하면 남겨두고 백오프 후 재시도)
+    // closing이면 백오프를 무시하고 마지막으로 한 번 시도
+    fn flush(&mut self, closing: bool) {
+        if self.pending.is_empty() {
+            return;
+        }
+        if !closing && self.retry_at.is_some_and(|at| Instant::now() < at) {
+            return;
+        }
+
+        if let Err(e) = self.try_flush() {
+            self.connection = None;
+            let delay = self.backoff.next_delay();
+            self.retry_at = Some(Instant::now() + delay);
+            let message = tf(Msg::PushRetrying, &[&format!("{:#}", e), &delay.as_secs()]);
+            eprintln!("{}", tf(Msg::Warning, &[&message]));
+            return;
+        }
+        self.backoff.reset();
+        self.retry_at = None;
+    }
+
+    fn try_flush(&mut self) -> Result<()> {
+        let connection = match &mut self.connection {
+            Some(connection) => connection,
+            None => self.connection.insert(Connection::open(&self.endpoint)?),
+        };
+        while !self.pending.is_empty() {
+            let size = self.pending.len().min(self.options.batch_size.max(1));
+            connection
+                .send(&self.pending[..size])
+                .with_context(|| format!("Failed to send to {}", self.endpoint))?;
+            self.pending.drain(..size);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_line_formats() {
+        let mut interface = NetworkInterface::new(2, "eth0".to_string(), "eth0".to_string());
+        interface.mac_address = "00:11:22:33:44:55".to_string();
+        let stats = InterfaceStats {
+            bytes_received: 1_500,
+            bytes_sent: 20,
+            ..InterfaceStats::new(2)
+        };
+        let rates = BandwidthStats {
+            download_rate: 12.5,
+            ..Default::default()
+        };
+        let timestamp = Duration::from_secs(1_700_000_000);
+
+        let influx = LineFormatter::new(PushFormat::Influx, "nbmon", "web 1");
+        assert_eq!(
+            influx.interface_lines(&interface, &stats, Some(&rates), None, timestamp),
+            vec![
+                "nbmon,host=web\\ 1,interface=eth0,kind=physical,mac=00:11:22:33:44:55 \
+                 rx_bytes=1500i,tx_bytes=20i,rx_packets=0i,tx_packets=0i,rx_errors=0i,\
+                 tx_errors=0i,rx_drops=0i,tx_drops=0i,rx_bytes_per_sec=12.5,tx_bytes_per_sec=0 \
+                 1700000000000000000"
+            ]
+        );
+
+        let graphite = LineFormatter::new(PushFormat::Graphite, "nbmon", "web1.example.com");
+        let lines = graphite.interface_lines(&interface, &stats, None, None, timestamp);
+        assert_eq!(lines.len(), 8);
+        assert_eq!(
+            lines[0],
+            "nbmon.web1_example_com.eth0.rx_bytes 1500 1700000000"
+        );
+
+        // StatsD 카운터는 직전 틱과의 차이, 첫 틱에는 게이지만
+        let previous = InterfaceStats {
+            bytes_received: 1_000,
+            ..InterfaceStats::new(2)
+        };
+        let statsd = LineFormatter::new(PushFormat::Statsd, "nbmon", "web1");
+        let lines = statsd.interface_lines(&interface, &stats, Some(&rates), None, timestamp);
+        assert_eq!(
+            lines,
+            vec![
+                "nbmon.web1.eth0.rx_bytes_per_sec:12.5|g",
+                "nbmon.web1.eth0.tx_bytes_per_sec:0|g"
+            ]
+        );
+        let lines = statsd.interface_lines(&interface, &stats, None, Some(&previous), timestamp);
+        assert_eq!(lines[0], "nbmon.web1.eth0.rx_bytes:500|c");
+        assert_eq!(lines[1], "nbmon.web1.eth0.tx_bytes:20|c");
+
+        let dogstatsd = LineFormatter::new(PushFormat::Dogstatsd, "nbmon", "web1");
+        let lines =
+            dogstatsd.interface_lines(&interface, &stats, Some(&rates), Some(&previous), timestamp);
+        assert_eq!(lines.len(), 10);
+        assert_eq!(lines[0], "nbmon.rx_bytes:500|c|#iface:eth0,kind:physical");
+        assert_eq!(
+            lines[8],
+            "nbmon.rx_bytes_per_sec:12.5|g|#iface:eth0,kind:physical"
+        );
+
+        assert_eq!(
+            "udp://graphite:2003"
+                .parse::<Endpoint>()
+                .unwrap()
+                .transport(),
+            Protocol::Udp
+        );
+        let bare: Endpoint = "localhost:8125".parse().unwrap();
+        assert_eq!(bare.to_string(), "tcp://localhost:8125");
+        // 스킴이 없으면 형식의 기본 프로토콜, 스킴이 있으면 그대로
+        let udp = PushFormat::Dogstatsd.default_protocol();
+        assert_eq!(
+            bare.with_default_protocol(udp).to_string(),
+            "udp://localhost:8125"
+        );
+        let tcp: Endpoint = "tcp://localhost:8125".parse().unwrap();
+        assert_eq!(tcp.with_default_protocol(udp).transport(), Protocol::Tcp);
+        assert!("http://x:1".parse::<Endpoint>().is_err());
+        assert!("tcp://nohost".parse::<Endpoint>().is_err());
+
+        let mut backoff = Backoff::default();
+        let delays: Vec<u64> = (0..8).map(|_| backoff.next_delay().as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 16, 32, 60, 60]);
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), MIN_BACKOFF);
+    }
+
+    #[test]
+    fn test_pusher_sends_batches_over_tcp() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint: Endpoint = listener.local_addr().unwrap().to_string().parse().unwrap();
+
+        let pusher = Pusher::start(
+            endpoint,
+            BatchOptions {
+                batch_size: 2,
+                flush_interval: Duration::from_secs(60),
+                buffer_limit: 100,
+            },
+        );
+        assert!(pusher.push(vec!["a 1".to_string(), "b 2".to_string()]));
+        assert!(pusher.push(vec!["c 3".to_string()]));
+        assert_eq!(pusher.finish(), 0);
+
+        let (stream, _) = listener.accept().unwrap();
+        let lines: Vec<String> = BufReader::new(stream).lines().map(Result::unwrap).collect();
+        assert_eq!(lines, vec!["a 1", "b 2", "c 3"]);
+    }
+}
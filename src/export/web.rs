@@ -0,0 +1,314 @@
+// 웹 대시보드 모듈 (nbmon web)
+// 브라우저에 HTML 페이지 하나(web.html)를 보내고, 페이지는 /events 이벤트 스트림(Server-Sent Events)으로 매 틱의 값을 받아 그래프를 그림
+// 샘플링 스레드가 틱마다 상태를 갱신하고 Condvar로 깨우면, 연결 스레드들은 잠깐 잠가서 JSON을 만든 뒤 잠금 없이 보냄
+
+use anyhow::Result;
+use serde::Serialize;
+use std::io::{self, Write};
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use super::http;
+use crate::network::sampler::Sampler;
+use crate::storage::accounting::{AccountingDb, Period, Recorder, Traffic, FLUSH_INTERVAL_SECS};
+use crate::utils::time::{self, DateTime};
+
+/// 대시보드 페이지 (스크립트와 스타일을 모두 포함한 파일 하나)
+pub const PAGE: &str = include_str!("web.html");
+
+// 처음 연결할 때 그래프를 채우도록 보내는 최근 샘플 수
+const HISTORY_POINTS: usize = 300;
+
+// 새 틱이 없을 때 연결이 끊겼는지 확인하고 프록시가 연결을 닫지 않도록 보내는 주석 간격
+const KEEPALIVE: Duration = Duration::from_secs(15);
+
+/// 연결 직후 한 번 보내는 이벤트 - 샘플 간격, 인터페이스별 최근 속도, 현재 상태
+#[derive(Debug, Serialize)]
+pub struct Hello {
+    /// 샘플 간격 (초)
+    pub interval: f64,
+    pub history: Vec<Series>,
+    pub frame: Frame,
+}
+
+/// 인터페이스 하나의 최근 속도 (bytes/s, 오래된 것 → 최신)
+#[derive(Debug, Serialize)]
+pub struct Series {
+    pub download: Vec<u64>,
+    pub upload: Vec<u64>,
+}
+
+/// 틱마다 보내는 이벤트
+#[derive(Debug, Serialize)]
+pub struct Frame {
+    /// 수집 시각 (Unix 초)
+    pub timestamp: i64,
+    pub interfaces: Vec<InterfaceFrame>,
+}
+
+/// 인터페이스 하나의 현재 상태와 합계
+#[derive(Debug, Serialize)]
+pub struct InterfaceFrame {
+    pub name: String,
+    pub description: String,
+    pub is_up: bool,
+    /// 링크 속도 (bits per second, 모르면 0)
+    pub speed: u64,
+    /// 속도 (첫 틱처럼 아직 계산하지 못했으면 null)
+    pub download_rate: Option<f64>,
+    pub upload_rate: Option<f64>,
+    /// 커널 카운터 (부팅 이후 합계)
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    /// 집계 데이터베이스의 오늘/이번 달 합계 (기록이 없으면 0)
+    pub today: Traffic,
+    pub month: Traffic,
+}
+
+/// 대시보드가 공유하는 상태
+pub struct Dashboard {
+    state: Mutex<State>,
+    ticked: Condvar, // 틱마다 이벤트 스트림 스레드들을 깨움
+    interval: Duration,
+}
+
+struct State {
+    sampler: Sampler,
+    tick: u64,
+    /// 직접 샘플링할 때의 집계 기록 (데이터 디렉터리가 없으면 None)
+    recorder: Option<Recorder>,
+    /// 데몬에 붙었을 때 데몬이 기록한 집계 파일을 읽은 결과
+    database: Option<AccountingDb>,
+    loaded: Option<Instant>,
+}
+
+impl Dashboard {
+    /// recorder가 None이면 집계를 기록하지 않고 기본 경로의 집계 파일을 주기적으로 읽기만 함 (--attach)
+    pub fn new(sampler: Sampler, recorder: Option<Recorder>, interval: Duration) -> Self {
+        Self {
+            state: Mutex::new(State {
+                sampler,
+                tick: 0,
+                recorder,
+                database: None,
+                loaded: None,
+            }),
+            ticked: Condvar::new(),
+            interval,
+        }
+    }
+
+    /// 한 틱 샘플링하고 연결된 페이지들에 알림
+    pub fn update(&self) -> Result<()> {
+        let mut state = self.lock();
+        state.sampler.update()?;
+        state.tick += 1;
+        state.refresh_totals();
+        drop(state);
+        self.ticked.notify_all();
+        Ok(())
+    }
+
+    /// 이벤트 스트림 - hello 이벤트 후 틱마다 frame 이벤트 (연결이 끊겨 쓰기에 실패하면 끝남)
+    pub fn stream(&self, out: &mut dyn Write) -> io::Result<()> {
+        let (hello, mut seen) = {
+            let state = self.lock();
+            (to_json(&state.hello(self.interval)), state.tick)
+        };
+        http::write_event(out, "hello", &hello)?;
+
+        loop {
+            let (state, _) = self
+                .ticked
+                .wait_timeout_while(self.lock(), KEEPALIVE, |state| state.tick == seen)
+                .unwrap_or_else(PoisonError::into_inner);
+            if state.tick == seen {
+                drop(state);
+                out.write_all(b": keepalive\n\n")?;
+                out.flush()?;
+                continue;
+            }
+            seen = state.tick;
+            let frame = to_json(&state.frame());
+            drop(state);
+            http::write_event(out, "frame", &frame)?;
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl State {
+    // 직접 샘플링하면 집계를 기록하고, 아니면 1분마다 집계 파일을 다시 읽음
+    fn refresh_totals(&mut self) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.maybe_flush(&self.sampler);
+            return;
+        }
+        let due = self
+            .loaded
+            .is_none_or(|loaded| loaded.elapsed().as_secs() >= FLUSH_INTERVAL_SECS);
+        if due {
+            self.loaded = Some(Instant::now());
+            self.database = AccountingDb::default_path()
+                .and_then(|path| AccountingDb::load(&path))
+                .ok();
+        }
+    }
+
+    fn hello(&self, interval: Duration) -> Hello {
+        let history = (0..self.sampler.interfaces().len())
+            .map(|i| match self.sampler.history(i) {
+                Some(history) => Series {
+                    download: history.download().recent(HISTORY_POINTS),
+                    upload: history.upload().recent(HISTORY_POINTS),
+                },
+                None => Series {
+                    download: Vec::new(),
+                    upload: Vec::new(),
+                },
+            })
+            .collect();
+        Hello {
+            interval: interval.as_secs_f64(),
+            history,
+            frame: self.frame(),
+        }
+    }
+
+    fn frame(&self) -> Frame {
+        let timestamp = time::now_unix();
+        let now = DateTime::local(timestamp);
+        let (day, month) = (now.day_key(), now.month_key());
+        let database = match &self.recorder {
+            Some(recorder) => recorder.database(),
+            None => self.database.as_ref(),
+        };
+
+        let sampler = &self.sampler;
+        let interfaces = sampler
+            .interfaces()
+            .iter()
+            .zip(sampler.all_stats())
+            .zip(sampler.all_bandwidth())
+            .map(|((interface, stats), rates)| {
+                let sum = |period: Period, key: &str| {
+                    database
+                        .map(|db| db.period_total(&interface.name, period, key))
+                        .unwrap_or_default()
+                };
+                InterfaceFrame {
+                    name: interface.name.clone(),
+                    description: interface.description.clone(),
+                    is_up: interface.is_up,
+                    speed: interface.speed,
+                    download_rate: rates.as_ref().map(|r| r.download_rate),
+                    upload_rate: rates.as_ref().map(|r| r.upload_rate),
+                    rx_bytes: stats.bytes_received,
+                    tx_bytes: stats.bytes_sent,
+                    today: sum(Period::Daily, &day),
+                    month: sum(Period::Monthly, &month),
+                }
+            })
+            .collect();
+
+        Frame {
+            timestamp,
+            interfaces,
+        }
+    }
+}
+
+// 이벤트 데이터 (한 줄 JSON - 직렬화할 수 없는 값이 없으므로 실패하지 않음)
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::history::InterfaceHistory;
+    use crate::network::interface::NetworkInterface;
+    use crate::network::sampler::{Snapshot, StatsSource, Update};
+    use crate::network::stats::{BandwidthStats, InterfaceStats};
+    use std::sync::Arc;
+    use std::thread;
+
+    // 매번 수신 카운터가 100씩 늘어나는 공급원
+    struct Counter(u64);
+
+    impl StatsSource for Counter {
+        fn fetch(&mut self) -> Result<Option<Update>> {
+            self.0 += 100;
+            let rates = BandwidthStats {
+                download_rate: 100.0,
+                ..Default::default()
+            };
+            Ok(Some(Update {
+                stats: vec![InterfaceStats {
+                    bytes_received: self.0,
+                    ..InterfaceStats::new(2)
+                }],
+                bandwidth: vec![Some(rates.clone())],
+                samples: vec![vec![rates]],
+                ages: Vec::new(),
+            }))
+        }
+    }
+
+    // 이벤트를 받을 때마다 채널로 넘기고, 세 번째 이벤트에서 연결이 끊긴 것처럼 실패
+    struct Events(std::sync::mpsc::Sender<String>, usize);
+
+    impl Write for Events {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let text = String::from_utf8_lossy(buf).to_string();
+            if text.starts_with("event:") {
+                self.1 += 1;
+                if self.1 > 2 {
+                    return Err(io::ErrorKind::BrokenPipe.into());
+                }
+                self.0.send(text).unwrap();
+            }
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_stream_sends_hello_then_frames() {
+        let eth0 = NetworkInterface::new(2, "eth0".to_string(), "eth0".to_string());
+        let snapshot = Snapshot {
+            interfaces: vec![eth0],
+            stats: vec![InterfaceStats::new(2)],
+            bandwidth: vec![None],
+            histories: vec![InterfaceHistory::new(60, Duration::from_secs(1))],
+            ages: Vec::new(),
+        };
+        let sampler = Sampler::from_snapshot(snapshot, Box::new(Counter(0)));
+        let dashboard = Arc::new(Dashboard::new(sampler, None, Duration::from_secs(1)));
+        dashboard.update().unwrap();
+
+        let (sender, events) = std::sync::mpsc::channel();
+        let streaming = Arc::clone(&dashboard);
+        let handle = thread::spawn(move || streaming.stream(&mut Events(sender, 0)));
+
+        let hello = events.recv().unwrap();
+        assert!(hello.starts_with("event: hello\ndata: {\"interval\":1.0,"));
+        assert!(hello.contains("\"history\":[{\"download\":[100],\"upload\":[0]}]"));
+        assert!(hello.ends_with("\n\n"));
+
+        dashboard.update().unwrap();
+        let frame = events.recv().unwrap();
+        assert!(frame.starts_with("event: frame\ndata: {\"timestamp\":"));
+        assert!(frame.contains("\"rx_bytes\":200,"));
+
+        // 다음 틱에서 쓰기에 실패하면 스트림이 끝남
+        dashboard.update().unwrap();
+        assert!(handle.join().unwrap().is_err());
+    }
+}
@@ -0,0 +1,263 @@
+// 영어 메시지 카탈로그 (기본)
+// 모든 Msg를 match로 다루므로 빠진 메시지가 있으면 컴파일되지 않음
+
+use super::Msg;
+
+pub(super) fn text(msg: Msg) -> &'static str {
+    match msg {
+        // 커맨드라인 도움말
+        Msg::CliAbout => "Cross-platform network bandwidth monitor (real-time traffic like nload/bmon)",
+        Msg::CliAfterHelp => "\
+Keyboard shortcuts (TUI modes):
+    ←/h, →/l      Previous/next network interface
+    +/-           Zoom the graphs (1m/10m/1h/24h)
+    Space         Refresh now
+    r             Reset history
+    f/v/d/o       Toggle filters (name patterns/hide virtual/show down/show loopback)
+    q             Quit
+
+Examples:
+    nbmon                                   # Enhanced TUI
+    nbmon classic -i eth0                   # Classic TUI with eth0 selected
+    nbmon --interval 0.5 --units bits       # Sample every 0.5 s, show bits/s
+    nbmon --units bits --scale M            # Always show Mbit/s
+    nbmon --exclude 'cali*' --exclude 're:^veth' --hide-virtual
+    nbmon export --format json --count 3 | jq .
+    nbmon export --format batch --count 60 > eth.log
+    nbmon record --csv samples.csv --interval 5
+    nbmon report --monthly
+    nbmon query -i eth0 --since 02:00 --until 03:00 --agg max
+    nbmon quota set eth0 1TB --reset-day 15
+    nbmon serve --listen 0.0.0.0:9959
+    nbmon push --to udp://127.0.0.1:8094 --format influx
+    nbmon otlp --endpoint http://127.0.0.1:4318 --export-interval 30s
+    nbmon mqtt --broker 192.168.1.10 --username nbmon --password-file mqtt.pass
+    nbmon daemon &                          # Shared background sampler
+    nbmon --attach                          # TUI with the daemon's history
+    nbmon tui --remote router --psk-file router.psk
+    nbmon dashboard                         # One table for the whole rack
+    nbmon tui --snmp switch1 --community monitoring
+    nbmon web --listen 0.0.0.0:8080         # Live charts in the browser
+    nbmon check -i eth0 --warn-rx 800Mbit --crit-rx 950Mbit --duration 10s
+    vnstat --json | nbmon import vnstat -
+
+Repository: https://github.com/i978sukhoi/nbmon",
+        Msg::HelpHelp => "Print help",
+        Msg::HelpVersion => "Print version",
+        Msg::HelpInterface => "Target interface (first selection in the TUIs, the only interface for export/record/simple/check instead of the filters, a filter for report/query)",
+        Msg::HelpInterval => "Sampling interval in seconds, fractions allowed (default 1)",
+        Msg::HelpHistory => "Raw samples kept per interface (default: 600 in the enhanced TUI, 60 in the classic one)",
+        Msg::HelpUnits => "Rate units",
+        Msg::HelpPrefix => "Unit prefixes (si: kB/Mbit, 1000-based; iec: KiB/Mibit, 1024-based; default: iec for bytes, si for bits)",
+        Msg::HelpScale => "Always show rates with one prefix (auto, 1, k, M, G, T; e.g. --units bits --scale M for Mbit/s)",
+        Msg::HelpNoPublicIp => "Never look up the public IP address from external services",
+        Msg::HelpInclude => "Show only interfaces matching this pattern (glob or re:regex, repeatable)",
+        Msg::HelpExclude => "Hide interfaces matching this pattern (glob or re:regex, repeatable)",
+        Msg::HelpHideVirtual => "Hide virtual interfaces (veth, docker, VPN, ...)",
+        Msg::HelpShowDown => "Also show interfaces that are down",
+        Msg::HelpShowLoopback => "Also show loopback interfaces",
+        Msg::HelpConfig => "Configuration file (default: $XDG_CONFIG_HOME/nbmon/config.toml, built-in defaults if missing)",
+        Msg::HelpLang => "Message language (default: from LC_ALL, LC_MESSAGES or LANG)",
+        Msg::HelpAttach => "Use a running daemon instead of sampling (default socket if omitted, e.g. --attach=/run/nbmon.sock)",
+        Msg::HelpCsv => "Append every tick's counters and rates per interface to a CSV file",
+        Msg::HelpRemote => "Show the interfaces of a remote agent (nbmon agent) instead of sampling (default port 9960)",
+        Msg::HelpRemotePsk => "Pre-shared key file for the agent's authentication (with --remote)",
+        Msg::HelpSnmp => "Show the IF-MIB ports of an SNMP agent (switch, router) instead of sampling (default port 161)",
+        Msg::HelpSnmpCommunity => "SNMPv2c community (default: public)",
+        Msg::HelpSnmpUser => "SNMPv3 user name (uses v3 instead of v2c)",
+        Msg::HelpSnmpAuth => "SNMPv3 authentication protocol",
+        Msg::HelpSnmpAuthFile => "SNMPv3 authentication passphrase file (with --snmp-user)",
+        Msg::HelpSnmpPrivFile => "SNMPv3 privacy (AES-128) passphrase file (with --snmp-auth-file)",
+        Msg::CmdTui => "Enhanced TUI - real-time graphs like nload (default)",
+        Msg::CmdClassic => "Classic TUI - a simple interface list",
+        Msg::CmdSimple => "Simple console mode - print interface details and 5 measurements, then exit",
+        Msg::CmdReport => "Print stored traffic totals as a table (default: daily)",
+        Msg::CmdQuery => "Aggregate stored per-minute rates into buckets (default: average over the last hour)",
+        Msg::CmdExport => "Write every tick as JSON Lines, a sar-style table or CSV",
+        Msg::CmdRecord => "Record totals and rates without a TUI (and CSV with --csv)",
+        Msg::CmdQuota => "Set or show per-interface billing-cycle data caps",
+        Msg::CmdQuotaShow => "Usage in the current billing cycle (default)",
+        Msg::CmdQuotaSet => "Set an interface's cap",
+        Msg::CmdQuotaClear => "Remove an interface's cap",
+        Msg::CmdImport => "Import vnstat --json or sar -n DEV history into the totals",
+        Msg::HelpHourly => "Hourly totals",
+        Msg::HelpDaily => "Daily totals (default)",
+        Msg::HelpMonthly => "Monthly totals",
+        Msg::HelpSince => "Start time (now, a duration ago like 2h, @unix, YYYY-MM-DD HH:MM, HH:MM)",
+        Msg::HelpUntil => "End time (same formats as --since)",
+        Msg::HelpAgg => "How to reduce each bucket",
+        Msg::HelpBucket => "Bucket length (e.g. 5m, 1h; default: the whole range as one bucket)",
+        Msg::HelpJson => "Print the result as JSON",
+        Msg::HelpFormat => "Output format (json: JSON Lines, batch: fixed-width table, csv: CSV file)",
+        Msg::HelpOutput => "Output file (required for csv, appended to)",
+        Msg::HelpExportCount => "Number of ticks to write (default: until interrupted)",
+        Msg::HelpHeaderEvery => "Repeat the batch header every N lines (0: only once)",
+        Msg::HelpRecordCsv => "Also append to a CSV file",
+        Msg::HelpRecordCount => "Number of ticks to record (default: until interrupted)",
+        Msg::HelpQuotaName => "Interface name",
+        Msg::HelpQuotaLimit => "Cap (e.g. 500GB, 1TiB)",
+        Msg::HelpResetDay => "Day of the month the usage resets (1-31)",
+        Msg::HelpQuotaCount => "Directions counted against the cap",
+        Msg::HelpImportFormat => "History format",
+        Msg::HelpImportSource => "File to read (- for standard input)",
+        Msg::CmdCheck => "Nagios/Icinga check: measure one interface and exit 0/1/2/3 (OK/WARNING/CRITICAL/UNKNOWN)",
+        Msg::HelpWarnRx => "WARNING when the average receive rate exceeds this (e.g. 800Mbit, 100MB)",
+        Msg::HelpCritRx => "CRITICAL when the average receive rate exceeds this",
+        Msg::HelpWarnTx => "WARNING when the average transmit rate exceeds this",
+        Msg::HelpCritTx => "CRITICAL when the average transmit rate exceeds this",
+        Msg::HelpWarnErrors => "WARNING when receive + transmit errors per second exceed this (e.g. 10/s)",
+        Msg::HelpCritErrors => "CRITICAL when receive + transmit errors per second exceed this",
+        Msg::HelpWarnDrops => "WARNING when receive + transmit drops per second exceed this",
+        Msg::HelpCritDrops => "CRITICAL when receive + transmit drops per second exceed this",
+        Msg::HelpDuration => "How long to measure (e.g. 10s, 1m)",
+        Msg::CmdServe => "Serve Prometheus metrics over HTTP (/metrics)",
+        Msg::HelpListen => "Address and port to listen on",
+        Msg::CmdPush => "Push counters and rates to InfluxDB/Telegraf, Graphite or StatsD over TCP/UDP",
+        Msg::HelpPushTo => "Endpoint to send to (tcp://host:port or udp://host:port; without a scheme, udp for statsd/dogstatsd and tcp otherwise)",
+        Msg::HelpPushFormat => "Line format (influx: line protocol, graphite: plaintext, statsd/dogstatsd: gauges and counters)",
+        Msg::HelpMetricPrefix => "Influx measurement name / first Graphite path component",
+        Msg::HelpBatchSize => "Send as soon as this many lines are queued",
+        Msg::HelpFlushInterval => "Send queued lines at least this often (e.g. 10s)",
+        Msg::HelpPushCount => "Number of ticks to push (default: until interrupted)",
+        Msg::CmdOtlp => "Export metrics to an OpenTelemetry collector over OTLP/HTTP",
+        Msg::HelpOtlpEndpoint => "Collector URL (/v1/metrics is appended when there is no path)",
+        Msg::HelpOtlpHeader => "Extra request header, repeatable (e.g. Authorization=Bearer abc)",
+        Msg::HelpExportInterval => "Time between exports (rates are averaged over it)",
+        Msg::HelpOtlpCount => "Number of exports to send (default: until interrupted)",
+        Msg::CmdMqtt => "Publish rates and totals to an MQTT broker (with Home Assistant discovery)",
+        Msg::HelpBroker => "Broker address (host or host:port, default port 1883)",
+        Msg::HelpTopicPrefix => "First topic level (<prefix>/<host>/<iface>/rx_bps)",
+        Msg::HelpClientId => "MQTT client ID (default: nbmon-<host>)",
+        Msg::HelpMqttUsername => "Broker user name",
+        Msg::HelpMqttPassword => "Read the broker password from this file (with --username)",
+        Msg::HelpDiscoveryPrefix => "Home Assistant discovery topic prefix",
+        Msg::HelpNoDiscovery => "Do not publish Home Assistant discovery messages",
+        Msg::HelpMqttCount => "Number of ticks to publish (default: until interrupted)",
+        Msg::CmdAgent => "Serve the daemon API over TCP (watch it with tui --remote)",
+        Msg::HelpAgentListen => "Address and port to listen on (--listen 0.0.0.0:9960 to allow other hosts)",
+        Msg::HelpAgentPsk => "Authenticate every connection with the key in this file (HMAC-SHA256 challenge, the key is never sent)",
+        Msg::CmdDashboard => "Table of every interface on the agents in the config's [[hosts]], busiest first (Enter opens a host)",
+        Msg::HelpDashboardHost => "Agent to show instead of the config's [[hosts]] (HOST[:PORT], repeatable)",
+        Msg::HelpDashboardPsk => "Pre-shared key file for the --host agents",
+        Msg::CmdWeb => "Serve a live dashboard for the browser (interface picker, download/upload charts, totals table)",
+        Msg::HelpWebListen => "Address and port to listen on (--listen 0.0.0.0:8080 to share the link with others)",
+        Msg::CmdDaemon => "Sample all interfaces in the background and serve them on a Unix socket",
+        Msg::HelpSocket => "Unix socket path (default: $NBMON_SOCKET, /run/nbmon.sock as root, else $XDG_RUNTIME_DIR/nbmon.sock)",
+        // 공통 화면 문구
+        Msg::TitleClassic => "NBMon - Cross-platform Network Bandwidth Monitor",
+        Msg::TitleImproved => "nbmon: Cross-platform Network Bandwidth Monitor",
+        Msg::TitleDashboard => "nbmon: Hosts",
+        Msg::TitleSimple => "NBMon - Cross-platform Network Bandwidth Monitor (Simple Mode)",
+        Msg::StatusUp => "UP",
+        Msg::StatusDown => "DOWN",
+        Msg::KindLoopback => "Loopback",
+        Msg::KindVirtual => "Virtual",
+        Msg::KindPhysical => "Physical",
+        Msg::LabelIndex => "Index",
+        Msg::LabelName => "Name",
+        Msg::LabelMac => "MAC",
+        Msg::LabelStatus => "Status",
+        Msg::LabelType => "Type",
+        Msg::LabelSpeed => "Speed",
+        Msg::LabelIp => "IP",
+        Msg::LabelIpAddresses => "IP Addresses",
+        Msg::LabelPublicIp => "Public IP",
+        Msg::LabelTotal => "Total",
+        Msg::IpPrivate => "Private",
+        Msg::IpPublic => "Public",
+        Msg::IpWithPublic => "{0} (Public: {1})",
+        Msg::IpFetchingPublic => "{0} (Fetching public IP...)",
+        Msg::IpNone => "None",
+        Msg::IpNoneInstallTools => "None (install 'ip' or 'ifconfig')",
+        Msg::PublicIpUnavailable => "Unable to fetch (check internet connection)",
+        Msg::Download => "Download",
+        Msg::Upload => "Upload",
+        Msg::Incoming => "Incoming:",
+        Msg::Outgoing => "Outgoing:",
+        Msg::Max => "Max",
+        Msg::Min => "Min",
+        Msg::TotalDownUp => "{0} ↓ {1} ↑",
+        Msg::QuotaCapReached => "cap reached",
+        Msg::QuotaCapEta => "cap ~{0}",
+        Msg::QuotaWithinCap => "within cap",
+        Msg::QuotaResets => "resets {0}",
+        Msg::QuotaGauge => "Quota: {0} / {1} ({2}%) · {3} left · {4}",
+        // TUI
+        Msg::BlockStatus => "Status",
+        Msg::BlockInterfaces => "Network Interfaces - Filter: {0}",
+        Msg::BlockDetails => "Interface Details",
+        Msg::HelpClassic => "Controls: ↑/↓ or {0}/{1} Select interface | {2}: Update | {3} | {4}: Quit",
+        Msg::HelpImproved => "←/→ or {0}/{1}: Switch interface | {2}/{3}: Zoom 1m/10m/1h/24h | {4}: Update | {5}: Reset history | {6}: Quit",
+        Msg::HelpDashboard => "↑/↓ or {0}/{1}: Select | Enter: Open host | {2}: Quit",
+        Msg::ColumnHost => "Host",
+        Msg::ColumnInterface => "Interface",
+        Msg::DashboardHosts => "{0}/{1} hosts connected",
+        Msg::DashboardUnreachable => "unreachable",
+        Msg::DashboardConnecting => "connecting...",
+        Msg::FilterTitle => " Filter: {0} ({1}) ",
+        Msg::FilterHint => "{0}: patterns/virtual/down/loopback",
+        Msg::FilterDefault => "default",
+        Msg::FilterPatternsOff => "patterns off",
+        Msg::FilterNoVirtual => "no virtual",
+        Msg::FilterDown => "down",
+        Msg::FilterLoopback => "loopback",
+        // 콘솔 출력
+        Msg::NoInterfacesFound => "No network interfaces found!",
+        Msg::NoInterfacesMatch => "No network interfaces match the interface filters",
+        Msg::NoActiveInterfaces => "No active interfaces found!",
+        Msg::DetectedInterfaces => "Detected Network Interfaces:",
+        Msg::SimpleTesting => "Testing bandwidth monitoring (5 updates, every {0}s)...",
+        Msg::SimpleUpdate => "[Update {0}]",
+        Msg::SimpleTotal => "{0} down / {1} up",
+        Msg::MonitoringComplete => "Monitoring complete!",
+        Msg::ApplicationError => "Application error: {0}",
+        Msg::ErrorChain => "Error chain:",
+        Msg::Warning => "Warning: {0}",
+        Msg::QuotaNone => "No quotas configured. Use: nbmon quota set <iface> <limit>",
+        Msg::QuotaLeftResets => "{0} left, resets {1}",
+        Msg::QuotaSaved => "Quota for {0}: {1} ({2}) per cycle, resets on day {3}",
+        Msg::QuotaRemoved => "Quota for {0} removed",
+        Msg::BucketsImported => "{0}: {1} buckets imported",
+        Msg::QueryNoData => "No rate data recorded in this range.",
+        Msg::QueryRecordedBy => "Per-minute rates are recorded while the TUI, `nbmon record`, `nbmon daemon`, `nbmon agent` or `nbmon web` is running.",
+        Msg::ServingMetrics => "Serving Prometheus metrics on http://{0}/metrics",
+        Msg::ServingWeb => "Serving the web dashboard on http://{0}/",
+        Msg::PushingTo => "Pushing {0} lines to {1}",
+        Msg::PushRetrying => "{0} (retrying in {1}s)",
+        Msg::PushDropped => "{0} lines were dropped because the endpoint was unavailable or too slow",
+        Msg::ExportingOtlp => "Exporting OTLP metrics to {0} every {1}s",
+        Msg::OtlpExportFailed => "OTLP export failed: {0}",
+        Msg::PublishingMqtt => "Publishing to MQTT broker {0} (availability topic {1})",
+        Msg::AgentListening => "Sampling {0} interfaces every {1}s, agent listening on {2}",
+        Msg::AgentWithoutPsk => "{0} is reachable from other hosts without authentication; consider --psk-file",
+        Msg::DaemonListening => "Sampling {0} interfaces every {1}s, listening on {2}",
+        // 오류
+        Msg::ErrListInterfaces => "Failed to get network interfaces list",
+        Msg::ErrRawMode => "Failed to enable terminal raw mode",
+        Msg::ErrRestoreTerminal => "Failed to restore terminal state",
+        Msg::ErrInitApp => "Failed to initialize the application",
+        Msg::ErrUpdateStats => "Failed to update network statistics",
+        Msg::ErrStatsFor => "Failed to get stats for {0}: {1}",
+        Msg::ErrInterfaceNotFound => "Interface not found: {0}",
+        Msg::ErrInterfaceHidden => "Interface {0} not found, not active or filtered out",
+        Msg::ErrIndexOutOfBounds => "Current interface index {0} is out of bounds (max: {1})",
+        Msg::ErrInvalidValue => "Invalid {0}: {1}",
+        Msg::ErrInvalidLimit => "Invalid limit: {0} (e.g. 500GB, 1TiB)",
+        Msg::ErrNoQuota => "No quota configured for {0}",
+        Msg::ErrReadStdin => "Failed to read standard input",
+        Msg::ErrReadFile => "Failed to read {0}",
+        Msg::ErrSinceAfterUntil => "--since must be earlier than --until",
+        Msg::ErrCsvNeedsOutput => "--format csv requires --output <FILE>",
+        Msg::ErrOutputOnlyCsv => "--output is only supported with --format csv, not {0}",
+        Msg::ErrWriteBatch => "Failed to write batch output",
+        Msg::ErrListen => "Failed to listen on {0}",
+        Msg::ErrNoDaemon => "No running daemon found (tried {0}); start one with `nbmon daemon`",
+        Msg::ErrAttachUnsupported => "--attach is not supported by this command",
+        Msg::ErrNoHosts => "No hosts to show; add [[hosts]] to {0} or use --host",
+        Msg::ErrNoInterface => "No interface given; use --iface <NAME>",
+        Msg::ErrNoCounters => "Interface counters are not available",
+        Msg::ErrNeedsUnixSockets => "{0} requires Unix domain sockets",
+        Msg::ErrOptionsConflict => "{0} and {1} cannot be used together",
+        Msg::ErrOptionBeforeCommand => "{0} before a command is not allowed; use `{1}`",
+    }
+}
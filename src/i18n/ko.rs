@@ -0,0 +1,263 @@
+// 한국어 메시지 카탈로그
+// 자리표시자 {0}, {1}, ...의 순서는 어순에 맞게 바꿔도 되지만 개수는 영어와 같아야 함
+
+use super::Msg;
+
+pub(super) fn text(msg: Msg) -> &'static str {
+    match msg {
+        // 커맨드라인 도움말
+        Msg::CliAbout => "크로스플랫폼 네트워크 대역폭 모니터 (nload/bmon 스타일의 실시간 트래픽 모니터)",
+        Msg::CliAfterHelp => "\
+키보드 단축키 (TUI 모드):
+    ←/h, →/l      이전/다음 네트워크 인터페이스
+    +/-           그래프 시간 범위 확대/축소 (1m/10m/1h/24h)
+    Space         수동 업데이트
+    r             히스토리 초기화
+    f/v/d/o       필터 켜기/끄기 (이름 패턴/가상 숨김/DOWN 표시/루프백 표시)
+    q             프로그램 종료
+
+예제:
+    nbmon                                   # 향상된 TUI 실행
+    nbmon classic -i eth0                   # eth0을 선택한 클래식 TUI
+    nbmon --interval 0.5 --units bits       # 0.5초 간격, 비트 단위 표시
+    nbmon --units bits --scale M            # 항상 Mbit/s로 표시
+    nbmon --exclude 'cali*' --exclude 're:^veth' --hide-virtual
+    nbmon export --format json --count 3 | jq .
+    nbmon export --format batch --count 60 > eth.log
+    nbmon record --csv samples.csv --interval 5
+    nbmon report --monthly
+    nbmon query -i eth0 --since 02:00 --until 03:00 --agg max
+    nbmon quota set eth0 1TB --reset-day 15
+    nbmon serve --listen 0.0.0.0:9959
+    nbmon push --to udp://127.0.0.1:8094 --format influx
+    nbmon otlp --endpoint http://127.0.0.1:4318 --export-interval 30s
+    nbmon mqtt --broker 192.168.1.10 --username nbmon --password-file mqtt.pass
+    nbmon daemon &                          # 공유 백그라운드 샘플러
+    nbmon --attach                          # 데몬의 히스토리로 TUI 실행
+    nbmon tui --remote router --psk-file router.psk
+    nbmon dashboard                         # 여러 호스트를 한 표에
+    nbmon tui --snmp switch1 --community monitoring
+    nbmon web --listen 0.0.0.0:8080         # 브라우저에서 실시간 그래프
+    nbmon check -i eth0 --warn-rx 800Mbit --crit-rx 950Mbit --duration 10s
+    vnstat --json | nbmon import vnstat -
+
+저장소: https://github.com/i978sukhoi/nbmon",
+        Msg::HelpHelp => "도움말 출력",
+        Msg::HelpVersion => "버전 정보 출력",
+        Msg::HelpInterface => "대상 인터페이스 (TUI는 처음 선택, export/record/simple/check는 필터 대신 이 인터페이스만, report/query는 필터)",
+        Msg::HelpInterval => "샘플링 간격 (초, 소수 허용, 기본 1)",
+        Msg::HelpHistory => "인터페이스마다 보관할 원본 샘플 수 (기본: 향상된 TUI 600, 클래식 60)",
+        Msg::HelpUnits => "속도 표시 단위",
+        Msg::HelpPrefix => "단위 접두사 (si: kB/Mbit 1000 단위, iec: KiB/Mibit 1024 단위; 기본: bytes는 iec, bits는 si)",
+        Msg::HelpScale => "속도를 항상 같은 접두사로 표시 (auto, 1, k, M, G, T; 예: --units bits --scale M → 항상 Mbit/s)",
+        Msg::HelpNoPublicIp => "Public IP를 외부 서비스에서 조회하지 않음",
+        Msg::HelpInclude => "이 패턴과 일치하는 인터페이스만 표시 (glob 또는 re:정규식, 여러 번 지정 가능)",
+        Msg::HelpExclude => "이 패턴과 일치하는 인터페이스는 숨김 (glob 또는 re:정규식, 여러 번 지정 가능)",
+        Msg::HelpHideVirtual => "가상 인터페이스(veth, docker, VPN 등) 숨김",
+        Msg::HelpShowDown => "DOWN 상태 인터페이스도 표시",
+        Msg::HelpShowLoopback => "루프백 인터페이스도 표시",
+        Msg::HelpConfig => "설정 파일 경로 (기본: $XDG_CONFIG_HOME/nbmon/config.toml, 없으면 기본 설정)",
+        Msg::HelpLang => "메시지 언어 (기본: LC_ALL, LC_MESSAGES, LANG 순으로 결정)",
+        Msg::HelpAttach => "직접 샘플링하지 않고 실행 중인 데몬에 붙음 (생략하면 기본 소켓, 예: --attach=/run/nbmon.sock)",
+        Msg::HelpCsv => "매 틱마다 인터페이스별 카운터와 속도를 CSV 파일에 추가",
+        Msg::HelpRemote => "직접 샘플링하지 않고 원격 에이전트(nbmon agent)의 인터페이스 표시 (기본 포트 9960)",
+        Msg::HelpRemotePsk => "에이전트 인증에 사용할 사전 공유 키 파일 (--remote와 함께)",
+        Msg::HelpSnmp => "직접 샘플링하지 않고 SNMP 에이전트(스위치, 라우터)의 IF-MIB 포트 표시 (기본 포트 161)",
+        Msg::HelpSnmpCommunity => "SNMPv2c 커뮤니티 (기본: public)",
+        Msg::HelpSnmpUser => "SNMPv3 사용자 이름 (지정하면 v2c 대신 v3 사용)",
+        Msg::HelpSnmpAuth => "SNMPv3 인증 프로토콜",
+        Msg::HelpSnmpAuthFile => "SNMPv3 인증 비밀번호 파일 (--snmp-user와 함께)",
+        Msg::HelpSnmpPrivFile => "SNMPv3 암호화(AES-128) 비밀번호 파일 (--snmp-auth-file과 함께)",
+        Msg::CmdTui => "향상된 TUI - nload 스타일의 실시간 그래프 (기본)",
+        Msg::CmdClassic => "클래식 TUI - 단순한 리스트 형태의 인터페이스",
+        Msg::CmdSimple => "단순 콘솔 모드 - 인터페이스 정보와 5회 측정값 출력 후 종료",
+        Msg::CmdReport => "저장된 트래픽 집계를 표로 출력 (기본: 일별)",
+        Msg::CmdQuery => "저장된 분 단위 속도 기록을 구간별로 집계 (기본: 지난 1시간 평균)",
+        Msg::CmdExport => "매 틱의 통계를 JSON Lines / sar 스타일 표 / CSV로 출력",
+        Msg::CmdRecord => "TUI 없이 집계/속도 기록 (--csv로 CSV도 기록)",
+        Msg::CmdQuota => "인터페이스별 청구 주기 데이터 한도 설정/확인",
+        Msg::CmdQuotaShow => "현재 청구 주기 사용 현황 (기본)",
+        Msg::CmdQuotaSet => "인터페이스의 한도 설정",
+        Msg::CmdQuotaClear => "인터페이스의 한도 삭제",
+        Msg::CmdImport => "vnstat --json / sar -n DEV 기록을 집계에 가져오기",
+        Msg::HelpHourly => "시간별 합계",
+        Msg::HelpDaily => "일별 합계 (기본)",
+        Msg::HelpMonthly => "월별 합계",
+        Msg::HelpSince => "시작 시각 (now, 2h 같은 기간 전, @unix, YYYY-MM-DD HH:MM, HH:MM)",
+        Msg::HelpUntil => "끝 시각 (형식은 --since와 같음)",
+        Msg::HelpAgg => "구간마다 값을 줄이는 방법",
+        Msg::HelpBucket => "구간 길이 (예: 5m, 1h; 생략하면 전체 범위를 한 구간으로)",
+        Msg::HelpJson => "결과를 JSON으로 출력",
+        Msg::HelpFormat => "출력 형식 (json: JSON Lines, batch: 고정 폭 표, csv: CSV 파일)",
+        Msg::HelpOutput => "출력 파일 (csv 형식에서 필수, 파일 끝에 추가)",
+        Msg::HelpExportCount => "출력 횟수 (기본: 중단할 때까지)",
+        Msg::HelpHeaderEvery => "batch 형식의 머리글 반복 간격 (줄 수, 0이면 한 번만)",
+        Msg::HelpRecordCsv => "집계와 함께 CSV 파일에도 기록",
+        Msg::HelpRecordCount => "기록 횟수 (기본: 중단할 때까지)",
+        Msg::HelpQuotaName => "인터페이스 이름",
+        Msg::HelpQuotaLimit => "한도 (예: 500GB, 1TiB)",
+        Msg::HelpResetDay => "매월 사용량이 초기화되는 날짜 (1-31)",
+        Msg::HelpQuotaCount => "한도에 포함할 방향",
+        Msg::HelpImportFormat => "기록 형식",
+        Msg::HelpImportSource => "읽을 파일 (-이면 표준 입력)",
+        Msg::CmdCheck => "Nagios/Icinga 점검: 한 인터페이스를 측정하고 0/1/2/3(OK/WARNING/CRITICAL/UNKNOWN)으로 종료",
+        Msg::HelpWarnRx => "평균 수신 속도가 이 값을 넘으면 WARNING (예: 800Mbit, 100MB)",
+        Msg::HelpCritRx => "평균 수신 속도가 이 값을 넘으면 CRITICAL",
+        Msg::HelpWarnTx => "평균 송신 속도가 이 값을 넘으면 WARNING",
+        Msg::HelpCritTx => "평균 송신 속도가 이 값을 넘으면 CRITICAL",
+        Msg::HelpWarnErrors => "초당 수신+송신 에러가 이 값을 넘으면 WARNING (예: 10/s)",
+        Msg::HelpCritErrors => "초당 수신+송신 에러가 이 값을 넘으면 CRITICAL",
+        Msg::HelpWarnDrops => "초당 수신+송신 드롭이 이 값을 넘으면 WARNING",
+        Msg::HelpCritDrops => "초당 수신+송신 드롭이 이 값을 넘으면 CRITICAL",
+        Msg::HelpDuration => "측정 시간 (예: 10s, 1m)",
+        Msg::CmdServe => "HTTP로 Prometheus 메트릭 제공 (/metrics)",
+        Msg::HelpListen => "수신할 주소와 포트",
+        Msg::CmdPush => "카운터와 속도를 TCP/UDP로 InfluxDB/Telegraf, Graphite 또는 StatsD에 보내기",
+        Msg::HelpPushTo => "보낼 곳 (tcp://host:port 또는 udp://host:port; 스킴이 없으면 statsd/dogstatsd는 udp, 나머지는 tcp)",
+        Msg::HelpPushFormat => "줄 형식 (influx: 라인 프로토콜, graphite: 평문, statsd/dogstatsd: 게이지와 카운터)",
+        Msg::HelpMetricPrefix => "Influx 측정 이름 / Graphite 경로의 첫 부분",
+        Msg::HelpBatchSize => "줄이 이만큼 모이면 바로 보냄",
+        Msg::HelpFlushInterval => "모인 줄을 적어도 이 간격마다 보냄 (예: 10s)",
+        Msg::HelpPushCount => "보낼 틱 수 (기본: 중단할 때까지)",
+        Msg::CmdOtlp => "OTLP/HTTP로 OpenTelemetry 컬렉터에 메트릭 보내기",
+        Msg::HelpOtlpEndpoint => "컬렉터 URL (경로가 없으면 /v1/metrics를 붙임)",
+        Msg::HelpOtlpHeader => "요청에 추가할 헤더, 여러 번 지정 가능 (예: Authorization=Bearer abc)",
+        Msg::HelpExportInterval => "메트릭을 보내는 간격 (속도는 이 구간의 평균)",
+        Msg::HelpOtlpCount => "보낼 횟수 (기본: 중단할 때까지)",
+        Msg::CmdMqtt => "MQTT 브로커에 속도와 누적 값 발행 (Home Assistant 자동 발견 포함)",
+        Msg::HelpBroker => "브로커 주소 (host 또는 host:port, 기본 포트 1883)",
+        Msg::HelpTopicPrefix => "토픽의 첫 부분 (<prefix>/<host>/<iface>/rx_bps)",
+        Msg::HelpClientId => "MQTT 클라이언트 ID (기본: nbmon-<host>)",
+        Msg::HelpMqttUsername => "브로커 사용자 이름",
+        Msg::HelpMqttPassword => "브로커 비밀번호를 이 파일에서 읽음 (--username과 함께)",
+        Msg::HelpDiscoveryPrefix => "Home Assistant 발견 토픽 접두사",
+        Msg::HelpNoDiscovery => "Home Assistant 발견 메시지를 보내지 않음",
+        Msg::HelpMqttCount => "발행할 틱 수 (기본: 중단할 때까지)",
+        Msg::CmdAgent => "데몬 API를 TCP로 제공 (tui --remote로 보기)",
+        Msg::HelpAgentListen => "수신할 주소와 포트 (다른 호스트에서 보려면 --listen 0.0.0.0:9960)",
+        Msg::HelpAgentPsk => "연결마다 이 파일의 키로 인증 (HMAC-SHA256 챌린지, 키는 전송되지 않음)",
+        Msg::CmdDashboard => "설정 파일 [[hosts]]의 에이전트들의 모든 인터페이스를 가장 바쁜 순서로 표시 (Enter로 호스트 열기)",
+        Msg::HelpDashboardHost => "설정 파일의 [[hosts]] 대신 표시할 에이전트 (HOST[:PORT], 여러 번 지정 가능)",
+        Msg::HelpDashboardPsk => "--host 에이전트의 사전 공유 키 파일",
+        Msg::CmdWeb => "브라우저용 실시간 대시보드 제공 (인터페이스 선택, 다운로드/업로드 그래프, 합계 표)",
+        Msg::HelpWebListen => "수신할 주소와 포트 (다른 사람에게 링크를 공유하려면 --listen 0.0.0.0:8080)",
+        Msg::CmdDaemon => "백그라운드에서 모든 인터페이스를 샘플링하고 Unix 소켓으로 제공",
+        Msg::HelpSocket => "Unix 소켓 경로 (기본: $NBMON_SOCKET, root면 /run/nbmon.sock, 아니면 $XDG_RUNTIME_DIR/nbmon.sock)",
+        // 공통 화면 문구
+        Msg::TitleClassic => "NBMon - 크로스플랫폼 네트워크 대역폭 모니터",
+        Msg::TitleImproved => "nbmon: 크로스플랫폼 네트워크 대역폭 모니터",
+        Msg::TitleDashboard => "nbmon: 호스트",
+        Msg::TitleSimple => "NBMon - 크로스플랫폼 네트워크 대역폭 모니터 (단순 모드)",
+        Msg::StatusUp => "UP",
+        Msg::StatusDown => "DOWN",
+        Msg::KindLoopback => "루프백",
+        Msg::KindVirtual => "가상",
+        Msg::KindPhysical => "물리",
+        Msg::LabelIndex => "인덱스",
+        Msg::LabelName => "이름",
+        Msg::LabelMac => "MAC",
+        Msg::LabelStatus => "상태",
+        Msg::LabelType => "종류",
+        Msg::LabelSpeed => "속도",
+        Msg::LabelIp => "IP",
+        Msg::LabelIpAddresses => "IP 주소",
+        Msg::LabelPublicIp => "Public IP",
+        Msg::LabelTotal => "합계",
+        Msg::IpPrivate => "사설",
+        Msg::IpPublic => "공인",
+        Msg::IpWithPublic => "{0} (Public: {1})",
+        Msg::IpFetchingPublic => "{0} (Public IP 조회 중...)",
+        Msg::IpNone => "없음",
+        Msg::IpNoneInstallTools => "없음 ('ip' 또는 'ifconfig' 설치 필요)",
+        Msg::PublicIpUnavailable => "가져올 수 없음 (인터넷 연결 확인)",
+        Msg::Download => "다운로드",
+        Msg::Upload => "업로드",
+        Msg::Incoming => "수신:",
+        Msg::Outgoing => "송신:",
+        Msg::Max => "최대",
+        Msg::Min => "최소",
+        Msg::TotalDownUp => "{0} ↓ {1} ↑",
+        Msg::QuotaCapReached => "한도 도달",
+        Msg::QuotaCapEta => "~{0} 한도 도달 예상",
+        Msg::QuotaWithinCap => "한도 이내",
+        Msg::QuotaResets => "{0} 초기화",
+        Msg::QuotaGauge => "한도: {0} / {1} ({2}%) · {3} 남음 · {4}",
+        // TUI
+        Msg::BlockStatus => "상태",
+        Msg::BlockInterfaces => "네트워크 인터페이스 - 필터: {0}",
+        Msg::BlockDetails => "인터페이스 정보",
+        Msg::HelpClassic => "조작: ↑/↓ 또는 {0}/{1} 인터페이스 선택 | {2}: 업데이트 | {3} | {4}: 종료",
+        Msg::HelpImproved => "←/→ 또는 {0}/{1}: 인터페이스 전환 | {2}/{3}: 확대/축소 1m/10m/1h/24h | {4}: 업데이트 | {5}: 히스토리 초기화 | {6}: 종료",
+        Msg::HelpDashboard => "↑/↓ 또는 {0}/{1}: 선택 | Enter: 호스트 열기 | {2}: 종료",
+        Msg::ColumnHost => "호스트",
+        Msg::ColumnInterface => "인터페이스",
+        Msg::DashboardHosts => "호스트 {1}개 중 {0}개 연결됨",
+        Msg::DashboardUnreachable => "연결 안 됨",
+        Msg::DashboardConnecting => "연결 중...",
+        Msg::FilterTitle => " 필터: {0} ({1}) ",
+        Msg::FilterHint => "{0}: 패턴/가상/DOWN/루프백",
+        Msg::FilterDefault => "기본",
+        Msg::FilterPatternsOff => "패턴 꺼짐",
+        Msg::FilterNoVirtual => "가상 숨김",
+        Msg::FilterDown => "DOWN 표시",
+        Msg::FilterLoopback => "루프백 표시",
+        // 콘솔 출력
+        Msg::NoInterfacesFound => "네트워크 인터페이스를 찾을 수 없습니다!",
+        Msg::NoInterfacesMatch => "인터페이스 필터와 일치하는 네트워크 인터페이스가 없습니다",
+        Msg::NoActiveInterfaces => "활성 인터페이스가 없습니다!",
+        Msg::DetectedInterfaces => "감지된 네트워크 인터페이스:",
+        Msg::SimpleTesting => "대역폭 측정 중 (5회, {0}초 간격)...",
+        Msg::SimpleUpdate => "[측정 {0}]",
+        Msg::SimpleTotal => "{0} 다운로드 / {1} 업로드",
+        Msg::MonitoringComplete => "측정 완료!",
+        Msg::ApplicationError => "애플리케이션 오류: {0}",
+        Msg::ErrorChain => "오류 원인:",
+        Msg::Warning => "경고: {0}",
+        Msg::QuotaNone => "설정된 한도가 없습니다. 사용법: nbmon quota set <인터페이스> <한도>",
+        Msg::QuotaLeftResets => "{0} 남음, {1} 초기화",
+        Msg::QuotaSaved => "{0} 한도: 주기마다 {1} ({2}), 매월 {3}일 초기화",
+        Msg::QuotaRemoved => "{0} 한도를 삭제했습니다",
+        Msg::BucketsImported => "{0}: {1}개 구간을 가져왔습니다",
+        Msg::QueryNoData => "이 범위에 기록된 속도가 없습니다.",
+        Msg::QueryRecordedBy => "분 단위 속도는 TUI, `nbmon record`, `nbmon daemon`, `nbmon agent`, `nbmon web`이 실행 중일 때 기록됩니다.",
+        Msg::ServingMetrics => "Prometheus 메트릭 제공 중: http://{0}/metrics",
+        Msg::ServingWeb => "웹 대시보드 제공 중: http://{0}/",
+        Msg::PushingTo => "{0} 형식으로 {1}에 보내는 중",
+        Msg::PushRetrying => "{0} ({1}초 후 다시 시도)",
+        Msg::PushDropped => "보낼 곳에 연결할 수 없거나 느려서 {0}줄을 버렸습니다",
+        Msg::ExportingOtlp => "OTLP 메트릭을 {1}초마다 {0}에 보내는 중",
+        Msg::OtlpExportFailed => "OTLP 메트릭을 보내지 못했습니다: {0}",
+        Msg::PublishingMqtt => "MQTT 브로커 {0}에 발행하는 중 (가용성 토픽 {1})",
+        Msg::AgentListening => "인터페이스 {0}개를 {1}초마다 샘플링, 에이전트가 {2}에서 대기 중",
+        Msg::AgentWithoutPsk => "{0}은(는) 인증 없이 다른 호스트에서 접속할 수 있습니다; --psk-file 사용을 고려하세요",
+        Msg::DaemonListening => "인터페이스 {0}개를 {1}초마다 샘플링, {2}에서 대기 중",
+        // 오류
+        Msg::ErrListInterfaces => "네트워크 인터페이스 목록을 가져오지 못했습니다",
+        Msg::ErrRawMode => "터미널 raw 모드를 켜지 못했습니다",
+        Msg::ErrRestoreTerminal => "터미널 상태를 복원하지 못했습니다",
+        Msg::ErrInitApp => "애플리케이션을 초기화하지 못했습니다",
+        Msg::ErrUpdateStats => "네트워크 통계를 갱신하지 못했습니다",
+        Msg::ErrStatsFor => "{0}의 통계를 가져오지 못했습니다: {1}",
+        Msg::ErrInterfaceNotFound => "인터페이스를 찾을 수 없습니다: {0}",
+        Msg::ErrInterfaceHidden => "인터페이스 {0}을(를) 찾을 수 없거나, 비활성 상태이거나, 필터로 숨겨졌습니다",
+        Msg::ErrIndexOutOfBounds => "현재 인터페이스 인덱스 {0}이(가) 범위를 벗어났습니다 (최대: {1})",
+        Msg::ErrInvalidValue => "잘못된 {0} 값: {1}",
+        Msg::ErrInvalidLimit => "잘못된 한도: {0} (예: 500GB, 1TiB)",
+        Msg::ErrNoQuota => "{0}에 설정된 한도가 없습니다",
+        Msg::ErrReadStdin => "표준 입력을 읽지 못했습니다",
+        Msg::ErrReadFile => "{0}을(를) 읽지 못했습니다",
+        Msg::ErrSinceAfterUntil => "--since는 --until보다 이전이어야 합니다",
+        Msg::ErrCsvNeedsOutput => "--format csv에는 --output <FILE>이 필요합니다",
+        Msg::ErrOutputOnlyCsv => "--output은 --format csv에서만 쓸 수 있습니다 ({0} 형식은 지원하지 않음)",
+        Msg::ErrWriteBatch => "batch 출력을 쓰지 못했습니다",
+        Msg::ErrListen => "{0}에서 연결을 받을 수 없습니다",
+        Msg::ErrNoDaemon => "실행 중인 데몬이 없습니다 ({0} 확인); `nbmon daemon`으로 시작하세요",
+        Msg::ErrAttachUnsupported => "이 명령은 --attach를 지원하지 않습니다",
+        Msg::ErrNoHosts => "표시할 호스트가 없습니다; {0}에 [[hosts]]를 추가하거나 --host를 사용하세요",
+        Msg::ErrNoInterface => "인터페이스가 지정되지 않았습니다; --iface <NAME>을 사용하세요",
+        Msg::ErrNoCounters => "인터페이스 카운터를 읽을 수 없습니다",
+        Msg::ErrNeedsUnixSockets => "{0}에는 Unix 도메인 소켓이 필요합니다",
+        Msg::ErrOptionsConflict => "{0}와(과) {1}은(는) 함께 쓸 수 없습니다",
+        Msg::ErrOptionBeforeCommand => "{0}은(는) 명령 앞에 올 수 없습니다; `{1}`을(를) 사용하세요",
+    }
+}
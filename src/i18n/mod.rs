@@ -0,0 +1,370 @@
+// 메시지 카탈로그 모듈 (다국어 지원)
+// 사용자에게 보이는 문구는 모두 Msg로 찾아서 현재 언어의 카탈로그(en.rs, ko.rs)에서 가져옴
+// 언어는 프로그램 시작 시 한 번 정함: --lang > LC_ALL > LC_MESSAGES > LANG (없으면 영어)
+//
+// 사용 예:
+//   println!("{}", i18n::t(Msg::MonitoringComplete));
+//   println!("{}", i18n::tf(Msg::QuotaRemoved, &[&name]));  // {0}, {1}, ... 자리에 인자 삽입
+
+mod en; // 영어 카탈로그 (en.rs)
+mod ko; // 한국어 카탈로그 (ko.rs)
+
+use once_cell::sync::OnceCell;
+use std::fmt::Display;
+
+/// 지원하는 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    #[default]
+    En,
+    Ko,
+}
+
+impl Lang {
+    /// "ko", "ko_KR.UTF-8", "en_US" 같은 값에서 언어 결정 ("C", "POSIX" 등은 영어)
+    pub fn parse(value: &str) -> Option<Self> {
+        let code = value
+            .split(['_', '.', '@', '-'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match code.as_str() {
+            "ko" => Some(Lang::Ko),
+            "en" | "c" | "posix" => Some(Lang::En),
+            _ => None,
+        }
+    }
+
+    /// 로캘 환경 변수에서 언어 결정 (처음으로 값이 있는 변수만 봄, 모르는 언어는 영어)
+    pub fn from_env() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Lang::parse(&value))
+            .unwrap_or_default()
+    }
+}
+
+// 프로그램 시작 시 한 번 정한 언어
+static LANG: OnceCell<Lang> = OnceCell::new();
+
+/// 메시지 언어를 설정 (첫 호출만 적용됨)
+pub fn set_lang(lang: Lang) {
+    let _ = LANG.set(lang);
+}
+
+/// 현재 메시지 언어 (설정하지 않았으면 영어)
+pub fn lang() -> Lang {
+    LANG.get().copied().unwrap_or_default()
+}
+
+/// 지정한 언어의 메시지
+pub fn text(lang: Lang, msg: Msg) -> &'static str {
+    match lang {
+        Lang::En => en::text(msg),
+        Lang::Ko => ko::text(msg),
+    }
+}
+
+/// 현재 언어의 메시지
+pub fn t(msg: Msg) -> &'static str {
+    text(lang(), msg)
+}
+
+/// 현재 언어의 메시지에서 {0}, {1}, ... 자리를 인자로 채움
+pub fn tf(msg: Msg, args: &[&dyn Display]) -> String {
+    fill(t(msg), args)
+}
+
+// 자리표시자 치환 (어순이 다른 언어를 위해 번호로 지정)
+fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut text = template.to_string();
+    for (i, arg) in args.iter().enumerate() {
+        text = text.replace(&format!("{{{}}}", i), &arg.to_string());
+    }
+    text
+}
+
+// Msg 열거형과 전체 목록(ALL)을 함께 정의 (카탈로그 검사용)
+macro_rules! messages {
+    ($($name:ident,)*) => {
+        /// 사용자에게 보이는 메시지 키
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum Msg {
+            $($name,)*
+        }
+
+        impl Msg {
+            /// 모든 메시지 (카탈로그 검사용)
+            pub const ALL: &'static [Msg] = &[$(Msg::$name,)*];
+        }
+    };
+}
+
+messages! {
+    // 커맨드라인 도움말
+    CliAbout,
+    CliAfterHelp,
+    HelpHelp,
+    HelpVersion,
+    HelpInterface,
+    HelpInterval,
+    HelpHistory,
+    HelpUnits,
+    HelpPrefix,
+    HelpScale,
+    HelpNoPublicIp,
+    HelpInclude,
+    HelpExclude,
+    HelpHideVirtual,
+    HelpShowDown,
+    HelpShowLoopback,
+    HelpConfig,
+    HelpLang,
+    HelpAttach,
+    HelpCsv,
+    HelpRemote,
+    HelpRemotePsk,
+    HelpSnmp,
+    HelpSnmpCommunity,
+    HelpSnmpUser,
+    HelpSnmpAuth,
+    HelpSnmpAuthFile,
+    HelpSnmpPrivFile,
+    CmdTui,
+    CmdClassic,
+    CmdSimple,
+    CmdReport,
+    CmdQuery,
+    CmdExport,
+    CmdRecord,
+    CmdQuota,
+    CmdQuotaShow,
+    CmdQuotaSet,
+    CmdQuotaClear,
+    CmdImport,
+    HelpHourly,
+    HelpDaily,
+    HelpMonthly,
+    HelpSince,
+    HelpUntil,
+    HelpAgg,
+    HelpBucket,
+    HelpJson,
+    HelpFormat,
+    HelpOutput,
+    HelpExportCount,
+    HelpHeaderEvery,
+    HelpRecordCsv,
+    HelpRecordCount,
+    HelpQuotaName,
+    HelpQuotaLimit,
+    HelpResetDay,
+    HelpQuotaCount,
+    HelpImportFormat,
+    HelpImportSource,
+    CmdCheck,
+    HelpWarnRx,
+    HelpCritRx,
+    HelpWarnTx,
+    HelpCritTx,
+    HelpWarnErrors,
+    HelpCritErrors,
+    HelpWarnDrops,
+    HelpCritDrops,
+    HelpDuration,
+    CmdServe,
+    HelpListen,
+    CmdPush,
+    HelpPushTo,
+    HelpPushFormat,
+    HelpMetricPrefix,
+    HelpBatchSize,
+    HelpFlushInterval,
+    HelpPushCount,
+    CmdOtlp,
+    HelpOtlpEndpoint,
+    HelpOtlpHeader,
+    HelpExportInterval,
+    HelpOtlpCount,
+    CmdMqtt,
+    HelpBroker,
+    HelpTopicPrefix,
+    HelpClientId,
+    HelpMqttUsername,
+    HelpMqttPassword,
+    HelpDiscoveryPrefix,
+    HelpNoDiscovery,
+    HelpMqttCount,
+    CmdDaemon,
+    HelpSocket,
+    CmdAgent,
+    HelpAgentListen,
+    HelpAgentPsk,
+    CmdDashboard,
+    HelpDashboardHost,
+    HelpDashboardPsk,
+    CmdWeb,
+    HelpWebListen,
+    // 공통 화면 문구
+    TitleClassic,
+    TitleImproved,
+    TitleDashboard,
+    TitleSimple,
+    StatusUp,
+    StatusDown,
+    KindLoopback,
+    KindVirtual,
+    KindPhysical,
+    LabelIndex,
+    LabelName,
+    LabelMac,
+    LabelStatus,
+    LabelType,
+    LabelSpeed,
+    LabelIp,
+    LabelIpAddresses,
+    LabelPublicIp,
+    LabelTotal,
+    IpPrivate,
+    IpPublic,
+    IpWithPublic,
+    IpFetchingPublic,
+    IpNone,
+    IpNoneInstallTools,
+    PublicIpUnavailable,
+    Download,
+    Upload,
+    Incoming,
+    Outgoing,
+    Max,
+    Min,
+    TotalDownUp,
+    QuotaCapReached,
+    QuotaCapEta,
+    QuotaWithinCap,
+    QuotaResets,
+    QuotaGauge,
+    // TUI
+    BlockStatus,
+    BlockInterfaces,
+    BlockDetails,
+    HelpClassic,
+    HelpImproved,
+    HelpDashboard,
+    ColumnHost,
+    ColumnInterface,
+    DashboardHosts,
+    DashboardConnecting,
+    DashboardUnreachable,
+    FilterTitle,
+    FilterHint,
+    FilterDefault,
+    FilterPatternsOff,
+    FilterNoVirtual,
+    FilterDown,
+    FilterLoopback,
+    // 콘솔 출력
+    NoInterfacesFound,
+    NoInterfacesMatch,
+    NoActiveInterfaces,
+    DetectedInterfaces,
+    SimpleTesting,
+    SimpleUpdate,
+    SimpleTotal,
+    MonitoringComplete,
+    ApplicationError,
+    ErrorChain,
+    Warning,
+    QuotaNone,
+    QuotaLeftResets,
+    QuotaSaved,
+    QuotaRemoved,
+    BucketsImported,
+    QueryNoData,
+    QueryRecordedBy,
+    ServingMetrics,
+    ServingWeb,
+    PushingTo,
+    PushRetrying,
+    PushDropped,
+    ExportingOtlp,
+    OtlpExportFailed,
+    PublishingMqtt,
+    DaemonListening,
+    AgentListening,
+    AgentWithoutPsk,
+    // 오류
+    ErrListInterfaces,
+    ErrRawMode,
+    ErrRestoreTerminal,
+    ErrInitApp,
+    ErrUpdateStats,
+    ErrStatsFor,
+    ErrInterfaceNotFound,
+    ErrInterfaceHidden,
+    ErrIndexOutOfBounds,
+    ErrInvalidValue,
+    ErrInvalidLimit,
+    ErrNoQuota,
+    ErrReadStdin,
+    ErrReadFile,
+    ErrSinceAfterUntil,
+    ErrCsvNeedsOutput,
+    ErrOutputOnlyCsv,
+    ErrWriteBatch,
+    ErrListen,
+    ErrNoDaemon,
+    ErrAttachUnsupported,
+    ErrNoHosts,
+    ErrNoInterface,
+    ErrNoCounters,
+    ErrNeedsUnixSockets,
+    ErrOptionsConflict,
+    ErrOptionBeforeCommand,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 자리표시자 번호 목록 (예: "{1} of {0}" → [0, 1])
+    fn placeholders(text: &str) -> Vec<usize> {
+        (0..10)
+            .filter(|i| text.contains(&format!("{{{}}}", i)))
+            .collect()
+    }
+
+    #[test]
+    fn test_catalogs_are_complete_and_consistent() {
+        for &msg in Msg::ALL {
+            let en = text(Lang::En, msg);
+            let ko = text(Lang::Ko, msg);
+            assert!(!en.is_empty() && !ko.is_empty(), "{:?} is empty", msg);
+            assert_eq!(
+                placeholders(en),
+                placeholders(ko),
+                "{:?} placeholders differ",
+                msg
+            );
+            // 영어 카탈로그에 한글이 섞이지 않았는지
+            assert!(
+                !en.chars().any(|c| ('\u{AC00}'..='\u{D7A3}').contains(&c)),
+                "{:?} has Korean text in the English catalog",
+                msg
+            );
+        }
+    }
+
+    #[test]
+    fn test_lang_from_locale_and_fill() {
+        assert_eq!(Lang::parse("ko_KR.UTF-8"), Some(Lang::Ko));
+        assert_eq!(Lang::parse("en_US"), Some(Lang::En));
+        assert_eq!(Lang::parse("C"), Some(Lang::En));
+        assert_eq!(Lang::parse("fr_FR"), None);
+
+        let args: [&dyn Display; 2] = [&"eth0", &3];
+        assert_eq!(fill("{1} on {0}", &args), "3 on eth0");
+    }
+}
@@ -2,8 +2,14 @@
 // 다른 모듈들을 선언하고 외부에서 사용할 수 있도록 re-export
 
 // pub mod: 공개 모듈 선언 - 다른 파일에서 접근 가능
+pub mod config; // src/config.rs 설정 파일 (config.toml)
+pub mod daemon; // src/daemon/ 디렉토리의 모듈 (백그라운드 샘플링 데몬과 Unix 소켓 API)
 pub mod error;
+pub mod export; // src/export/ 디렉토리의 모듈 (CSV 등 내보내기)
+pub mod i18n; // src/i18n/ 디렉토리의 모듈 (메시지 카탈로그 en, ko)
 pub mod network; // src/network/ 디렉토리의 모듈
+pub mod snmp; // src/snmp/ 디렉토리의 모듈 (스위치/라우터의 IF-MIB를 읽는 SNMP 공급원)
+pub mod storage; // src/storage/ 디렉토리의 모듈 (디스크 저장 데이터)
 pub mod ui; // src/ui/ 디렉토리의 모듈
 pub mod utils; // src/utils/ 디렉토리의 모듈 // src/error.rs 에러 처리 모듈
 
@@ -11,4 +17,4 @@ pub mod utils; // src/utils/ 디렉토리의 모듈 // src/error.rs 에러 처
 // 예: nbmon::NetworkInterface 대신 use nbmon::NetworkInterface로 바로 사용 가능
 pub use network::interface::NetworkInterface; // 네트워크 인터페이스 구조체
 pub use network::stats::InterfaceStats; // 인터페이스 통계 구조체
-pub use ui::{App, ImprovedApp}; // TUI 애플리케이션 구조체들
+pub use ui::{App, Dashboard, ImprovedApp, MonitorOptions}; // TUI 애플리케이션 구조체들과 실행 옵션
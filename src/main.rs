@@ -1,6 +1,11 @@
+// 커맨드라인 정의 모듈 (cli.rs)
+// mod: 바이너리 전용 모듈 선언 - 라이브러리(lib.rs)에는 포함되지 않음
+mod cli;
+
 // Rust에서 외부 라이브러리(crate)를 import하는 방법
 // anyhow: 에러 처리를 간단하게 해주는 라이브러리
 use anyhow::{Context, Result};
+// clap: 구조체 정의로부터 커맨드라인 파서와 도움말을 생성하는 라이브러리
 // crossterm: 크로스플랫폼 터미널 조작 라이브러리
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
@@ -11,59 +16,179 @@ use crossterm::{
 use ratatui::{backend::CrosstermBackend, Terminal};
 // std::io: Rust 표준 라이브러리의 입출력 모듈
 use std::io;
+use std::time::Duration;
 
 // 우리가 만든 라이브러리에서 필요한 구조체들을 import
+use cli::{
+    AgentArgs, CheckArgs, Cli, Command, DaemonArgs, DashboardArgs, ExportArgs, ImportArgs,
+    MqttArgs, OtlpArgs, PushArgs, QueryArgs, QuotaAction, RecordArgs, ReportArgs, ServeArgs,
+    TuiArgs, WebArgs,
+};
 use nbmon::{
-    network::{interface, public_ip},
-    App, ImprovedApp,
+    config::{Config, HostConfig},
+    daemon,
+    export::csv::CsvWriter,
+    i18n::{self, t, tf, Lang, Msg},
+    network::{interface, public_ip, sampler::Sampler},
+    snmp,
+    utils::{format, net},
+    App, Dashboard, ImprovedApp, MonitorOptions, NetworkInterface,
 };
 
 // fn main() -> Result<()>: 메인 함수
 // Result<()>는 성공시 (), 실패시 에러를 반환하는 타입
 fn main() -> Result<()> {
-    // 커맨드라인 인자들을 Vec<String>으로 수집
-    // std::env::args(): 프로그램 실행시 전달된 인자들을 iterator로 반환
-    // .collect(): iterator를 Vec로 변환
-    let args: Vec<String> = std::env::args().collect();
-
-    // 인자가 하나 이상 있으면 처리
-    if args.len() > 1 {
-        // match 표현식: 패턴 매칭으로 값에 따라 다른 동작 수행
-        match args[1].as_str() {
-            "--simple" => return run_simple_mode(),  // 간단한 콘솔 모드
-            "--classic" => return run_classic_tui(), // 클래식 TUI 모드
-            "--help" | "-h" => {
-                show_help();
-                return Ok(());
-            }
-            "--version" | "-v" => {
-                show_version();
-                return Ok(());
-            }
-            _ => {
-                eprintln!("Unknown option: {}", args[1]);
-                eprintln!("Use --help for usage information.");
-                return Ok(());
-            }
+    // 메시지 언어 결정 (--lang > LC_ALL > LC_MESSAGES > LANG) - 도움말도 이 언어로 출력
+    // check의 출력은 모니터링 서버가 해석하므로 언어 설정과 관계없이 영어 (export/check.rs)
+    let lang = if cli::is_check(std::env::args_os()) {
+        Lang::En
+    } else {
+        cli::lang_from_args(std::env::args_os()).unwrap_or_else(Lang::from_env)
+    };
+    i18n::set_lang(lang);
+
+    // 커맨드라인 인자 해석
+    // 잘못된 옵션이나 값이 있으면 clap이 사용법을 출력하고 종료 코드 2로 종료
+    let cli = Cli::parse_args();
+
+    // check는 실행 전의 에러(설정 파일, --attach 등)도 플러그인 규칙에 따라 UNKNOWN(3)으로 보고
+    let is_check = matches!(cli.command, Some(Command::Check(_)));
+    match run(cli) {
+        Err(e) if is_check => {
+            let result = nbmon::export::check::CheckResult::unknown(&format!("{:#}", e));
+            println!("{}", result.output);
+            std::process::exit(result.status.code());
         }
+        result => result,
     }
+}
+
+// 설정 파일과 전역 옵션을 반영해서 명령 실행
+fn run(cli: Cli) -> Result<()> {
+    let global = &cli.global;
+
+    // 설정 파일 읽기 (--config로 지정한 파일은 반드시 있어야 하고, 기본 경로의 파일은 없어도 됨)
+    let config = match &global.config {
+        Some(path) => Config::load(path)?,
+        None => Config::load_default()?,
+    };
 
-    // 네트워크 인터페이스 목록 가져오기
+    // 속도 단위와 Public IP 조회 여부는 모든 화면에 공통으로 적용 (커맨드라인이 설정 파일보다 우선)
+    let units = match &global.units {
+        Some(units) => format::Units::parse(units)
+            .with_context(|| tf(Msg::ErrInvalidValue, &[&"--units", units]))?,
+        None => config.units.unwrap_or_default(),
+    };
+    let prefix = match &global.prefix {
+        Some(prefix) => Some(
+            format::Prefix::parse(prefix)
+                .with_context(|| tf(Msg::ErrInvalidValue, &[&"--prefix", prefix]))?,
+        ),
+        None => config.prefix,
+    };
+    let scale = global.scale.or(config.scale).unwrap_or_default();
+    format::set_units(format::UnitSystem::new(units, prefix, scale));
+    public_ip::set_enabled(config.public_ip.enabled && !global.no_public_ip);
+    public_ip::set_refresh_interval(Duration::from_secs(config.public_ip.refresh_secs));
+
+    let mut options = config.monitor_options();
+    if let Some(interval) = global.interval {
+        options.interval = interval;
+    }
+    if let Some(samples) = global.history {
+        options.history = Some(samples as usize);
+    }
+    if let Some(interface) = &global.interface {
+        options.interface = Some(interface.clone());
+    }
+    // 패턴 목록은 커맨드라인에서 주면 설정 파일의 목록을 대체, 종류 필터는 켜기만 가능
+    if !global.include.is_empty() {
+        options.filters.include = global.include.clone();
+    }
+    if !global.exclude.is_empty() {
+        options.filters.exclude = global.exclude.clone();
+    }
+    options.filters.hide_virtual |= global.hide_virtual;
+    options.filters.show_down |= global.show_down;
+    options.filters.show_loopback |= global.show_loopback;
+
+    // --attach: 직접 샘플링하는 명령만 데몬에 붙을 수 있음
+    if let Some(socket) = global.attach.clone() {
+        let supported = matches!(
+            cli.command,
+            None | Some(
+                Command::Tui(_)
+                    | Command::Classic(_)
+                    | Command::Export(_)
+                    | Command::Check(_)
+                    | Command::Serve(_)
+                    | Command::Push(_)
+                    | Command::Otlp(_)
+                    | Command::Mqtt(_)
+                    | Command::Web(_)
+            )
+        );
+        if !supported {
+            anyhow::bail!(t(Msg::ErrAttachUnsupported));
+        }
+        options.attach = Some(attach_socket(socket)?);
+    }
+
+    // match 표현식: 패턴 매칭으로 명령에 따라 다른 동작 수행
+    // 명령을 생략하면 향상된 TUI 실행
+    match cli.command {
+        None => run_tui(cli.tui, &options),
+        Some(Command::Tui(args)) => run_tui(args, &options),
+        Some(Command::Classic(args)) => run_classic_tui(args, &options),
+        Some(Command::Simple) => run_simple_mode(&options),
+        Some(Command::Report(args)) => run_report(args, options.interface.as_deref()),
+        Some(Command::Query(args)) => run_query(args, options.interface.as_deref()),
+        Some(Command::Export(args)) => run_export(args, &options),
+        Some(Command::Record(args)) => run_record(args, &options),
+        Some(Command::Quota(args)) => run_quota(args.action.unwrap_or(QuotaAction::Show)),
+        Some(Command::Import(args)) => run_import(args),
+        Some(Command::Check(args)) => run_check(args, &options),
+        Some(Command::Serve(args)) => run_serve(args, &options),
+        Some(Command::Push(args)) => run_push(args, &options),
+        Some(Command::Otlp(args)) => run_otlp(args, &options),
+        Some(Command::Mqtt(args)) => run_mqtt(args, &options),
+        Some(Command::Daemon(args)) => run_daemon(args, &options),
+        Some(Command::Agent(args)) => run_agent(args, &options),
+        Some(Command::Dashboard(args)) => {
+            run_dashboard(args, &config.hosts, global.config.as_deref(), &options)
+        }
+        Some(Command::Web(args)) => run_web(args, &options),
+    }
+}
+
+// 향상된 TUI 모드 (기본)
+fn run_tui(args: TuiArgs, options: &MonitorOptions) -> Result<()> {
+    // CSV 파일은 터미널을 TUI 모드로 바꾸기 전에 열어서 에러를 일반 출력으로 보여줌
+    let csv = args
+        .csv
+        .as_ref()
+        .map(|path| CsvWriter::open(path))
+        .transpose()?;
+
+    // 네트워크 인터페이스 목록 가져오기 (데몬이나 에이전트에 붙으면 그쪽이 샘플링하는 인터페이스들)
     // ? 연산자: Result가 Err이면 함수에서 바로 에러를 반환
-    let interfaces =
-        interface::list_interfaces().context("Failed to get network interfaces list")?;
+    let sampler = tui_sampler(&args, options)?;
+    let interfaces = match &sampler {
+        Some(sampler) => sampler.interfaces().to_vec(),
+        None => interface::list_interfaces().context(t(Msg::ErrListInterfaces))?,
+    };
 
     // Vec이 비어있는지 확인
     if interfaces.is_empty() {
         // eprintln!: stderr로 출력하는 매크로
-        eprintln!("No network interfaces found!");
+        eprintln!("{}", t(Msg::NoInterfacesFound));
         // Ok(()): 성공적인 결과 반환
         return Ok(());
     }
 
     // 터미널을 TUI 모드로 설정
     // raw mode: 터미널이 입력을 즉시 프로그램에 전달 (Enter 없이도)
-    enable_raw_mode().context("Failed to enable terminal raw mode")?;
+    enable_raw_mode().context(t(Msg::ErrRawMode))?;
 
     // stdout 핸들 가져오기
     // mut: 변경 가능한(mutable) 변수로 선언
@@ -79,12 +204,27 @@ fn main() -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // ImprovedApp 인스턴스 생성하고 실행
-    let mut app = ImprovedApp::new(interfaces).context("Failed to initialize the application")?;
+    let app = match sampler {
+        Some(sampler) => ImprovedApp::with_sampler(sampler, options),
+        None => ImprovedApp::with_options(interfaces, options),
+    };
+    let mut app = app.context(t(Msg::ErrInitApp))?;
+    // 원격 호스트와 SNMP 장비의 카운터는 이 컴퓨터의 집계에 섞지 않음
+    if let Some(host) = args.remote.as_ref().or(args.snmp.as_ref()) {
+        app = app.without_recorder().with_host(host);
+    }
+    // 데몬에 붙으면 데몬이 집계와 속도 기록을 남기므로 두 번 기록하지 않음
+    if options.attach.is_some() {
+        app = app.without_recorder();
+    }
+    if let Some(csv) = csv {
+        app = app.with_csv(csv);
+    }
     // app.run()의 결과를 res 변수에 저장
     let res = app.run(&mut terminal);
 
     // 터미널 상태 복원 (정리 작업)
-    disable_raw_mode().context("Failed to restore terminal state")?;
+    disable_raw_mode().context(t(Msg::ErrRestoreTerminal))?;
     execute!(
         terminal.backend_mut(), // backend에 대한 mutable 참조 가져오기
         LeaveAlternateScreen,   // 원래 화면으로 복귀
@@ -96,8 +236,8 @@ fn main() -> Result<()> {
     // if let: 패턴 매칭으로 특정 케이스만 처리
     if let Err(err) = res {
         // 에러 체인 전체를 출력하여 근본 원인 표시
-        eprintln!("Application error: {err:?}");
-        eprintln!("\nError chain:");
+        eprintln!("{}", tf(Msg::ApplicationError, &[&format!("{err:?}")]));
+        eprintln!("\n{}", t(Msg::ErrorChain));
         for cause in err.chain() {
             eprintln!("  - {}", cause);
         }
@@ -107,28 +247,27 @@ fn main() -> Result<()> {
 }
 
 // 간단한 콘솔 출력 모드 - TUI 없이 텍스트만 출력
-fn run_simple_mode() -> Result<()> {
+fn run_simple_mode(options: &MonitorOptions) -> Result<()> {
     // 이 함수에서만 사용할 모듈들을 지역적으로 import
     // use 문을 함수 내부에 쓰면 해당 함수에서만 사용 가능
     use nbmon::network::stats;
-    use nbmon::utils::format;
-    use std::{thread, time::Duration}; // 스레드와 시간 관련 기능
+    use std::thread; // 스레드 관련 기능
 
     // println!: 콘솔에 텍스트 출력하는 매크로
-    println!("NBMon - Cross-platform Network Bandwidth Monitor (Simple Mode)");
+    println!("{}", t(Msg::TitleSimple));
     // "=".repeat(50): 문자열을 50번 반복
     println!("{}", "=".repeat(50));
 
-    // 네트워크 인터페이스 목록 가져오기
-    let interfaces = interface::list_interfaces()?;
+    // 네트워크 인터페이스 목록 가져오기 (--interface가 있으면 그 인터페이스만)
+    let interfaces = select_interfaces(options)?;
 
     // 인터페이스가 없으면 종료
     if interfaces.is_empty() {
-        println!("No network interfaces found!");
+        println!("{}", t(Msg::NoInterfacesMatch));
         return Ok(());
     }
 
-    println!("\nDetected Network Interfaces:");
+    println!("\n{}", t(Msg::DetectedInterfaces));
     println!("{}", "-".repeat(50));
 
     // 모든 네트워크 인터페이스 정보 출력
@@ -137,50 +276,64 @@ fn run_simple_mode() -> Result<()> {
     for (idx, iface) in interfaces.iter().enumerate() {
         // idx는 0부터 시작하므로 +1해서 사용자에게 1부터 보여줌
         println!("\n[{}] {}", idx + 1, iface.display_name());
-        println!("    Index: {}", iface.index);
-        println!("    Name: {}", iface.name);
-        println!("    MAC: {}", iface.mac_address);
+        println!("    {}: {}", t(Msg::LabelIndex), iface.index);
+        println!("    {}: {}", t(Msg::LabelName), iface.name);
+        println!("    {}: {}", t(Msg::LabelMac), iface.mac_address);
 
         // if 표현식을 이용한 조건부 문자열 선택
-        println!("    Status: {}", if iface.is_up { "UP" } else { "DOWN" });
+        println!(
+            "    {}: {}",
+            t(Msg::LabelStatus),
+            t(if iface.is_up {
+                Msg::StatusUp
+            } else {
+                Msg::StatusDown
+            })
+        );
 
         // 중첩된 if 표현식으로 인터페이스 타입 판별
         println!(
-            "    Type: {}",
-            if iface.is_loopback {
-                "Loopback"
+            "    {}: {}",
+            t(Msg::LabelType),
+            t(if iface.is_loopback {
+                Msg::KindLoopback
             } else if iface.is_virtual() {
-                "Virtual"
+                Msg::KindVirtual
             } else {
-                "Physical"
-            }
+                Msg::KindPhysical
+            })
         );
 
         // 속도 정보가 있는 경우에만 출력
         if iface.speed > 0 {
-            println!("    Speed: {}", format::format_bits_per_sec(iface.speed));
+            println!(
+                "    {}: {}",
+                t(Msg::LabelSpeed),
+                format::format_bits_per_sec(iface.speed)
+            );
         }
 
         // IP 주소 목록이 비어있지 않은 경우 출력
         if !iface.ip_addresses.is_empty() {
-            println!("    IP Addresses:");
+            println!("    {}:", t(Msg::LabelIpAddresses));
             // &iface.ip_addresses: 벡터에 대한 참조 (소유권을 이동시키지 않음)
             for ip in &iface.ip_addresses {
                 let is_private = public_ip::is_private_ip(ip);
-                if is_private {
-                    println!("        - {} (Private)", ip);
+                let kind = if is_private {
+                    Msg::IpPrivate
                 } else {
-                    println!("        - {} (Public)", ip);
-                }
+                    Msg::IpPublic
+                };
+                println!("        - {} ({})", ip, t(kind));
             }
 
             // 사설 IP가 있는 경우 Public IP도 표시
-            if iface.ip_addresses.iter().any(public_ip::is_private_ip) {
-                print!("    Public IP: ");
+            if public_ip::is_enabled() && iface.ip_addresses.iter().any(public_ip::is_private_ip) {
+                print!("    {}: ", t(Msg::LabelPublicIp));
                 if let Some(public_ip_addr) = public_ip::get_public_ip() {
                     println!("{}", public_ip_addr);
                 } else {
-                    println!("Unable to fetch (check internet connection)");
+                    println!("{}", t(Msg::PublicIpUnavailable));
                 }
             }
         }
@@ -188,16 +341,20 @@ fn run_simple_mode() -> Result<()> {
 
     // Test statistics collection for active interfaces
     println!("\n{}", "=".repeat(50));
-    println!("Testing bandwidth monitoring (5 seconds)...");
+    println!(
+        "{}",
+        tf(
+            Msg::SimpleTesting,
+            &[&format!("{:.1}", options.interval.as_secs_f64())]
+        )
+    );
     println!("{}", "-".repeat(50));
 
-    let active_interfaces: Vec<_> = interfaces
-        .iter()
-        .filter(|i| i.is_up && !i.is_loopback)
-        .collect();
+    // 필터를 이미 통과한 목록이지만, -i로 고른 인터페이스가 DOWN이면 측정하지 않음
+    let active_interfaces: Vec<_> = interfaces.iter().filter(|i| i.is_up).collect();
 
     if active_interfaces.is_empty() {
-        println!("No active non-loopback interfaces found!");
+        println!("{}", t(Msg::NoActiveInterfaces));
         return Ok(());
     }
 
@@ -206,14 +363,14 @@ fn run_simple_mode() -> Result<()> {
     for iface in &active_interfaces {
         match stats::get_interface_stats(iface.index) {
             Ok(stat) => prev_stats.push(stat),
-            Err(e) => println!("Failed to get stats for {}: {}", iface.display_name(), e),
+            Err(e) => println!("{}", tf(Msg::ErrStatsFor, &[&iface.display_name(), &e])),
         }
     }
 
-    // Monitor for 5 seconds with 1-second intervals
+    // Monitor 5 updates at the configured interval
     for i in 1..=5 {
-        thread::sleep(Duration::from_secs(1));
-        println!("\n[Update {}]", i);
+        thread::sleep(options.interval);
+        println!("\n{}", tf(Msg::SimpleUpdate, &[&i]));
 
         for (idx, iface) in active_interfaces.iter().enumerate() {
             if let Ok(current_stats) = stats::get_interface_stats(iface.index) {
@@ -222,24 +379,32 @@ fn run_simple_mode() -> Result<()> {
                         println!(
                             "  {} ({}):",
                             iface.display_name(),
-                            if iface.is_virtual() {
-                                "Virtual"
+                            t(if iface.is_virtual() {
+                                Msg::KindVirtual
                             } else {
-                                "Physical"
-                            }
+                                Msg::KindPhysical
+                            })
                         );
                         println!(
-                            "    ↓ Download: {}",
+                            "    ↓ {}: {}",
+                            t(Msg::Download),
                             format::format_bytes_per_sec(bandwidth.download_rate)
                         );
                         println!(
-                            "    ↑ Upload:   {}",
+                            "    ↑ {}: {}",
+                            t(Msg::Upload),
                             format::format_bytes_per_sec(bandwidth.upload_rate)
                         );
                         println!(
-                            "    Total: {} down / {} up",
-                            format::format_bytes(bandwidth.total_downloaded),
-                            format::format_bytes(bandwidth.total_uploaded)
+                            "    {}: {}",
+                            t(Msg::LabelTotal),
+                            tf(
+                                Msg::SimpleTotal,
+                                &[
+                                    &format::format_bytes(bandwidth.total_downloaded),
+                                    &format::format_bytes(bandwidth.total_uploaded)
+                                ]
+                            )
                         );
                     }
                     prev_stats[idx] = current_stats;
@@ -249,17 +414,928 @@ fn run_simple_mode() -> Result<()> {
     }
 
     println!("\n{}", "=".repeat(50));
-    println!("Monitoring complete!");
+    println!("{}", t(Msg::MonitoringComplete));
+
+    Ok(())
+}
+
+// 트래픽 집계 보고서 모드 - 디스크에 저장된 시간/일/월 합계를 표로 출력
+fn run_report(args: ReportArgs, interface_name: Option<&str>) -> Result<()> {
+    use nbmon::storage::{accounting::Period, report};
+
+    let period = if args.hourly {
+        Period::Hourly
+    } else if args.monthly {
+        Period::Monthly
+    } else {
+        Period::Daily
+    };
+
+    let db = load_current_accounting()?;
+    print!("{}", report::render_report(&db, period, interface_name));
+    Ok(())
+}
+
+// 집계 데이터베이스를 읽고 현재 커널 카운터를 반영하여 저장
+// nbmon이 실행되지 않던 동안의 트래픽도 (재부팅 전까지는) 집계됨
+fn load_current_accounting() -> Result<nbmon::storage::accounting::AccountingDb> {
+    use nbmon::network::parallel_stats::collect_all_stats_parallel;
+    use nbmon::storage::accounting::AccountingDb;
+    use nbmon::utils::time;
+
+    let path = AccountingDb::default_path()?;
+    let Some((interfaces, stats)) = interface::list_interfaces().ok().and_then(|interfaces| {
+        let stats = collect_all_stats_parallel(&interfaces).ok()?;
+        Some((interfaces, stats))
+    }) else {
+        return AccountingDb::load(&path);
+    };
+
+    match AccountingDb::modify(&path, |db| {
+        db.record_stats(&interfaces, &stats, time::now_unix());
+    }) {
+        Ok(db) => Ok(db),
+        Err(e) => {
+            eprintln!("{}", tf(Msg::Warning, &[&format!("{:#}", e)]));
+            AccountingDb::load(&path)
+        }
+    }
+}
+
+// 데이터 사용량 한도 관리
+//   nbmon quota [show]                            현재 청구 주기 사용 현황
+//   nbmon quota set <이름> <한도> [--reset-day N] [--count rx|tx|both]
+//   nbmon quota clear <이름>
+fn run_quota(action: QuotaAction) -> Result<()> {
+    use nbmon::storage::quota::{self, Quota, QuotaCount, QuotaSet};
+    use nbmon::utils::time;
+
+    let path = QuotaSet::default_path()?;
+    // set/clear가 다른 nbmon의 변경을 덮어쓰지 않도록 읽기 전부터 잠금
+    let _lock = nbmon::storage::lock_exclusive(&path)?;
+    let mut quotas = QuotaSet::load(&path)?;
+
+    match action {
+        QuotaAction::Show => {
+            if quotas.quotas.is_empty() {
+                println!("{}", t(Msg::QuotaNone));
+                return Ok(());
+            }
+
+            let db = load_current_accounting()?;
+            let now = time::now_unix();
+            for (name, status) in quota::statuses(&quotas, &db, now) {
+                let projection = match status.projected_exhaustion {
+                    Some(_) if status.is_exceeded() => t(Msg::QuotaCapReached).to_string(),
+                    Some(eta) => tf(Msg::QuotaCapEta, &[&time::DateTime::local(eta).day_key()]),
+                    None => t(Msg::QuotaWithinCap).to_string(),
+                };
+                println!(
+                    " {:<12} {:>11} / {:<11} {:>4.0}%  {}  ({})",
+                    name,
+                    format::format_bytes(status.used),
+                    format::format_bytes(status.limit),
+                    status.ratio() * 100.0,
+                    tf(
+                        Msg::QuotaLeftResets,
+                        &[
+                            &format::format_bytes(status.remaining()),
+                            &time::DateTime::local(status.cycle_end).day_key()
+                        ]
+                    ),
+                    projection
+                );
+            }
+        }
+        QuotaAction::Set {
+            name,
+            limit,
+            reset_day,
+            count,
+        } => {
+            let limit =
+                format::parse_bytes(&limit).with_context(|| tf(Msg::ErrInvalidLimit, &[&limit]))?;
+            let quota = Quota {
+                limit,
+                reset_day,
+                count: QuotaCount::parse(&count)
+                    .with_context(|| tf(Msg::ErrInvalidValue, &[&"--count", &count]))?,
+            };
+
+            println!(
+                "{}",
+                tf(
+                    Msg::QuotaSaved,
+                    &[
+                        &name,
+                        &format::format_bytes(quota.limit),
+                        &quota.count.label(),
+                        &quota.reset_day
+                    ]
+                )
+            );
+            quotas.quotas.insert(name, quota);
+            quotas.save(&path)?;
+        }
+        QuotaAction::Clear { name } => {
+            if quotas.quotas.remove(&name).is_none() {
+                anyhow::bail!(tf(Msg::ErrNoQuota, &[&name]));
+            }
+            quotas.save(&path)?;
+            println!("{}", tf(Msg::QuotaRemoved, &[&name]));
+        }
+    }
+    Ok(())
+}
+
+// 다른 도구의 기록을 집계 데이터베이스로 가져오기
+//   nbmon import vnstat <파일|->   (vnstat --json 출력)
+//   nbmon import sar <파일|->      (sar -n DEV 출력)
+fn run_import(args: ImportArgs) -> Result<()> {
+    use nbmon::storage::{accounting::AccountingDb, import};
+    use std::io::Read;
+
+    let ImportArgs { format, source } = args;
+
+    // "-"이면 표준 입력에서 읽음 (예: vnstat --json | nbmon import vnstat -)
+    let text = if source == "-" {
+        let mut text = String::new();
+        io::stdin()
+            .read_to_string(&mut text)
+            .context(t(Msg::ErrReadStdin))?;
+        text
+    } else {
+        std::fs::read_to_string(&source).with_context(|| tf(Msg::ErrReadFile, &[&source]))?
+    };
+
+    let accounts = match format.as_str() {
+        "vnstat" => import::parse_vnstat_json(&text)?,
+        "sar" => import::parse_sar_dev(&text)?,
+        other => anyhow::bail!(tf(Msg::ErrInvalidValue, &[&"format", &other])),
+    };
+
+    let path = AccountingDb::default_path()?;
+    AccountingDb::modify(&path, |db| {
+        for (name, account) in &accounts {
+            let changed = db.import(name, account);
+            println!("{}", tf(Msg::BucketsImported, &[name, &changed]));
+        }
+    })?;
+    Ok(())
+}
+
+// 저장된 분 단위 속도 기록 조회
+//   nbmon query [-i <이름>] [--since 1h] [--until now] [--agg p95|avg|max|min|sum] [--bucket 5m] [--json]
+fn run_query(args: QueryArgs, interface: Option<&str>) -> Result<()> {
+    use nbmon::storage::{
+        query::{Aggregation, Query},
+        rates::RateLog,
+    };
+    use nbmon::utils::time;
+
+    let now = time::now_unix();
+    let query = Query {
+        interface: interface.map(str::to_string),
+        since: time::parse_time_spec(&args.since, now)
+            .with_context(|| tf(Msg::ErrInvalidValue, &[&"--since", &args.since]))?,
+        until: time::parse_time_spec(&args.until, now)
+            .with_context(|| tf(Msg::ErrInvalidValue, &[&"--until", &args.until]))?,
+        aggregation: Aggregation::parse(&args.agg)
+            .with_context(|| tf(Msg::ErrInvalidValue, &[&"--agg", &args.agg]))?,
+        bucket: args.bucket,
+    };
+
+    if query.since >= query.until {
+        anyhow::bail!(t(Msg::ErrSinceAfterUntil));
+    }
+
+    let log = RateLog::new(RateLog::default_dir()?);
+    let records = log.read(query.since, query.until, query.interface.as_deref())?;
+    let rows = query.run(&records);
+
+    if args.json {
+        println!("{}", query.render_json(&rows)?);
+    } else {
+        print!("{}", query.render_table(&rows));
+    }
+    Ok(())
+}
+
+// 헤드리스 기록 모드 - TUI 없이 주기적으로 샘플링하여 집계/속도 기록과 CSV를 갱신
+//   nbmon record [--csv <파일>] [--interval <초>] [--count <횟수>] [-i <이름>]
+fn run_record(args: RecordArgs, options: &MonitorOptions) -> Result<()> {
+    use nbmon::storage::accounting::Recorder;
+    use std::thread;
+
+    let mut csv = args.csv.map(|path| CsvWriter::open(&path)).transpose()?;
+
+    // 헤드리스 모드는 그래프가 없으므로 최소한의 히스토리만 유지
+    let mut sampler = Sampler::new(select_interfaces(options)?, 1, options.interval);
+    let mut recorder = Recorder::new().ok();
+
+    let mut ticks = 0;
+    while args.count.is_none_or(|count| ticks < count) {
+        thread::sleep(options.interval);
+        sampler.update()?;
+
+        if let Some(csv) = &mut csv {
+            csv.write_tick(&sampler)?;
+        }
+        if let Some(recorder) = &mut recorder {
+            recorder.maybe_flush(&sampler);
+        }
+        ticks += 1;
+    }
+
+    if let Some(recorder) = &mut recorder {
+        recorder.flush(&sampler);
+    }
+    Ok(())
+}
+
+// 통계 내보내기 - 매 틱마다 선택한 형식으로 출력
+//   nbmon export [--format json|batch|csv] [-o <파일>] [--count <횟수>] [--header-every N]
+fn run_export(args: ExportArgs, options: &MonitorOptions) -> Result<()> {
+    let sampler = open_sampler(options)?;
+
+    match (args.format.as_str(), &args.output) {
+        ("json", None) => run_json_mode(sampler, options.interval, args.count),
+        ("batch", None) => run_batch_mode(sampler, options.interval, args.count, args.header_every),
+        ("csv", Some(path)) => run_csv_mode(sampler, options.interval, args.count, path),
+        ("csv", None) => anyhow::bail!(t(Msg::ErrCsvNeedsOutput)),
+        (format, _) => anyhow::bail!(tf(Msg::ErrOutputOnlyCsv, &[&format])),
+    }
+}
+
+// JSON Lines 스트리밍 모드 - 매 틱마다 모든 인터페이스의 스냅샷을 한 줄로 출력
+fn run_json_mode(mut sampler: Sampler, interval: Duration, count: Option<u64>) -> Result<()> {
+    use nbmon::export::json::Snapshot;
+    use std::thread;
+
+    let mut stdout = io::stdout().lock();
+
+    let mut ticks = 0;
+    while count.is_none_or(|count| ticks < count) {
+        thread::sleep(interval);
+        sampler.update()?;
+
+        // `| head` 처럼 읽는 쪽이 먼저 끝나면 정상 종료
+        match Snapshot::from_sampler(&sampler).write_line(&mut stdout) {
+            Err(e) if is_broken_pipe(&e) => return Ok(()),
+            result => result?,
+        }
+        ticks += 1;
+    }
+    Ok(())
+}
+
+// CSV 내보내기 - 집계 기록 없이 CSV 파일에만 매 틱 추가 (집계도 필요하면 record --csv)
+fn run_csv_mode(
+    mut sampler: Sampler,
+    interval: Duration,
+    count: Option<u64>,
+    path: &std::path::Path,
+) -> Result<()> {
+    use std::thread;
+
+    let mut csv = CsvWriter::open(path)?;
+
+    let mut ticks = 0;
+    while count.is_none_or(|count| ticks < count) {
+        thread::sleep(interval);
+        sampler.update()?;
+        csv.write_tick(&sampler)?;
+        ticks += 1;
+    }
+    Ok(())
+}
+
+// 배치 모드 - 매 틱마다 인터페이스별 고정 폭 한 줄 출력 (sar -n DEV 스타일)
+fn run_batch_mode(
+    mut sampler: Sampler,
+    interval: Duration,
+    count: Option<u64>,
+    header_every: usize,
+) -> Result<()> {
+    use io::{IsTerminal, Write};
+    use nbmon::export::batch::{BatchRow, BatchTable};
+    use nbmon::utils::time::{self, DateTime};
+    use std::thread;
+
+    // 파이프나 파일로 출력할 때는 색상 코드를 넣지 않음
+    let color = io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+    let mut table = BatchTable::new(header_every, color, format::units());
+    let mut stdout = io::stdout().lock();
+
+    let mut ticks = 0;
+    while count.is_none_or(|count| ticks < count) {
+        thread::sleep(interval);
+        let previous = sampler.all_stats().to_vec();
+        sampler.update()?;
+
+        let time = DateTime::local(time::now_unix()).time_string();
+        let rows: Vec<BatchRow> = sampler
+            .interfaces()
+            .iter()
+            .zip(previous.iter().zip(sampler.all_stats()))
+            .filter_map(|(interface, (previous, current))| {
+                BatchRow::between(&time, interface, previous, current)
+            })
+            .collect();
+
+        // `| head` 처럼 읽는 쪽이 먼저 끝나면 정상 종료
+        match stdout
+            .write_all(table.render(&rows).as_bytes())
+            .and_then(|_| stdout.flush())
+        {
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => return Ok(()),
+            result => result.context(t(Msg::ErrWriteBatch))?,
+        }
+        ticks += 1;
+    }
+    Ok(())
+}
+
+// Nagios/Icinga 점검 - 한 줄 상태와 perfdata를 출력하고 상태에 맞는 종료 코드로 끝냄
+//   nbmon check -i eth0 [--warn-rx 800Mbit] [--crit-rx 950Mbit] [--warn-errors 10/s] [--duration 10s]
+// 측정하지 못하면(인터페이스 없음 등) UNKNOWN
+fn run_check(args: CheckArgs, options: &MonitorOptions) -> Result<()> {
+    use nbmon::export::check::{CheckResult, Threshold, Thresholds};
+
+    let thresholds = Thresholds {
+        rx: Threshold {
+            warn: args.warn_rx,
+            crit: args.crit_rx,
+        },
+        tx: Threshold {
+            warn: args.warn_tx,
+            crit: args.crit_tx,
+        },
+        errors: Threshold {
+            warn: args.warn_errors,
+            crit: args.crit_errors,
+        },
+        drops: Threshold {
+            warn: args.warn_drops,
+            crit: args.crit_drops,
+        },
+    };
+
+    let result = match measure_interface(options, args.duration) {
+        Ok(measurement) => CheckResult::evaluate(&measurement, &thresholds, format::units()),
+        Err(e) => CheckResult::unknown(&format!("{:#}", e)),
+    };
+    println!("{}", result.output);
+    std::process::exit(result.status.code());
+}
+
+// -i로 지정한 인터페이스를 duration 동안 측정한 평균
+fn measure_interface(
+    options: &MonitorOptions,
+    duration: Duration,
+) -> Result<nbmon::export::check::Measurement> {
+    use nbmon::export::check::Measurement;
+    if options.interface.is_none() {
+        anyhow::bail!(t(Msg::ErrNoInterface));
+    }
+    let mut sampler = open_sampler(options)?;
+    let previous = sampler.all_stats().to_vec();
+    std::thread::sleep(duration);
+    sampler.update()?;
+
+    Measurement::between(
+        &sampler.interfaces()[0],
+        &previous[0],
+        &sampler.all_stats()[0],
+    )
+    .context(t(Msg::ErrNoCounters))
+}
+
+// Prometheus 메트릭 서버 - 샘플링 스레드가 interval마다 갱신하고, 요청마다 최신 값을 출력
+//   nbmon serve [--listen 127.0.0.1:9959]
+fn run_serve(args: ServeArgs, options: &MonitorOptions) -> Result<()> {
+    use nbmon::export::http::{self, Response};
+    use nbmon::export::prometheus;
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex, PoisonError};
+    use std::thread;
+
+    let listener =
+        TcpListener::bind(args.listen).with_context(|| tf(Msg::ErrListen, &[&args.listen]))?;
+    let sampler = Arc::new(Mutex::new(open_sampler(options)?));
+
+    let interval = options.interval;
+    let background = Arc::clone(&sampler);
+    thread::spawn(move || loop {
+        thread::sleep(interval);
+        let result = background
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .update();
+        if let Err(e) = result {
+            eprintln!("{}", tf(Msg::Warning, &[&e]));
+        }
+    });
+
+    println!("{}", tf(Msg::ServingMetrics, &[&listener.local_addr()?]));
+    http::serve(listener, move |request| match request.path.as_str() {
+        "/metrics" => {
+            let sampler = sampler.lock().unwrap_or_else(PoisonError::into_inner);
+            Response::ok(prometheus::CONTENT_TYPE, prometheus::render(&sampler))
+        }
+        "/" => Response::ok(
+            "text/html; charset=utf-8",
+            "<html><body><h1>nbmon</h1><p><a href=\"/metrics\">Metrics</a></p></body></html>\n",
+        ),
+        _ => Response::not_found(),
+    })
+}
+
+// 웹 대시보드 - 페이지 하나와 /events 이벤트 스트림 제공
+//   nbmon web [--listen 127.0.0.1:8080]
+// 그래프가 처음부터 채워져 보이도록 향상된 TUI만큼 히스토리를 보관 (--attach면 데몬의 히스토리)
+fn run_web(args: WebArgs, options: &MonitorOptions) -> Result<()> {
+    use nbmon::export::http::{self, Reply, Response};
+    use nbmon::export::web::{self, Dashboard};
+    use nbmon::storage::accounting::Recorder;
+    use std::net::TcpListener;
+    use std::sync::Arc;
+    use std::thread;
+
+    const WEB_HISTORY: usize = 600;
+
+    let listener =
+        TcpListener::bind(args.listen).with_context(|| tf(Msg::ErrListen, &[&args.listen]))?;
+    // 데몬에 붙으면 데몬이 집계를 기록하므로 여기서는 읽기만 함
+    let dashboard = Arc::new(match &options.attach {
+        Some(_) => Dashboard::new(open_sampler(options)?, None, options.interval),
+        None => Dashboard::new(
+            Sampler::new(
+                select_interfaces(options)?,
+                options.history.unwrap_or(WEB_HISTORY),
+                options.interval,
+            ),
+            Recorder::new().ok(),
+            options.interval,
+        ),
+    });
+
+    let interval = options.interval;
+    let background = Arc::clone(&dashboard);
+    thread::spawn(move || loop {
+        thread::sleep(interval);
+        if let Err(e) = background.update() {
+            eprintln!("{}", tf(Msg::Warning, &[&e]));
+        }
+    });
+
+    println!("{}", tf(Msg::ServingWeb, &[&listener.local_addr()?]));
+    http::serve(listener, move |request| match request.path.as_str() {
+        "/" => Response::ok("text/html; charset=utf-8", web::PAGE).into(),
+        "/events" => {
+            let dashboard = Arc::clone(&dashboard);
+            Reply::Events(Box::new(move |out| dashboard.stream(out)))
+        }
+        _ => Response::not_found().into(),
+    })
+}
+
+// 푸시 출력 - 매 틱의 줄을 전송 스레드에 넘기고, 전송 스레드가 묶음 전송/재연결을 담당
+//   nbmon push --to tcp://host:port [--format influx|graphite] [--batch-size N] [--flush-interval 10s]
+fn run_push(args: PushArgs, options: &MonitorOptions) -> Result<()> {
+    use nbmon::export::push::{BatchOptions, LineFormatter, PushFormat, Pusher};
+    use nbmon::utils::host;
+    use std::thread;
+    use std::time::SystemTime;
+
+    let format = PushFormat::parse(&args.format)
+        .with_context(|| tf(Msg::ErrInvalidValue, &[&"--format", &args.format]))?;
+    let mut formatter = LineFormatter::new(format, &args.metric_prefix, &host::hostname());
+    let mut sampler = open_sampler(options)?;
+
+    let endpoint = args.to.with_default_protocol(format.default_protocol());
+    println!("{}", tf(Msg::PushingTo, &[&args.format, &endpoint]));
+    let pusher = Pusher::start(
+        endpoint,
+        BatchOptions {
+            batch_size: args.batch_size as usize,
+            flush_interval: args.flush_interval,
+            ..Default::default()
+        },
+    );
+
+    let mut ticks = 0;
+    while args.count.is_none_or(|count| ticks < count) {
+        thread::sleep(options.interval);
+        sampler.update()?;
+        pusher.push(formatter.lines(&sampler, SystemTime::now()));
+        ticks += 1;
+    }
+
+    let dropped = pusher.finish();
+    if dropped > 0 {
+        eprintln!(
+            "{}",
+            tf(Msg::Warning, &[&tf(Msg::PushDropped, &[&dropped])])
+        );
+    }
+    Ok(())
+}
+
+// OTLP 내보내기 모드 - --export-interval마다 누적 카운터와 구간 평균 속도를 컬렉터로 POST
+fn run_otlp(args: OtlpArgs, options: &MonitorOptions) -> Result<()> {
+    use nbmon::export::otlp::{self, Encoder, Exporter};
+    use nbmon::utils::host;
+    use std::thread;
+    use std::time::SystemTime;
+
+    let url = otlp::metrics_url(&args.endpoint);
+    let mut encoder = Encoder::new(&host::hostname());
+    let mut sampler = open_sampler(options)?;
+    let exporter = Exporter::start(url.clone(), args.header)?;
+
+    println!(
+        "{}",
+        tf(Msg::ExportingOtlp, &[&url, &args.export_interval.as_secs()])
+    );
+
+    let mut exports = 0;
+    while args.count.is_none_or(|count| exports < count) {
+        thread::sleep(args.export_interval);
+        sampler.update()?;
+        // 이전 요청이 아직 끝나지 않았으면 이번 전송은 건너뜀 (다음 누적 값이 대신함)
+        exporter.export(&encoder.request(&sampler, SystemTime::now()));
+        exports += 1;
+    }
 
+    exporter.finish();
     Ok(())
 }
 
+// MQTT 발행 모드 - 매 틱 인터페이스별 속도와 누적 값을 발행
+// 연결이 끊기면 경고를 출력하고 백오프 후 다시 연결 (연결할 때마다 발견 메시지와 online을 다시 보냄)
+fn run_mqtt(args: MqttArgs, options: &MonitorOptions) -> Result<()> {
+    use nbmon::export::mqtt::{self, Client, ConnectOptions, Topics};
+    use nbmon::export::push::Backoff;
+    use nbmon::utils::host;
+    use std::thread;
+    use std::time::Instant;
+
+    let host = host::hostname();
+    let discovery = (!args.no_discovery).then_some(args.discovery_prefix.as_str());
+    let topics = Topics::new(&args.topic_prefix, &host, discovery);
+    let address = mqtt::broker_address(&args.broker);
+    let connect = ConnectOptions {
+        client_id: args.client_id.unwrap_or_else(|| format!("nbmon-{}", host)),
+        username: args.username,
+        password: args
+            .password_file
+            .as_deref()
+            .map(net::read_secret)
+            .transpose()?,
+        // PINGREQ 없이 매 틱 발행만으로 연결을 유지하므로 틱 간격보다 넉넉하게
+        keep_alive: Duration::from_secs(60).max(options.interval * 3),
+        will: Some(topics.availability(false)),
+    };
+    let mut sampler = open_sampler(options)?;
+
+    println!("{}", tf(Msg::PublishingMqtt, &[&address, &topics.status()]));
+
+    let warn = |e: &anyhow::Error, delay: Duration| {
+        eprintln!(
+            "{}",
+            tf(
+                Msg::Warning,
+                &[&tf(
+                    Msg::PushRetrying,
+                    &[&format!("{:#}", e), &delay.as_secs()]
+                )]
+            )
+        );
+    };
+    let mut client: Option<Client> = None;
+    let mut backoff = Backoff::default();
+    let mut retry_at = Instant::now();
+
+    let mut ticks = 0;
+    while args.count.is_none_or(|count| ticks < count) {
+        thread::sleep(options.interval);
+        sampler.update()?;
+        ticks += 1;
+
+        if client.is_none() && Instant::now() >= retry_at {
+            let result = Client::connect(&address, &connect).and_then(|mut c| {
+                for message in topics.announcements(sampler.interfaces()) {
+                    c.publish(&message)?;
+                }
+                Ok(c)
+            });
+            match result {
+                Ok(c) => {
+                    client = Some(c);
+                    backoff.reset();
+                }
+                Err(e) => {
+                    let delay = backoff.next_delay();
+                    warn(&e, delay);
+                    retry_at = Instant::now() + delay;
+                }
+            }
+        }
+
+        if let Some(c) = client.as_mut() {
+            if let Err(e) = topics
+                .states(&sampler)
+                .iter()
+                .try_for_each(|m| c.publish(m))
+            {
+                let delay = backoff.next_delay();
+                warn(&e, delay);
+                retry_at = Instant::now() + delay;
+                client = None;
+            }
+        }
+    }
+
+    // 정상 종료 시에는 직접 offline을 남김 (비정상 종료는 브로커가 유언 메시지로 처리)
+    if let Some(mut c) = client {
+        c.publish(&topics.availability(false))?;
+        c.disconnect()?;
+    }
+    Ok(())
+}
+
+// 에러 체인에 BrokenPipe가 있는지 확인
+fn is_broken_pipe(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        cause
+            .downcast_ref::<io::Error>()
+            .is_some_and(|e| e.kind() == io::ErrorKind::BrokenPipe)
+    })
+}
+
+// 샘플링할 인터페이스 목록
+// -i로 이름을 주면 필터와 관계없이 그 인터페이스만, 아니면 필터를 통과한 인터페이스들
+fn select_interfaces(options: &MonitorOptions) -> Result<Vec<NetworkInterface>> {
+    let interfaces = interface::list_interfaces().context(t(Msg::ErrListInterfaces))?;
+    select_from(interfaces, options)
+}
+
+// 주어진 목록(시스템 또는 데몬의 인터페이스)에서 -i와 필터로 고름
+fn select_from(
+    interfaces: Vec<NetworkInterface>,
+    options: &MonitorOptions,
+) -> Result<Vec<NetworkInterface>> {
+    let Some(name) = options.interface.as_deref() else {
+        return Ok(interfaces
+            .into_iter()
+            .filter(|interface| options.filters.matches(interface))
+            .collect());
+    };
+
+    let selected: Vec<NetworkInterface> = interfaces
+        .into_iter()
+        .filter(|interface| interface.name == name)
+        .collect();
+    if selected.is_empty() {
+        anyhow::bail!(tf(Msg::ErrInterfaceNotFound, &[&name]));
+    }
+    Ok(selected)
+}
+
+// 헤드리스 명령의 Sampler - --attach면 데몬에 붙고, 아니면 직접 샘플링 (그래프가 없으므로 히스토리 최소)
+fn open_sampler(options: &MonitorOptions) -> Result<Sampler> {
+    match &options.attach {
+        Some(path) => attach_sampler(path, |interfaces| select_from(interfaces, options)),
+        None => Ok(Sampler::new(
+            select_interfaces(options)?,
+            1,
+            options.interval,
+        )),
+    }
+}
+
+// 데몬에 붙어서 히스토리가 채워진 Sampler를 받음
+#[cfg(unix)]
+fn attach_sampler(
+    path: &std::path::Path,
+    select: impl FnOnce(Vec<NetworkInterface>) -> Result<Vec<NetworkInterface>>,
+) -> Result<Sampler> {
+    let client = daemon::client::Client::connect_unix(path)?;
+    daemon::client::attach(client, select)
+}
+
+#[cfg(not(unix))]
+fn attach_sampler(
+    _path: &std::path::Path,
+    _select: impl FnOnce(Vec<NetworkInterface>) -> Result<Vec<NetworkInterface>>,
+) -> Result<Sampler> {
+    anyhow::bail!(tf(Msg::ErrNeedsUnixSockets, &[&"--attach"]))
+}
+
+// --attach의 소켓 경로 (생략하면 기본 경로 중 소켓 파일이 있는 곳)
+fn attach_socket(path: Option<std::path::PathBuf>) -> Result<std::path::PathBuf> {
+    if let Some(path) = path {
+        return Ok(path);
+    }
+    let candidates = daemon::attach_candidates();
+    candidates
+        .iter()
+        .find(|path| path.exists())
+        .cloned()
+        .ok_or_else(|| {
+            let tried: Vec<String> = candidates.iter().map(|p| p.display().to_string()).collect();
+            anyhow::anyhow!(tf(Msg::ErrNoDaemon, &[&tried.join(", ")]))
+        })
+}
+
+// 데몬/에이전트의 공유 상태 - 클라이언트가 어떤 인터페이스든 고를 수 있도록 필터 없이 전체를 샘플링
+// 히스토리는 향상된 TUI와 같은 크기 (10분간 1초 해상도, 이후는 롤업), 집계도 함께 기록
+fn sampling_state(
+    options: &MonitorOptions,
+) -> Result<std::sync::Arc<std::sync::Mutex<nbmon::daemon::server::State>>> {
+    use nbmon::daemon::server::State;
+    use nbmon::storage::accounting::Recorder;
+    use std::sync::{Arc, Mutex};
+
+    const DAEMON_HISTORY: usize = 600;
+
+    let interfaces = interface::list_interfaces().context(t(Msg::ErrListInterfaces))?;
+    let sampler = Sampler::new(
+        interfaces,
+        options.history.unwrap_or(DAEMON_HISTORY),
+        options.interval,
+    );
+    Ok(Arc::new(Mutex::new(State::new(
+        sampler,
+        Recorder::new().ok(),
+    ))))
+}
+
+// interval마다 공유 상태를 한 틱씩 갱신 (중단할 때까지)
+fn run_sampling_loop(
+    state: &std::sync::Mutex<nbmon::daemon::server::State>,
+    interval: Duration,
+) -> Result<()> {
+    use std::sync::PoisonError;
+
+    loop {
+        std::thread::sleep(interval);
+        let result = state.lock().unwrap_or_else(PoisonError::into_inner).tick();
+        if let Err(e) = result {
+            eprintln!("{}", tf(Msg::Warning, &[&e]));
+        }
+    }
+}
+
+// 백그라운드 데몬 - 모든 인터페이스를 interval마다 샘플링하고 Unix 소켓으로 상태 제공
+//   nbmon daemon [--socket <경로>] [--interval <초>] [--history <샘플 수>]
+#[cfg(unix)]
+fn run_daemon(args: DaemonArgs, options: &MonitorOptions) -> Result<()> {
+    use std::sync::{Arc, PoisonError};
+    use std::thread;
+
+    let path = args.socket.unwrap_or_else(daemon::default_socket_path);
+    let listener = daemon::bind(&path)?;
+    let state = sampling_state(options)?;
+    let count = state
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .interface_count();
+
+    println!(
+        "{}",
+        tf(
+            Msg::DaemonListening,
+            &[&count, &options.interval.as_secs_f64(), &path.display()]
+        )
+    );
+    let shared = Arc::clone(&state);
+    thread::spawn(move || daemon::serve(listener, shared));
+    run_sampling_loop(&state, options.interval)
+}
+
+#[cfg(not(unix))]
+fn run_daemon(_args: DaemonArgs, _options: &MonitorOptions) -> Result<()> {
+    anyhow::bail!(tf(Msg::ErrNeedsUnixSockets, &[&"nbmon daemon"]))
+}
+
+// 원격 에이전트 - 데몬과 같은 상태를 TCP로 제공 (--psk-file이 있으면 연결마다 인증)
+//   nbmon agent [--listen 0.0.0.0:9960] [--psk-file <파일>]
+fn run_agent(args: AgentArgs, options: &MonitorOptions) -> Result<()> {
+    use nbmon::daemon::agent;
+    use std::net::TcpListener;
+    use std::sync::{Arc, PoisonError};
+    use std::thread;
+
+    let psk: Option<Arc<[u8]>> = args
+        .psk_file
+        .as_deref()
+        .map(net::read_secret)
+        .transpose()?
+        .map(|psk| Arc::from(psk.into_bytes()));
+    let listener =
+        TcpListener::bind(args.listen).with_context(|| tf(Msg::ErrListen, &[&args.listen]))?;
+    let address = listener.local_addr()?;
+    let state = sampling_state(options)?;
+    let count = state
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .interface_count();
+
+    println!(
+        "{}",
+        tf(
+            Msg::AgentListening,
+            &[&count, &options.interval.as_secs_f64(), &address]
+        )
+    );
+    if psk.is_none() && !address.ip().is_loopback() {
+        eprintln!(
+            "{}",
+            tf(Msg::Warning, &[&tf(Msg::AgentWithoutPsk, &[&address])])
+        );
+    }
+    let shared = Arc::clone(&state);
+    thread::spawn(move || agent::serve(listener, shared, psk));
+    run_sampling_loop(&state, options.interval)
+}
+
+// TUI가 직접 샘플링하는 대신 사용할 Sampler
+// (--remote면 에이전트, --snmp면 SNMP 에이전트, --attach면 데몬, 아니면 None)
+fn tui_sampler(args: &TuiArgs, options: &MonitorOptions) -> Result<Option<Sampler>> {
+    use nbmon::daemon::{agent, client::Client};
+
+    // SNMP 장비는 히스토리를 받아올 곳이 없으므로 향상된 TUI와 같은 크기로 새로 시작
+    const SNMP_HISTORY: usize = 600;
+
+    if let Some(remote) = &args.remote {
+        let psk = args.psk_file.as_deref().map(net::read_secret).transpose()?;
+        let address = net::with_default_port(remote, agent::DEFAULT_PORT);
+        let client = Client::connect_tcp(&address, psk.as_ref().map(String::as_bytes))?;
+        // 이 컴퓨터의 Public IP는 원격 호스트와 관계없으므로 표시하지 않음
+        public_ip::set_enabled(false);
+        return daemon::client::attach(client, Ok).map(Some);
+    }
+    if let Some(agent) = &args.snmp {
+        let target = snmp_target(agent, args)?;
+        public_ip::set_enabled(false);
+        return snmp::connect(
+            &target,
+            options.history.unwrap_or(SNMP_HISTORY),
+            options.interval,
+        )
+        .map(Some);
+    }
+    match &options.attach {
+        Some(path) => attach_sampler(path, Ok).map(Some),
+        None => Ok(None),
+    }
+}
+
+// --snmp 옵션들로 폴링할 에이전트와 자격 증명 (--snmp-user가 있으면 v3, 없으면 v2c)
+fn snmp_target(agent: &str, args: &TuiArgs) -> Result<snmp::Target> {
+    use nbmon::snmp::usm::{AuthProtocol, User};
+
+    let security = match &args.snmp_user {
+        Some(name) => {
+            let protocol = AuthProtocol::parse(&args.snmp_auth).unwrap_or(AuthProtocol::Sha1);
+            let auth = args.snmp_auth_file.as_deref().map(net::read_secret);
+            let privacy = args.snmp_priv_file.as_deref().map(net::read_secret);
+            snmp::Security::User(User {
+                name: name.clone(),
+                auth: auth.transpose()?.map(|passphrase| (protocol, passphrase)),
+                privacy: privacy.transpose()?,
+            })
+        }
+        None => snmp::Security::Community(
+            args.community
+                .clone()
+                .unwrap_or_else(|| "public".to_string()),
+        ),
+    };
+    Ok(snmp::Target {
+        address: net::with_default_port(agent, snmp::DEFAULT_PORT),
+        security,
+    })
+}
+
 // Classic TUI version (original implementation)
-fn run_classic_tui() -> Result<()> {
-    let interfaces = interface::list_interfaces()?;
+fn run_classic_tui(args: TuiArgs, options: &MonitorOptions) -> Result<()> {
+    // Open the CSV file before switching the terminal into raw mode
+    let csv = args
+        .csv
+        .as_ref()
+        .map(|path| CsvWriter::open(path))
+        .transpose()?;
+    let sampler = tui_sampler(&args, options)?;
+    let interfaces = match &sampler {
+        Some(sampler) => sampler.interfaces().to_vec(),
+        None => interface::list_interfaces()?,
+    };
 
     if interfaces.is_empty() {
-        eprintln!("No network interfaces found!");
+        eprintln!("{}", t(Msg::NoInterfacesFound));
         return Ok(());
     }
 
@@ -271,7 +1347,16 @@ fn run_classic_tui() -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // Create classic app and run
-    let mut app = App::new(interfaces)?;
+    let mut app = match sampler {
+        Some(sampler) => App::with_sampler(sampler, options)?,
+        None => App::with_options(interfaces, options)?,
+    };
+    // Remote and SNMP counters are not added to this machine's accounting,
+    // and an attached daemon already records them
+    if args.remote.is_some() || args.snmp.is_some() || options.attach.is_some() {
+        app.recorder = None;
+    }
+    app.csv = csv;
     let res = app.run(&mut terminal);
 
     // Restore terminal
@@ -290,50 +1375,52 @@ fn run_classic_tui() -> Result<()> {
     Ok(())
 }
 
-/// 도움말 메시지를 출력하는 함수
-fn show_help() {
-    println!(
-        "NBMon v{} - Cross-platform Network Bandwidth Monitor",
-        env!("CARGO_PKG_VERSION")
-    );
-    println!("Linux의 nload와 bmon에서 영감을 받은 실시간 네트워크 트래픽 모니터링 도구");
-    println!();
-    println!("사용법:");
-    println!("    nbmon [OPTIONS]");
-    println!();
-    println!("옵션:");
-    println!("    (기본)        향상된 TUI 모드 - Linux nload 스타일의 실시간 그래프");
-    println!("    --classic     클래식 TUI 모드 - 단순한 리스트 형태의 인터페이스");
-    println!("    --simple      단순 콘솔 모드 - 한 번 출력 후 종료");
-    println!("    -h, --help    이 도움말 메시지 출력");
-    println!("    -v, --version 버전 정보 출력");
-    println!();
-    println!("키보드 단축키 (TUI 모드):");
-    println!("    ←/h           이전 네트워크 인터페이스");
-    println!("    →/l           다음 네트워크 인터페이스");
-    println!("    Space         수동 업데이트");
-    println!("    r             히스토리 초기화");
-    println!("    q             프로그램 종료");
-    println!();
-    println!("기능:");
-    println!("    • 실시간 네트워크 대역폭 모니터링");
-    println!("    • 업로드/다운로드 속도 그래프 (스파크라인)");
-    println!("    • 다중 네트워크 인터페이스 지원");
-    println!("    • Private/Public IP 자동 감지 및 표시");
-    println!("    • 크로스플랫폼 지원 (Windows/Linux)");
-    println!("    • 낮은 리소스 사용량");
-    println!();
-    println!("예제:");
-    println!("    nbmon                # 기본 향상된 TUI 모드 실행");
-    println!("    nbmon --classic      # 클래식 TUI 모드 실행");
-    println!("    nbmon --simple       # 간단한 정보 출력 후 종료");
-    println!();
-    println!("저장소: https://github.com/i978sukhoi/nbmon");
-}
-
-/// 버전 정보를 출력하는 함수
-fn show_version() {
-    println!("NBMon v{}", env!("CARGO_PKG_VERSION"));
-    println!("Cross-platform Network Bandwidth Monitor");
-    println!("Built with Rust");
+// 여러 호스트 대시보드 (--host를 주면 설정 파일의 [[hosts]] 대신 사용)
+fn run_dashboard(
+    args: DashboardArgs,
+    configured: &[HostConfig],
+    config_path: Option<&std::path::Path>,
+    options: &MonitorOptions,
+) -> Result<()> {
+    let hosts: Vec<HostConfig> = if args.host.is_empty() {
+        configured.to_vec()
+    } else {
+        args.host
+            .iter()
+            .map(|address| HostConfig {
+                psk_file: args.psk_file.clone(),
+                ..HostConfig::new(address)
+            })
+            .collect()
+    };
+    if hosts.is_empty() {
+        let path = config_path
+            .map(std::path::Path::to_path_buf)
+            .or_else(|| Config::default_path().ok())
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| nbmon::config::CONFIG_FILE.to_string());
+        anyhow::bail!(tf(Msg::ErrNoHosts, &[&path]));
+    }
+
+    // 키 파일은 터미널을 TUI 모드로 바꾸기 전에 읽어서 에러를 일반 출력으로 보여줌
+    let mut dashboard = Dashboard::new(&hosts, options)?;
+    // 이 컴퓨터의 Public IP는 원격 호스트와 관계없으므로 표시하지 않음
+    public_ip::set_enabled(false);
+
+    enable_raw_mode().context(t(Msg::ErrRawMode))?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let res = dashboard.run(&mut terminal);
+
+    disable_raw_mode().context(t(Msg::ErrRestoreTerminal))?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+    res
 }
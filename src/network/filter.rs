@@ -0,0 +1,270 @@
+// 인터페이스 필터 모듈
+// 이름 패턴(glob 또는 정규식)과 종류(가상/DOWN/루프백)로 화면과 내보내기에 포함할 인터페이스를 고름
+// 설정 파일 [filters], 커맨드라인(--include/--exclude/...), TUI 단축키가 모두 이 구조체를 사용
+//
+// 패턴 문법:
+//   eth*, wlan?, enp[0-9]s*   glob (이름 전체와 일치)
+//   re:^(cali|veth)           re: 접두사가 붙으면 정규식 (이름의 일부와 일치해도 됨)
+
+use regex::Regex;
+use serde::{de, Deserialize, Deserializer};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::i18n::{t, Msg};
+use crate::network::interface::NetworkInterface;
+
+/// 정규식 패턴 접두사
+pub const REGEX_PREFIX: &str = "re:";
+
+/// 인터페이스 이름 패턴 (glob 또는 re:정규식)
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    source: String, // 사용자가 입력한 원래 문자열 (표시용)
+    regex: Regex,   // glob도 정규식으로 변환해서 보관
+}
+
+impl Pattern {
+    /// 이름 또는 설명이 패턴과 일치하는지 (Windows는 이름보다 설명이 익숙하므로 둘 다 검사)
+    pub fn matches(&self, interface: &NetworkInterface) -> bool {
+        self.regex.is_match(&interface.name) || self.regex.is_match(&interface.description)
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = regex::Error;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let regex = match source.strip_prefix(REGEX_PREFIX) {
+            Some(expression) => Regex::new(expression)?,
+            None => Regex::new(&glob_to_regex(source))?,
+        };
+        Ok(Self {
+            source: source.to_string(),
+            regex,
+        })
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+// 설정 파일에서는 문자열로 읽고, 잘못된 패턴은 줄 번호와 함께 보고되도록 serde 에러로 변환
+impl<'de> Deserialize<'de> for Pattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        source
+            .parse()
+            .map_err(|e| de::Error::custom(format!("invalid pattern `{}`: {}", source, e)))
+    }
+}
+
+// glob을 이름 전체와 일치하는 정규식으로 변환 (*, ?, [...], [!...] 지원)
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::from("^");
+    let mut chars = glob.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            '[' => {
+                regex.push('[');
+                if chars.next_if_eq(&'!').is_some() {
+                    regex.push('^');
+                }
+                // 닫는 ]까지 그대로 복사 (없으면 정규식 컴파일 에러로 보고됨)
+                for c in chars.by_ref() {
+                    if c == '\\' {
+                        regex.push_str("\\\\");
+                        continue;
+                    }
+                    regex.push(c);
+                    if c == ']' {
+                        break;
+                    }
+                }
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+/// 표시할 인터페이스를 고르는 필터 ([filters])
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct InterfaceFilter {
+    /// 비어 있지 않으면 하나라도 일치하는 인터페이스만 표시
+    pub include: Vec<Pattern>,
+    /// 하나라도 일치하면 숨김 (include보다 우선)
+    pub exclude: Vec<Pattern>,
+    /// 가상 인터페이스(veth, docker, VPN 등) 숨김
+    pub hide_virtual: bool,
+    /// DOWN 상태 인터페이스도 표시
+    pub show_down: bool,
+    /// 루프백 인터페이스도 표시
+    pub show_loopback: bool,
+    /// TUI에서 include/exclude 패턴을 잠시 끈 상태
+    #[serde(skip)]
+    pub patterns_paused: bool,
+}
+
+impl InterfaceFilter {
+    /// 인터페이스를 표시할지 여부
+    pub fn matches(&self, interface: &NetworkInterface) -> bool {
+        // 종류 필터
+        if !interface.is_up && !self.show_down {
+            return false;
+        }
+        if interface.is_loopback && !self.show_loopback {
+            return false;
+        }
+        if self.hide_virtual && interface.is_virtual() {
+            return false;
+        }
+
+        // 이름 패턴
+        if self.patterns_paused {
+            return true;
+        }
+        let included = self.include.is_empty()
+            || self
+                .include
+                .iter()
+                .any(|pattern| pattern.matches(interface));
+        included
+            && !self
+                .exclude
+                .iter()
+                .any(|pattern| pattern.matches(interface))
+    }
+
+    /// 필터를 통과한 인터페이스의 위치 목록
+    pub fn visible_indices(&self, interfaces: &[NetworkInterface]) -> Vec<usize> {
+        interfaces
+            .iter()
+            .enumerate()
+            .filter(|(_, interface)| self.matches(interface))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// include 또는 exclude 패턴이 있는지
+    pub fn has_patterns(&self) -> bool {
+        !self.include.is_empty() || !self.exclude.is_empty()
+    }
+
+    /// 화면 제목에 붙일 현재 필터 요약 (기본 필터면 "default")
+    pub fn summary_or_default(&self) -> String {
+        let summary = self.summary();
+        if summary.is_empty() {
+            t(Msg::FilterDefault).to_string()
+        } else {
+            summary
+        }
+    }
+
+    /// 현재 필터 요약 (기본 필터면 빈 문자열)
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if self.has_patterns() {
+            if self.patterns_paused {
+                parts.push(t(Msg::FilterPatternsOff).to_string());
+            } else {
+                if !self.include.is_empty() {
+                    parts.push(format!("+{}", join(&self.include)));
+                }
+                if !self.exclude.is_empty() {
+                    parts.push(format!("-{}", join(&self.exclude)));
+                }
+            }
+        }
+        if self.hide_virtual {
+            parts.push(t(Msg::FilterNoVirtual).to_string());
+        }
+        if self.show_down {
+            parts.push(t(Msg::FilterDown).to_string());
+        }
+        if self.show_loopback {
+            parts.push(t(Msg::FilterLoopback).to_string());
+        }
+        parts.join(", ")
+    }
+}
+
+fn join(patterns: &[Pattern]) -> String {
+    patterns
+        .iter()
+        .map(Pattern::to_string)
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interface(name: &str, is_up: bool) -> NetworkInterface {
+        let mut interface = NetworkInterface::new(1, name.to_string(), name.to_string());
+        interface.is_up = is_up;
+        interface
+    }
+
+    fn patterns(sources: &[&str]) -> Vec<Pattern> {
+        sources.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_glob_and_regex_patterns() {
+        let glob: Pattern = "enp[0-9]s*".parse().unwrap();
+        assert!(glob.matches(&interface("enp3s0", true)));
+        assert!(!glob.matches(&interface("xenp3s0", true)));
+
+        let negated: Pattern = "eth[!0]".parse().unwrap();
+        assert!(negated.matches(&interface("eth1", true)));
+        assert!(!negated.matches(&interface("eth0", true)));
+
+        // 점은 glob에서 문자 그대로
+        let dotted: Pattern = "eth0.100".parse().unwrap();
+        assert!(!dotted.matches(&interface("eth0x100", true)));
+
+        let regex: Pattern = "re:^(cali|veth)".parse().unwrap();
+        assert!(regex.matches(&interface("cali12ab", true)));
+        assert!(!regex.matches(&interface("eth0", true)));
+
+        assert!("re:(".parse::<Pattern>().is_err());
+    }
+
+    #[test]
+    fn test_filter_kinds_and_patterns() {
+        let interfaces = vec![
+            interface("eth0", true),
+            interface("veth1a2b", true),
+            interface("cali0", true),
+            interface("wlan0", false),
+        ];
+
+        let mut filter = InterfaceFilter {
+            exclude: patterns(&["cali*"]),
+            hide_virtual: true,
+            ..Default::default()
+        };
+        assert_eq!(filter.visible_indices(&interfaces), vec![0]);
+
+        filter.show_down = true;
+        filter.hide_virtual = false;
+        assert_eq!(filter.visible_indices(&interfaces), vec![0, 1, 3]);
+
+        // include가 있으면 일치하는 것만, exclude가 우선
+        filter.include = patterns(&["re:0$"]);
+        assert_eq!(filter.visible_indices(&interfaces), vec![0, 3]);
+
+        filter.patterns_paused = true;
+        assert_eq!(filter.visible_indices(&interfaces), vec![0, 1, 2, 3]);
+    }
+}
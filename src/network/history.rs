@@ -0,0 +1,440 @@
+// 인터페이스별 대역폭 히스토리를 보관하는 모듈
+// 여러 해상도의 롤업(1초/10초/1분)을 유지하여 긴 시간 범위도 메모리에 보관
+// 각 버킷은 min/avg/max를 함께 저장하므로 다운샘플링 후에도 피크가 사라지지 않음
+
+use serde::{Deserialize, Serialize}; // 데몬에 붙는 클라이언트에 히스토리 전체를 전달
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::network::stats::BandwidthStats;
+
+// 그래프 스케일링 기본값 (1 MB/s)
+const DEFAULT_MAX_RATE: f64 = 1024.0 * 1024.0;
+// 최대 속도 스케일링 배수 - 그래프 상단에 약간의 여유를 둠
+const RATE_SCALE_MULTIPLIER: f64 = 1.1;
+
+// 롤업 단계 설정: (버킷 하나의 길이(초), 보관할 버킷 수)
+// 1초 해상도 10분, 10초 해상도 2시간, 1분 해상도 24시간
+// 샘플링 간격이 1초가 아니면 버킷당 샘플 수와 보관할 버킷 수를 간격에 맞춰 조정 (첫 단계는 샘플 하나가 버킷 하나)
+const ROLLUP_TIERS: [(u64, usize); 3] = [(1, 600), (10, 720), (60, 1440)];
+
+/// 하나의 버킷에 모인 샘플들의 요약 (바이트/초)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Bucket {
+    pub min: u64,
+    pub avg: u64,
+    pub max: u64,
+}
+
+impl Bucket {
+    /// 단일 샘플로 이루어진 버킷
+    pub fn single(value: u64) -> Self {
+        Self {
+            min: value,
+            avg: value,
+            max: value,
+        }
+    }
+
+    /// 여러 버킷을 하나로 합침 (min의 최소, max의 최대, avg의 평균)
+    pub fn merge(buckets: &[Bucket]) -> Option<Self> {
+        let first = buckets.first()?;
+        let sum: u128 = buckets.iter().map(|b| b.avg as u128).sum();
+        Some(Self {
+            min: buckets.iter().map(|b| b.min).min().unwrap_or(first.min),
+            avg: (sum / buckets.len() as u128) as u64,
+            max: buckets.iter().map(|b| b.max).max().unwrap_or(first.max),
+        })
+    }
+}
+
+/// 그래프에 표시할 시간 범위 (줌 단계)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeRange {
+    OneMinute,
+    TenMinutes,
+    OneHour,
+    OneDay,
+}
+
+impl TimeRange {
+    /// 모든 줌 단계 (짧은 범위 → 긴 범위)
+    pub const ALL: [TimeRange; 4] = [
+        TimeRange::OneMinute,
+        TimeRange::TenMinutes,
+        TimeRange::OneHour,
+        TimeRange::OneDay,
+    ];
+
+    /// 화면 표시용 짧은 라벨
+    pub fn label(self) -> &'static str {
+        match self {
+            TimeRange::OneMinute => "1m",
+            TimeRange::TenMinutes => "10m",
+            TimeRange::OneHour => "1h",
+            TimeRange::OneDay => "24h",
+        }
+    }
+
+    /// 범위 전체 길이 (초)
+    pub fn seconds(self) -> u64 {
+        match self {
+            TimeRange::OneMinute => 60,
+            TimeRange::TenMinutes => 600,
+            TimeRange::OneHour => 3_600,
+            TimeRange::OneDay => 86_400,
+        }
+    }
+
+    /// 라벨("1m", "10m", "1h", "24h")로부터 범위
+    pub fn parse(label: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|range| range.label() == label)
+    }
+
+    /// 더 긴 범위로 줌 아웃 (마지막 단계에서는 그대로)
+    pub fn zoom_out(self) -> Self {
+        let pos = Self::ALL.iter().position(|r| *r == self).unwrap_or(0);
+        Self::ALL[(pos + 1).min(Self::ALL.len() - 1)]
+    }
+
+    /// 더 짧은 범위로 줌 인 (첫 단계에서는 그대로)
+    pub fn zoom_in(self) -> Self {
+        let pos = Self::ALL.iter().position(|r| *r == self).unwrap_or(0);
+        Self::ALL[pos.saturating_sub(1)]
+    }
+}
+
+// 아직 완성되지 않은 버킷을 누적하는 구조체
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct Accumulator {
+    min: u64,
+    max: u64,
+    // 데몬 응답(내부 태그 enum) 안에서는 serde가 u128을 다루지 못하므로 문자열로 주고받음
+    #[serde(with = "u128_string")]
+    sum: u128,
+    count: u64,
+}
+
+mod u128_string {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Accumulator {
+    fn add(&mut self, bucket: Bucket, weight: u64) {
+        if self.count == 0 {
+            self.min = bucket.min;
+            self.max = bucket.max;
+        } else {
+            self.min = self.min.min(bucket.min);
+            self.max = self.max.max(bucket.max);
+        }
+        self.sum += bucket.avg as u128 * weight as u128;
+        self.count += weight;
+    }
+
+    fn finish(&mut self) -> Bucket {
+        let bucket = Bucket {
+            min: self.min,
+            avg: (self.sum / self.count.max(1) as u128) as u64,
+            max: self.max,
+        };
+        *self = Self::default();
+        bucket
+    }
+}
+
+// 하나의 해상도 단계
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Tier {
+    resolution_ms: u64, // 버킷 하나가 차지하는 시간 (밀리초, 1초 미만 간격도 표현)
+    samples: u64,       // 버킷 하나에 모으는 샘플 수
+    capacity: usize,    // 보관할 최대 버킷 수
+    buckets: VecDeque<Bucket>,
+    pending: Accumulator, // 아직 채워지지 않은 현재 버킷
+}
+
+impl Tier {
+    fn new(samples: u64, interval_ms: u64, capacity: usize) -> Self {
+        Self {
+            resolution_ms: samples * interval_ms,
+            samples,
+            capacity,
+            buckets: VecDeque::with_capacity(capacity),
+            pending: Accumulator::default(),
+        }
+    }
+
+    // 샘플 하나를 추가하고, 버킷이 완성되면 보관 목록에 넣음
+    fn add(&mut self, value: u64) {
+        self.pending.add(Bucket::single(value), 1);
+        if self.pending.count >= self.samples {
+            let bucket = self.pending.finish();
+            self.buckets.push_back(bucket);
+            while self.buckets.len() > self.capacity {
+                self.buckets.pop_front();
+            }
+        }
+    }
+}
+
+/// 한 방향(다운로드 또는 업로드)의 다중 해상도 속도 시계열
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollupSeries {
+    tiers: Vec<Tier>,
+}
+
+impl RollupSeries {
+    /// raw_capacity: 원본 해상도(샘플 하나가 버킷 하나)로 보관할 샘플 수
+    /// interval: 샘플링 간격 - 버킷을 샘플 수가 아닌 시간으로 나누기 위해 사용
+    pub fn new(raw_capacity: usize, interval: Duration) -> Self {
+        let interval_ms = (interval.as_millis() as u64).max(1);
+        let tiers = ROLLUP_TIERS
+            .iter()
+            .enumerate()
+            .map(|(i, &(resolution, capacity))| {
+                // 첫 단계는 원본 샘플 그대로, 보관 길이만 설정 가능
+                if i == 0 {
+                    return Tier::new(1, interval_ms, raw_capacity);
+                }
+                // 버킷 길이에 가장 가까운 샘플 수로 묶고, 단계 전체 길이가 유지되도록 버킷 수 조정
+                let resolution_ms = resolution * 1000;
+                let samples = ((resolution_ms + interval_ms / 2) / interval_ms).max(1);
+                let span_ms = resolution_ms * capacity as u64;
+                let capacity = span_ms.div_ceil(samples * interval_ms) as usize;
+                Tier::new(samples, interval_ms, capacity)
+            })
+            .collect();
+        Self { tiers }
+    }
+
+    /// 샘플 하나를 모든 단계에 반영
+    pub fn push(&mut self, value: u64) {
+        for tier in &mut self.tiers {
+            tier.add(value);
+        }
+    }
+
+    /// 시간 범위를 표현할 수 있는 가장 세밀한 단계의 버킷들 (오래된 것 → 최신)
+    pub fn range(&self, range: TimeRange) -> Vec<Bucket> {
+        // 범위 전체를 담을 수 있는 첫 단계 선택, 없으면 가장 거친 단계 사용
+        let tier = self
+            .tiers
+            .iter()
+            .find(|t| t.resolution_ms * t.capacity as u64 >= range.seconds() * 1000)
+            .or(self.tiers.last());
+
+        let Some(tier) = tier else {
+            return Vec::new();
+        };
+
+        let wanted = (range.seconds() * 1000 / tier.resolution_ms).max(1) as usize;
+        let skip = tier.buckets.len().saturating_sub(wanted);
+        tier.buckets.iter().skip(skip).copied().collect()
+    }
+
+    /// 원본 해상도의 최근 n개 샘플 (오래된 것 → 최신)
+    pub fn recent(&self, n: usize) -> Vec<u64> {
+        let Some(raw) = self.tiers.first() else {
+            return Vec::new();
+        };
+        let skip = raw.buckets.len().saturating_sub(n);
+        raw.buckets.iter().skip(skip).map(|b| b.avg).collect()
+    }
+
+    /// 가장 최근 샘플
+    pub fn latest(&self) -> Option<u64> {
+        self.tiers.first()?.buckets.back().map(|b| b.avg)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiers.first().is_none_or(|t| t.buckets.is_empty())
+    }
+
+    pub fn clear(&mut self) {
+        for tier in &mut self.tiers {
+            tier.buckets.clear();
+            tier.pending = Accumulator::default();
+        }
+    }
+}
+
+/// 버킷 목록을 최대 width개로 줄임 - 인접한 버킷을 합쳐서 피크를 보존
+pub fn downsample(buckets: &[Bucket], width: usize) -> Vec<Bucket> {
+    if width == 0 || buckets.len() <= width {
+        return buckets.to_vec();
+    }
+
+    // div_ceil(): 올림 나눗셈 - 결과가 width를 넘지 않도록 함
+    let chunk_size = buckets.len().div_ceil(width);
+    buckets
+        .chunks(chunk_size)
+        .filter_map(Bucket::merge)
+        .collect()
+}
+
+/// 하나의 인터페이스에 대한 다운로드/업로드 속도 히스토리
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterfaceHistory {
+    download: RollupSeries, // 다운로드 속도 (바이트/초)
+    upload: RollupSeries,   // 업로드 속도 (바이트/초)
+    max_download_rate: f64, // 그래프 스케일링용 최대 다운로드 속도
+    max_upload_rate: f64,   // 그래프 스케일링용 최대 업로드 속도
+}
+
+impl InterfaceHistory {
+    /// raw_capacity: 원본 해상도로 보관할 샘플 수 (긴 범위는 롤업으로 보관)
+    /// interval: 샘플링 간격 (롤업 버킷의 시간 길이를 맞추는 데 사용)
+    pub fn new(raw_capacity: usize, interval: Duration) -> Self {
+        Self {
+            download: RollupSeries::new(raw_capacity, interval),
+            upload: RollupSeries::new(raw_capacity, interval),
+            max_download_rate: DEFAULT_MAX_RATE,
+            max_upload_rate: DEFAULT_MAX_RATE,
+        }
+    }
+
+    /// 새 대역폭 샘플 추가 - 모든 해상도 단계에 반영
+    pub fn push(&mut self, bandwidth: &BandwidthStats) {
+        self.download.push(bandwidth.download_rate as u64);
+        self.upload.push(bandwidth.upload_rate as u64);
+
+        self.max_download_rate = self
+            .max_download_rate
+            .max(bandwidth.download_rate * RATE_SCALE_MULTIPLIER);
+        self.max_upload_rate = self
+            .max_upload_rate
+            .max(bandwidth.upload_rate * RATE_SCALE_MULTIPLIER);
+    }
+
+    /// 히스토리와 스케일을 초기 상태로 리셋
+    pub fn clear(&mut self) {
+        self.download.clear();
+        self.upload.clear();
+        self.max_download_rate = DEFAULT_MAX_RATE;
+        self.max_upload_rate = DEFAULT_MAX_RATE;
+    }
+
+    pub fn download(&self) -> &RollupSeries {
+        &self.download
+    }
+
+    pub fn upload(&self) -> &RollupSeries {
+        &self.upload
+    }
+
+    pub fn max_download_rate(&self) -> f64 {
+        self.max_download_rate
+    }
+
+    pub fn max_upload_rate(&self) -> f64 {
+        self.max_upload_rate
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.download.is_empty()
+    }
+}
+
+/// 버킷들의 max를 기준으로 그래프 스케일 계산 (최소 기본값 유지)
+pub fn scale_for(buckets: &[Bucket]) -> f64 {
+    let peak = buckets.iter().map(|b| b.max).max().unwrap_or(0) as f64;
+    (peak * RATE_SCALE_MULTIPLIER).max(DEFAULT_MAX_RATE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(download: f64, upload: f64) -> BandwidthStats {
+        BandwidthStats {
+            download_rate: download,
+            upload_rate: upload,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_raw_tier_is_bounded() {
+        let mut history = InterfaceHistory::new(3, Duration::from_secs(1));
+        for i in 0..5 {
+            history.push(&sample(i as f64, (i * 10) as f64));
+        }
+
+        assert_eq!(history.download().recent(10), [2, 3, 4]);
+        assert_eq!(history.upload().recent(10), [20, 30, 40]);
+        assert_eq!(history.download().latest(), Some(4));
+    }
+
+    #[test]
+    fn test_rollup_keeps_min_avg_max() {
+        let mut series = RollupSeries::new(600, Duration::from_secs(1));
+        // 10초 버킷 하나: 0이 9번, 1000이 1번
+        for _ in 0..9 {
+            series.push(0);
+        }
+        series.push(1000);
+
+        let hour = series.range(TimeRange::OneHour);
+        assert_eq!(
+            hour,
+            [Bucket {
+                min: 0,
+                avg: 100,
+                max: 1000
+            }]
+        );
+        // 1분 버킷은 아직 완성되지 않음
+        assert!(series.range(TimeRange::OneDay).is_empty());
+    }
+
+    #[test]
+    fn test_ranges_follow_sampling_interval() {
+        // 5초 간격: 1분은 원본 샘플 12개, 1분 버킷은 샘플 12개로 완성
+        let mut series = RollupSeries::new(600, Duration::from_secs(5));
+        for i in 0..200 {
+            series.push(i);
+        }
+        assert_eq!(series.range(TimeRange::OneMinute).len(), 12);
+        assert_eq!(series.range(TimeRange::TenMinutes).len(), 120);
+        // 200샘플 = 1000초: 10초 버킷 100개, 1분 버킷 16개
+        assert_eq!(series.range(TimeRange::OneHour).len(), 100);
+        assert_eq!(series.range(TimeRange::OneDay).len(), 16);
+
+        // 0.5초 간격: 1분은 원본 샘플 120개
+        let mut series = RollupSeries::new(600, Duration::from_millis(500));
+        for i in 0..200 {
+            series.push(i);
+        }
+        assert_eq!(series.range(TimeRange::OneMinute).len(), 120);
+    }
+
+    #[test]
+    fn test_downsample_preserves_peaks() {
+        let buckets: Vec<Bucket> = (0..10)
+            .map(|i| Bucket::single(if i == 7 { 500 } else { 10 }))
+            .collect();
+
+        let reduced = downsample(&buckets, 3);
+        assert_eq!(reduced.len(), 3);
+        assert_eq!(reduced.iter().map(|b| b.max).max(), Some(500));
+    }
+
+    #[test]
+    fn test_zoom_steps() {
+        assert_eq!(TimeRange::OneMinute.zoom_in(), TimeRange::OneMinute);
+        assert_eq!(TimeRange::OneMinute.zoom_out(), TimeRange::TenMinutes);
+        assert_eq!(TimeRange::OneDay.zoom_out(), TimeRange::OneDay);
+        assert_eq!(TimeRange::parse("10m"), Some(TimeRange::TenMinutes));
+        assert_eq!(TimeRange::parse("2h"), None);
+    }
+}
@@ -1,11 +1,13 @@
 // 네트워크 인터페이스 정보를 다루는 모듈
 use anyhow::Result;
+use serde::{Deserialize, Serialize}; // JSON 출력/원격 전송용 직렬화
 use std::net::IpAddr; // 표준 라이브러리의 IP 주소 타입 // 에러 처리용 Result 타입
 
 // #[derive(...)]: 자동으로 trait 구현 생성
 // Debug: println!("{:?}", obj) 형태로 디버그 출력 가능
 // Clone: .clone() 메서드로 복사본 생성 가능
-#[derive(Debug, Clone)]
+// Serialize/Deserialize: serde로 JSON 등의 형식과 변환 가능
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkInterface {
     // pub: 구조체 필드를 외부에서 접근 가능하도록 공개
     pub index: u32,                // 네트워크 인터페이스 인덱스 번호
@@ -52,6 +54,32 @@ impl NetworkInterface {
     // 가상 네트워크 인터페이스인지 판별하는 메서드
     // &self: 구조체 인스턴스에 대한 immutable 참조 (self를 수정하지 않음)
     pub fn is_virtual(&self) -> bool {
+        // Linux 컨테이너/가상화 도구가 만드는 인터페이스 이름 접두사
+        // (Linux는 description이 이름과 같으므로 이름으로 판별)
+        const VIRTUAL_PREFIXES: [&str; 15] = [
+            "veth",
+            "cali",
+            "docker",
+            "br-",
+            "virbr",
+            "vnet",
+            "flannel",
+            "cni",
+            "vxlan",
+            "kube-",
+            "lxc",
+            "weave",
+            "cilium",
+            "podman",
+            "tailscale",
+        ];
+        if VIRTUAL_PREFIXES
+            .iter()
+            .any(|prefix| self.name.starts_with(prefix))
+        {
+            return true;
+        }
+
         // description을 소문자로 변환하여 대소문자 구분 없이 비교
         let lower_desc = self.description.to_lowercase();
 
@@ -66,6 +94,17 @@ impl NetworkInterface {
             || lower_desc.contains("tun")
     }
 
+    // 인터페이스 종류 이름 (메트릭 레이블처럼 기계가 읽는 출력용이므로 번역하지 않음)
+    pub fn kind(&self) -> &'static str {
+        if self.is_loopback {
+            "loopback"
+        } else if self.is_virtual() {
+            "virtual"
+        } else {
+            "physical"
+        }
+    }
+
     // 사용자에게 표시할 인터페이스 이름을 반환하는 메서드
     pub fn display_name(&self) -> String {
         // description이 있으면 description 사용, 없으면 name 사용
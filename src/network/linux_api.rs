@@ -13,7 +13,6 @@ use std::process::Command;
 /// /sys/class/net 디렉터리를 읽어서 인터페이스 정보 수집
 pub fn get_network_interfaces() -> Result<Vec<NetworkInterface>> {
     let mut interfaces = Vec::new();
-    let mut index = 1u32;
 
     // /sys/class/net 디렉터리의 모든 인터페이스 읽기
     let net_dir = Path::new("/sys/class/net");
@@ -22,11 +21,14 @@ pub fn get_network_interfaces() -> Result<Vec<NetworkInterface>> {
             .context("This system may not be Linux or the /sys filesystem is not mounted");
     }
 
-    for entry in fs::read_dir(net_dir)
+    // enumerate(): 디렉터리 순서대로 1부터 시작하는 인덱스 부여
+    for (position, entry) in fs::read_dir(net_dir)
         .context("Failed to read /sys/class/net directory")
         .context("Check if you have permission to access network information")?
+        .enumerate()
     {
         let entry = entry.context("Failed to read directory entry")?;
+        let index = position as u32 + 1;
         let iface_name = entry.file_name().to_string_lossy().to_string();
 
         // 인터페이스 정보 생성
@@ -70,7 +72,6 @@ pub fn get_network_interfaces() -> Result<Vec<NetworkInterface>> {
         interface.ip_addresses = get_ip_addresses(&iface_name).unwrap_or_default();
 
         interfaces.push(interface);
-        index += 1;
     }
 
     Ok(interfaces)
@@ -84,13 +85,26 @@ pub fn get_interface_statistics(interface_index: u32) -> Result<InterfaceStats>
         .context("Failed to read /proc/net/dev")
         .context("Network statistics file not accessible")?;
 
-    // 인터페이스 목록 가져오기
-    let interfaces = get_network_interfaces()?;
-    let target_interface = interfaces
-        .get((interface_index - 1) as usize)
+    // 인덱스에 해당하는 인터페이스 이름 찾기
+    // 매 틱마다 모든 인터페이스를 수집하므로 IP 조회(외부 명령 실행) 없이 이름만 읽음
+    let target_name = interface_name_by_index(interface_index)?;
+
+    parse_proc_net_dev(&target_name, &proc_content, interface_index)
+}
+
+/// get_network_interfaces()와 같은 순서로 /sys/class/net을 읽어 인덱스의 이름을 반환
+fn interface_name_by_index(interface_index: u32) -> Result<String> {
+    let position = (interface_index as usize)
+        .checked_sub(1)
         .context("Interface index out of range")?;
 
-    parse_proc_net_dev(&target_interface.name, &proc_content, interface_index)
+    let entry = fs::read_dir("/sys/class/net")
+        .context("Failed to read /sys/class/net directory")?
+        .nth(position)
+        .context("Interface index out of range")?
+        .context("Failed to read directory entry")?;
+
+    Ok(entry.file_name().to_string_lossy().to_string())
 }
 
 /// /proc/net/dev 내용을 파싱하여 인터페이스 통계를 추출하는 함수
@@ -124,10 +138,12 @@ fn parse_proc_net_dev(
                     stats.bytes_received = parts[0].parse().unwrap_or(0);
                     stats.packets_received = parts[1].parse().unwrap_or(0);
                     stats.errors_in = parts[2].parse().unwrap_or(0);
+                    stats.drops_in = parts[3].parse().unwrap_or(0);
 
                     stats.bytes_sent = parts[8].parse().unwrap_or(0);
                     stats.packets_sent = parts[9].parse().unwrap_or(0);
                     stats.errors_out = parts[10].parse().unwrap_or(0);
+                    stats.drops_out = parts[11].parse().unwrap_or(0);
 
                     return Ok(stats);
                 }
@@ -3,6 +3,7 @@
 
 use anyhow::{Context, Result};
 use once_cell::sync::Lazy;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
@@ -10,7 +11,6 @@ use std::time::{Duration, Instant};
 struct PublicIpCache {
     ip: Option<String>,
     last_updated: Option<Instant>,
-    cache_duration: Duration,
 }
 
 impl PublicIpCache {
@@ -18,13 +18,12 @@ impl PublicIpCache {
         Self {
             ip: None,
             last_updated: None,
-            cache_duration: Duration::from_secs(300), // 5분 캐시
         }
     }
 
     fn is_valid(&self) -> bool {
         if let Some(last_updated) = self.last_updated {
-            last_updated.elapsed() < self.cache_duration
+            last_updated.elapsed() < refresh_interval()
         } else {
             false
         }
@@ -47,6 +46,12 @@ impl PublicIpCache {
 // 전역 캐시 인스턴스
 static CACHE: Lazy<Mutex<PublicIpCache>> = Lazy::new(|| Mutex::new(PublicIpCache::new()));
 
+// 외부 서비스 조회 허용 여부 (--no-public-ip로 끔)
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+// 캐시 유효 시간 (초, 기본 5분, 설정 파일의 public_ip.refresh_secs)
+static REFRESH_SECS: AtomicU64 = AtomicU64::new(300);
+
 // Public IP 서비스 목록 (폴백 지원)
 const IP_SERVICES: &[&str] = &[
     "https://api.ipify.org",         // 가장 간단하고 빠름
@@ -55,9 +60,34 @@ const IP_SERVICES: &[&str] = &[
     "https://ifconfig.me/ip",        // 전통적인 서비스
 ];
 
+/// Public IP 조회를 켜거나 끔
+/// 끄면 외부 서비스에 요청하지 않고 get_public_ip()가 항상 None을 반환
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Public IP 조회가 켜져 있는지 확인
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Public IP를 다시 조회하는 간격 설정
+pub fn set_refresh_interval(interval: Duration) {
+    REFRESH_SECS.store(interval.as_secs().max(1), Ordering::Relaxed);
+}
+
+/// Public IP를 다시 조회하는 간격
+pub fn refresh_interval() -> Duration {
+    Duration::from_secs(REFRESH_SECS.load(Ordering::Relaxed))
+}
+
 /// Public IP 주소를 가져오는 함수
 /// 캐시된 값이 있으면 반환, 없으면 API 호출
 pub fn get_public_ip() -> Option<String> {
+    if !is_enabled() {
+        return None;
+    }
+
     // 먼저 캐시 확인
     {
         let cache = CACHE.lock().unwrap();
@@ -125,6 +155,9 @@ fn is_valid_ip(ip: &str) -> bool {
 
 /// 비동기 버전 - 백그라운드에서 Public IP 업데이트
 pub fn update_public_ip_async() {
+    if !is_enabled() {
+        return;
+    }
     std::thread::spawn(|| {
         fetch_public_ip().inspect(|ip| {
             let mut cache = CACHE.lock().unwrap();
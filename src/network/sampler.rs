@@ -0,0 +1,224 @@
+// 모든 네트워크 인터페이스의 통계를 주기적으로 수집하는 모듈
+// 선택된 인터페이스와 관계없이 매 틱마다 전체 인터페이스를 샘플링하고
+// 인터페이스별 히스토리를 유지하여 화면 전환시에도 그래프가 보존되도록 함
+// 직접 샘플링하는 대신 데몬(nbmon daemon)에서 받은 스냅샷과 갱신으로 같은 상태를 유지할 수도 있음
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+use crate::network::history::InterfaceHistory;
+use crate::network::interface::NetworkInterface;
+use crate::network::parallel_stats::collect_all_stats_parallel;
+use crate::network::stats::{self, BandwidthStats, InterfaceStats};
+
+/// 카운터를 직접 읽지 않고 다른 곳(데몬 등)에서 통계를 받아오는 공급원
+pub trait StatsSource: Send {
+    /// 마지막 호출 이후의 갱신 (새 샘플이 없으면 None)
+    fn fetch(&mut self) -> Result<Option<Update>>;
+}
+
+/// Sampler 상태 전체 (데몬에 처음 붙을 때 히스토리까지 한 번에 받음)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub interfaces: Vec<NetworkInterface>,
+    pub stats: Vec<InterfaceStats>,
+    pub bandwidth: Vec<Option<BandwidthStats>>,
+    pub histories: Vec<InterfaceHistory>,
+    /// 인터페이스별로 카운터를 읽은 뒤 지난 시간 (밀리초, sample_ages)
+    #[serde(default)]
+    pub ages: Vec<u64>,
+}
+
+/// 한 번의 갱신 (목록은 스냅샷의 인터페이스 순서)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Update {
+    pub stats: Vec<InterfaceStats>,
+    pub bandwidth: Vec<Option<BandwidthStats>>,
+    /// 지난 갱신 이후 인터페이스별로 히스토리에 추가된 속도 (오래된 것 → 최신)
+    pub samples: Vec<Vec<BandwidthStats>>,
+    /// 인터페이스별로 카운터를 읽은 뒤 지난 시간 (밀리초, sample_ages)
+    #[serde(default)]
+    pub ages: Vec<u64>,
+}
+
+/// 카운터를 읽은 뒤 지난 시간 (밀리초, 시각이 없으면 0)
+/// Instant는 보낼 수 없으므로 받는 쪽은 받은 시각에서 이만큼 빼서 읽은 시각을 되살림
+/// (받은 시각을 그대로 쓰면 속도 계산의 경과 시간이 최대 한 간격만큼 어긋남)
+pub fn sample_ages(stats: &[InterfaceStats]) -> Vec<u64> {
+    stats
+        .iter()
+        .map(|stats| {
+            stats
+                .timestamp
+                .map_or(0, |t| t.elapsed().as_millis() as u64)
+        })
+        .collect()
+}
+
+// 받은 카운터의 시각: 보낸 쪽이 알려준 경과 시간 > 공급원이 기록한 시각 > 받은 시각
+fn stamp(stats: InterfaceStats, age: Option<&u64>, now: Instant) -> InterfaceStats {
+    let timestamp = match age {
+        Some(&age) => now.checked_sub(Duration::from_millis(age)),
+        None => stats.timestamp,
+    };
+    InterfaceStats {
+        timestamp: timestamp.or(Some(now)),
+        ..stats
+    }
+}
+
+/// 인터페이스 목록과 각 인터페이스의 최신 통계/히스토리를 함께 관리
+pub struct Sampler {
+    interfaces: Vec<NetworkInterface>, // 모든 네트워크 인터페이스 목록
+    stats: Vec<InterfaceStats>,        // 인터페이스별 마지막 카운터 값
+    bandwidth: Vec<Option<BandwidthStats>>, // 인터페이스별 마지막 계산된 속도
+    histories: Vec<InterfaceHistory>,  // 인터페이스별 속도 히스토리
+    source: Option<Box<dyn StatsSource>>, // 통계 공급원 (None이면 직접 샘플링)
+}
+
+impl Sampler {
+    /// 초기 카운터를 읽어 Sampler 생성
+    /// history_size: 인터페이스마다 보관할 샘플 수, interval: update를 호출할 간격
+    pub fn new(interfaces: Vec<NetworkInterface>, history_size: usize, interval: Duration) -> Self {
+        // 통계를 읽지 못한 인터페이스는 빈 통계로 시작
+        let stats = interfaces
+            .iter()
+            .map(|interface| {
+                stats::get_interface_stats(interface.index)
+                    .unwrap_or_else(|_| InterfaceStats::new(interface.index))
+            })
+            .collect();
+
+        Self {
+            bandwidth: vec![None; interfaces.len()],
+            histories: vec![InterfaceHistory::new(history_size, interval); interfaces.len()],
+            interfaces,
+            stats,
+            source: None,
+        }
+    }
+
+    /// 스냅샷에서 시작해서 이후 갱신은 source에서 받는 Sampler 생성
+    pub fn from_snapshot(snapshot: Snapshot, source: Box<dyn StatsSource>) -> Self {
+        let now = Instant::now();
+        Self {
+            stats: snapshot
+                .stats
+                .into_iter()
+                .enumerate()
+                .map(|(i, stats)| stamp(stats, snapshot.ages.get(i), now))
+                .collect(),
+            interfaces: snapshot.interfaces,
+            bandwidth: snapshot.bandwidth,
+            histories: snapshot.histories,
+            source: Some(source),
+        }
+    }
+
+    /// 지정한 인터페이스들의 현재 상태 (indices 순서)
+    pub fn snapshot(&self, indices: &[usize]) -> Snapshot {
+        let stats: Vec<InterfaceStats> = indices.iter().map(|&i| self.stats[i].clone()).collect();
+        Snapshot {
+            interfaces: indices
+                .iter()
+                .map(|&i| self.interfaces[i].clone())
+                .collect(),
+            ages: sample_ages(&stats),
+            stats,
+            bandwidth: indices.iter().map(|&i| self.bandwidth[i].clone()).collect(),
+            histories: indices.iter().map(|&i| self.histories[i].clone()).collect(),
+        }
+    }
+
+    /// 받아온 갱신 반영 (카운터의 시각은 보낸 쪽에서 읽은 시각으로)
+    pub fn apply(&mut self, update: Update) {
+        let now = Instant::now();
+        for (i, stats) in update.stats.into_iter().enumerate().take(self.stats.len()) {
+            self.stats[i] = stamp(stats, update.ages.get(i), now);
+        }
+        for (i, bandwidth) in update.bandwidth.into_iter().enumerate() {
+            if let Some(slot) = self.bandwidth.get_mut(i) {
+                *slot = bandwidth;
+            }
+        }
+        for (history, samples) in self.histories.iter_mut().zip(&update.samples) {
+            for sample in samples {
+                history.push(sample);
+            }
+        }
+    }
+
+    /// 모든 인터페이스의 통계를 수집하고 속도와 히스토리를 갱신
+    /// 공급원이 있으면 직접 읽지 않고 공급원에서 받은 갱신을 반영
+    pub fn update(&mut self) -> Result<()> {
+        if let Some(source) = &mut self.source {
+            if let Some(update) = source.fetch()? {
+                self.apply(update);
+            }
+            return Ok(());
+        }
+
+        // 병렬로 모든 인터페이스의 통계 수집
+        let new_stats: Vec<Option<InterfaceStats>> =
+            match collect_all_stats_parallel(&self.interfaces) {
+                Ok(new_stats) => new_stats.into_iter().map(Some).collect(),
+                Err(e) => {
+                    // 병렬 수집 실패 시 순차 수집으로 폴백 (실패한 인터페이스만 건너뜀)
+                    crate::debug_log!(
+                        "Parallel stats collection failed: {}, using sequential fallback",
+                        e
+                    );
+                    self.interfaces
+                        .iter()
+                        .map(|interface| stats::get_interface_stats(interface.index).ok())
+                        .collect()
+                }
+            };
+
+        for (i, current_stats) in new_stats.into_iter().enumerate() {
+            let Some(current_stats) = current_stats else {
+                continue;
+            };
+            if i >= self.stats.len() {
+                break;
+            }
+
+            self.bandwidth[i] = current_stats.calculate_bandwidth(&self.stats[i]);
+            if let Some(bandwidth) = &self.bandwidth[i] {
+                self.histories[i].push(bandwidth);
+            }
+            self.stats[i] = current_stats;
+        }
+
+        Ok(())
+    }
+
+    // === Getter 메서드들 ===
+
+    pub fn interfaces(&self) -> &[NetworkInterface] {
+        &self.interfaces
+    }
+
+    /// 모든 인터페이스의 마지막 통계
+    pub fn all_stats(&self) -> &[InterfaceStats] {
+        &self.stats
+    }
+
+    /// 모든 인터페이스의 마지막 계산된 속도
+    pub fn all_bandwidth(&self) -> &[Option<BandwidthStats>] {
+        &self.bandwidth
+    }
+
+    /// i번째 인터페이스의 히스토리
+    pub fn history(&self, i: usize) -> Option<&InterfaceHistory> {
+        self.histories.get(i)
+    }
+
+    /// i번째 인터페이스의 히스토리 초기화
+    pub fn clear_history(&mut self, i: usize) {
+        if let Some(history) = self.histories.get_mut(i) {
+            history.clear();
+        }
+    }
+}
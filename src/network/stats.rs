@@ -1,7 +1,8 @@
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InterfaceStats {
     pub interface_index: u32,
     pub bytes_sent: u64,
@@ -10,10 +11,17 @@ pub struct InterfaceStats {
     pub packets_received: u64,
     pub errors_in: u64,
     pub errors_out: u64,
+    // 버퍼 부족 등으로 버려진 패킷 수 (이전 형식의 데이터에는 없으므로 기본값 0)
+    #[serde(default)]
+    pub drops_in: u64,
+    #[serde(default)]
+    pub drops_out: u64,
+    // Instant는 프로세스 안에서만 의미가 있으므로 직렬화하지 않음
+    #[serde(skip)]
     pub timestamp: Option<Instant>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BandwidthStats {
     pub download_rate: f64, // bytes per second
     pub upload_rate: f64,   // bytes per second
@@ -158,6 +158,8 @@ pub fn get_interface_statistics(interface_index: u32) -> Result<InterfaceStats>
         stats.packets_sent = if_row.OutUcastPkts + if_row.OutNUcastPkts;
         stats.errors_in = if_row.InErrors;
         stats.errors_out = if_row.OutErrors;
+        stats.drops_in = if_row.InDiscards;
+        stats.drops_out = if_row.OutDiscards;
 
         Ok(stats)
     }
@@ -0,0 +1,392 @@
+// SNMP 메시지에 필요한 만큼의 BER(ASN.1 Basic Encoding Rules) 인코딩/디코딩
+// 모든 값은 TLV(태그, 길이, 내용) - 길이가 127 이하면 1바이트, 넘으면 0x80|바이트 수 뒤에 빅엔디언 길이
+//
+//   INTEGER 5        → 02 01 05
+//   OCTET STRING "a" → 04 01 61
+//   OID 1.3.6.1      → 06 03 2b 06 01   (처음 두 번호는 40 * a + b, 나머지는 7비트씩)
+
+use anyhow::{bail, Result};
+
+/// OID (1.3.6.1.2.1... 번호 목록)
+pub type Oid = Vec<u32>;
+
+// 범용 태그
+pub const INTEGER: u8 = 0x02;
+pub const OCTET_STRING: u8 = 0x04;
+pub const NULL: u8 = 0x05;
+pub const OBJECT_ID: u8 = 0x06;
+pub const SEQUENCE: u8 = 0x30;
+
+// SNMP 응용 태그 (RFC 2578)
+pub const COUNTER32: u8 = 0x41;
+pub const GAUGE32: u8 = 0x42;
+pub const TIMETICKS: u8 = 0x43;
+pub const COUNTER64: u8 = 0x46;
+
+// 변수 대신 오는 예외 (RFC 3416)
+pub const NO_SUCH_OBJECT: u8 = 0x80;
+pub const NO_SUCH_INSTANCE: u8 = 0x81;
+pub const END_OF_MIB_VIEW: u8 = 0x82;
+
+/// 변수 바인딩의 값
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Null,
+    Integer(i64),
+    OctetString(Vec<u8>),
+    ObjectId(Oid),
+    Counter32(u32),
+    Gauge32(u32),
+    TimeTicks(u32),
+    Counter64(u64),
+    NoSuchObject,
+    NoSuchInstance,
+    EndOfMibView,
+    /// 이 모듈이 해석하지 않는 형식 (IpAddress, Opaque 등)
+    Other(u8),
+}
+
+impl Value {
+    /// 부호 없는 정수로 (카운터, 게이지, 0 이상의 INTEGER)
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            Value::Integer(value) => u64::try_from(value).ok(),
+            Value::Counter32(value) | Value::Gauge32(value) | Value::TimeTicks(value) => {
+                Some(value as u64)
+            }
+            Value::Counter64(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Value::OctetString(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// 테이블 열이 끝났음을 알리는 값인지 (walk 종료 조건)
+    pub fn is_exception(&self) -> bool {
+        matches!(
+            self,
+            Value::NoSuchObject | Value::NoSuchInstance | Value::EndOfMibView
+        )
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            Value::Null => tlv(NULL, &[]),
+            Value::Integer(value) => integer(*value),
+            Value::OctetString(bytes) => octet_string(bytes),
+            Value::ObjectId(oid) => object_id(oid),
+            Value::Counter32(value) => unsigned(COUNTER32, *value as u64),
+            Value::Gauge32(value) => unsigned(GAUGE32, *value as u64),
+            Value::TimeTicks(value) => unsigned(TIMETICKS, *value as u64),
+            Value::Counter64(value) => unsigned(COUNTER64, *value),
+            Value::NoSuchObject => tlv(NO_SUCH_OBJECT, &[]),
+            Value::NoSuchInstance => tlv(NO_SUCH_INSTANCE, &[]),
+            Value::EndOfMibView => tlv(END_OF_MIB_VIEW, &[]),
+            Value::Other(tag) => tlv(*tag, &[]),
+        }
+    }
+}
+
+// === 인코딩 ===
+
+/// 태그와 내용으로 TLV 하나
+pub fn tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag];
+    let len = content.len();
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let bytes = len.to_be_bytes();
+        let skip = bytes.iter().take_while(|&&b| b == 0).count();
+        out.push(0x80 | (bytes.len() - skip) as u8);
+        out.extend_from_slice(&bytes[skip..]);
+    }
+    out.extend_from_slice(content);
+    out
+}
+
+/// 이미 인코딩된 값들을 묶은 SEQUENCE (PDU처럼 다른 태그의 구조체도)
+pub fn constructed(tag: u8, parts: &[Vec<u8>]) -> Vec<u8> {
+    tlv(tag, &parts.concat())
+}
+
+pub fn sequence(parts: &[Vec<u8>]) -> Vec<u8> {
+    constructed(SEQUENCE, parts)
+}
+
+/// 가장 짧은 2의 보수 표현
+pub fn integer(value: i64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let mut start = 0;
+    while start < bytes.len() - 1 {
+        let redundant = (bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0)
+            || (bytes[start] == 0xff && bytes[start + 1] & 0x80 != 0);
+        if !redundant {
+            break;
+        }
+        start += 1;
+    }
+    tlv(INTEGER, &bytes[start..])
+}
+
+/// 부호 없는 값 (최상위 비트가 1이면 앞에 0을 붙임)
+pub fn unsigned(tag: u8, value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let skip = bytes[..7].iter().take_while(|&&b| b == 0).count();
+    let mut content = Vec::with_capacity(9);
+    if bytes[skip] & 0x80 != 0 {
+        content.push(0);
+    }
+    content.extend_from_slice(&bytes[skip..]);
+    tlv(tag, &content)
+}
+
+pub fn octet_string(bytes: &[u8]) -> Vec<u8> {
+    tlv(OCTET_STRING, bytes)
+}
+
+pub fn null() -> Vec<u8> {
+    tlv(NULL, &[])
+}
+
+pub fn object_id(oid: &[u32]) -> Vec<u8> {
+    let mut content = Vec::new();
+    let (first, rest) = match oid {
+        [a, b, rest @ ..] => (a * 40 + b, rest),
+        [a] => (a * 40, &[][..]),
+        [] => (0, &[][..]),
+    };
+    for &arc in std::iter::once(&first).chain(rest) {
+        // 7비트씩 나누어 앞 바이트들에는 최상위 비트 표시
+        let mut groups = vec![(arc & 0x7f) as u8];
+        let mut value = arc >> 7;
+        while value > 0 {
+            groups.push((value & 0x7f) as u8 | 0x80);
+            value >>= 7;
+        }
+        content.extend(groups.iter().rev());
+    }
+    tlv(OBJECT_ID, &content)
+}
+
+// === 디코딩 ===
+
+/// 읽은 TLV 하나 (offset: 메시지 처음부터 내용이 시작하는 위치)
+#[derive(Debug, Clone, Copy)]
+pub struct Tlv<'a> {
+    pub tag: u8,
+    pub offset: usize,
+    pub content: &'a [u8],
+}
+
+/// 바이트열에서 TLV를 차례로 읽음
+pub struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    /// data가 메시지 전체에서 시작하는 위치 (중첩된 구조에서도 절대 위치를 알기 위해)
+    base: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            base: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    /// 다음 TLV
+    pub fn read(&mut self) -> Result<Tlv<'a>> {
+        let data = self.data;
+        let Some(&tag) = data.get(self.pos) else {
+            bail!("truncated SNMP message");
+        };
+        let Some(&first) = data.get(self.pos + 1) else {
+            bail!("truncated SNMP message");
+        };
+        let mut pos = self.pos + 2;
+        let len = if first < 0x80 {
+            first as usize
+        } else {
+            let count = (first & 0x7f) as usize;
+            if count == 0 || count > 4 || pos + count > data.len() {
+                bail!("unsupported BER length");
+            }
+            let len = data[pos..pos + count]
+                .iter()
+                .fold(0usize, |len, &b| (len << 8) | b as usize);
+            pos += count;
+            len
+        };
+        if pos + len > data.len() {
+            bail!("truncated SNMP message");
+        }
+        self.pos = pos + len;
+        Ok(Tlv {
+            tag,
+            offset: self.base + pos,
+            content: &data[pos..pos + len],
+        })
+    }
+
+    /// 다음 TLV가 tag인지 확인하고 내용 반환
+    pub fn expect(&mut self, tag: u8) -> Result<Tlv<'a>> {
+        let tlv = self.read()?;
+        if tlv.tag != tag {
+            bail!(
+                "unexpected BER tag 0x{:02x} (expected 0x{:02x})",
+                tlv.tag,
+                tag
+            );
+        }
+        Ok(tlv)
+    }
+
+    /// 다음 구조체(SEQUENCE, PDU, BER을 담은 OCTET STRING)의 내용을 읽는 Reader
+    pub fn enter(&mut self, tag: u8) -> Result<Reader<'a>> {
+        let tlv = self.expect(tag)?;
+        Ok(Reader {
+            data: tlv.content,
+            pos: 0,
+            base: tlv.offset,
+        })
+    }
+
+    pub fn integer(&mut self) -> Result<i64> {
+        decode_integer(self.expect(INTEGER)?.content)
+    }
+
+    pub fn octet_string(&mut self) -> Result<&'a [u8]> {
+        Ok(self.expect(OCTET_STRING)?.content)
+    }
+
+    pub fn object_id(&mut self) -> Result<Oid> {
+        decode_object_id(self.expect(OBJECT_ID)?.content)
+    }
+
+    pub fn value(&mut self) -> Result<Value> {
+        let tlv = self.read()?;
+        let content = tlv.content;
+        Ok(match tlv.tag {
+            NULL => Value::Null,
+            INTEGER => Value::Integer(decode_integer(content)?),
+            OCTET_STRING => Value::OctetString(content.to_vec()),
+            OBJECT_ID => Value::ObjectId(decode_object_id(content)?),
+            COUNTER32 => Value::Counter32(decode_unsigned(content)? as u32),
+            GAUGE32 => Value::Gauge32(decode_unsigned(content)? as u32),
+            TIMETICKS => Value::TimeTicks(decode_unsigned(content)? as u32),
+            COUNTER64 => Value::Counter64(decode_unsigned(content)?),
+            NO_SUCH_OBJECT => Value::NoSuchObject,
+            NO_SUCH_INSTANCE => Value::NoSuchInstance,
+            END_OF_MIB_VIEW => Value::EndOfMibView,
+            tag => Value::Other(tag),
+        })
+    }
+}
+
+fn decode_integer(content: &[u8]) -> Result<i64> {
+    if content.is_empty() || content.len() > 8 {
+        bail!("invalid BER integer");
+    }
+    // 부호 확장
+    let initial = if content[0] & 0x80 != 0 { -1i64 } else { 0 };
+    Ok(content
+        .iter()
+        .fold(initial, |value, &b| (value << 8) | b as i64))
+}
+
+fn decode_unsigned(content: &[u8]) -> Result<u64> {
+    let content = match content {
+        [0, rest @ ..] if !rest.is_empty() => rest,
+        content => content,
+    };
+    if content.is_empty() || content.len() > 8 {
+        bail!("invalid BER unsigned value");
+    }
+    Ok(content
+        .iter()
+        .fold(0u64, |value, &b| (value << 8) | b as u64))
+}
+
+fn decode_object_id(content: &[u8]) -> Result<Oid> {
+    let mut arcs = Vec::new();
+    let mut value: u32 = 0;
+    for (i, &b) in content.iter().enumerate() {
+        if value > u32::MAX >> 7 {
+            bail!("OID arc too large");
+        }
+        value = (value << 7) | (b & 0x7f) as u32;
+        if b & 0x80 != 0 {
+            if i + 1 == content.len() {
+                bail!("truncated OID");
+            }
+            continue;
+        }
+        if arcs.is_empty() {
+            let first = (value / 40).min(2);
+            arcs.push(first);
+            arcs.push(value - first * 40);
+        } else {
+            arcs.push(value);
+        }
+        value = 0;
+    }
+    Ok(arcs)
+}
+
+/// "1.3.6.1.2.1" 형식으로 표시
+pub fn format_oid(oid: &[u32]) -> String {
+    oid.iter().map(u32::to_string).collect::<Vec<_>>().join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_and_decode() {
+        assert_eq!(integer(0), [0x02, 0x01, 0x00]);
+        assert_eq!(integer(128), [0x02, 0x02, 0x00, 0x80]);
+        assert_eq!(integer(-129), [0x02, 0x02, 0xff, 0x7f]);
+        assert_eq!(
+            unsigned(COUNTER32, 0xffff_ffff),
+            [0x41, 0x05, 0, 0xff, 0xff, 0xff, 0xff]
+        );
+        // ifHCInOctets.3 (1.3.6.1.2.1.31.1.1.1.6.3)
+        let oid = vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 6, 3];
+        assert_eq!(
+            object_id(&oid),
+            [0x06, 0x0b, 0x2b, 6, 1, 2, 1, 31, 1, 1, 1, 6, 3]
+        );
+        assert_eq!(object_id(&[1, 3, 6, 1, 4, 1, 8072])[7..], [0xbf, 0x08]);
+
+        // 긴 길이와 중첩 구조를 거쳐 그대로 돌아옴
+        let values = [
+            Value::Integer(-5),
+            Value::OctetString(vec![b'x'; 300]),
+            Value::ObjectId(oid.clone()),
+            Value::Counter64(u64::MAX),
+            Value::Gauge32(10_000),
+            Value::EndOfMibView,
+        ];
+        let encoded = sequence(&values.iter().map(Value::encode).collect::<Vec<_>>());
+        let mut outer = Reader::new(&encoded);
+        let mut inner = outer.enter(SEQUENCE).unwrap();
+        for value in &values {
+            assert_eq!(&inner.value().unwrap(), value);
+        }
+        assert!(inner.is_empty() && outer.is_empty());
+        assert!(Reader::new(&encoded[..encoded.len() - 1]).read().is_err());
+        assert_eq!(format_oid(&oid), "1.3.6.1.2.1.31.1.1.1.6.3");
+    }
+}
@@ -0,0 +1,342 @@
+// IF-MIB (RFC 2863)을 읽어서 NetworkInterface와 InterfaceStats로 바꿈
+// 처음에 ifTable/ifXTable로 포트 목록을 만들고, 매 틱 64비트 카운터(ifHC*Octets)를 다시 읽음
+//
+//   ifTable  (1.3.6.1.2.1.2.2.1):    ifDescr .2, ifType .3, ifPhysAddress .6, ifOperStatus .8, 에러/드롭 카운터
+//   ifXTable (1.3.6.1.2.1.31.1.1.1): ifName .1, ifHCInOctets .6, ifHCOutOctets .10, ifHighSpeed .15, ifAlias .18
+//
+// 행은 OID의 마지막 번호(ifIndex)로 구분하고, NetworkInterface.index에도 ifIndex를 그대로 씀
+
+use anyhow::{bail, Result};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use super::ber::{Oid, Value};
+use super::session::Session;
+use super::Target;
+use crate::network::history::InterfaceHistory;
+use crate::network::interface::NetworkInterface;
+use crate::network::sampler::{Sampler, Snapshot, StatsSource, Update};
+use crate::network::stats::InterfaceStats;
+
+const IF_TABLE: [u32; 9] = [1, 3, 6, 1, 2, 1, 2, 2, 1];
+const IF_X_TABLE: [u32; 10] = [1, 3, 6, 1, 2, 1, 31, 1, 1, 1];
+
+// GetBulk 한 번에 열마다 받을 행 수
+const MAX_REPETITIONS: u32 = 10;
+
+// ifType softwareLoopback, ifOperStatus up
+const SOFTWARE_LOOPBACK: u64 = 24;
+const OPER_STATUS_UP: u64 = 1;
+
+fn column(table: &[u32], column: u32) -> Oid {
+    let mut oid = table.to_vec();
+    oid.push(column);
+    oid
+}
+
+// 포트 목록을 만들 때 읽는 열 (순서대로 행의 값 위치)
+fn discovery_columns() -> Vec<Oid> {
+    vec![
+        column(&IF_TABLE, 2),    // ifDescr
+        column(&IF_TABLE, 3),    // ifType
+        column(&IF_TABLE, 6),    // ifPhysAddress
+        column(&IF_TABLE, 8),    // ifOperStatus
+        column(&IF_X_TABLE, 1),  // ifName
+        column(&IF_X_TABLE, 15), // ifHighSpeed (Mbps)
+        column(&IF_X_TABLE, 18), // ifAlias
+    ]
+}
+
+// 매 틱 읽는 카운터 열
+fn counter_columns() -> Vec<Oid> {
+    vec![
+        column(&IF_X_TABLE, 6),  // ifHCInOctets
+        column(&IF_X_TABLE, 10), // ifHCOutOctets
+        column(&IF_X_TABLE, 7),  // ifHCInUcastPkts
+        column(&IF_X_TABLE, 11), // ifHCOutUcastPkts
+        column(&IF_TABLE, 14),   // ifInErrors
+        column(&IF_TABLE, 20),   // ifOutErrors
+        column(&IF_TABLE, 13),   // ifInDiscards
+        column(&IF_TABLE, 19),   // ifOutDiscards
+    ]
+}
+
+/// ifIndex별 행 (값은 columns 순서, 에이전트에 없는 값은 None)
+pub type Rows = BTreeMap<u32, Vec<Option<Value>>>;
+
+/// 여러 열을 GetBulk로 함께 걸어서 표로 모음
+/// 열의 범위를 벗어나거나 endOfMibView가 오면 그 열은 끝
+pub fn walk(session: &mut Session, columns: &[Oid]) -> Result<Rows> {
+    let mut rows = Rows::new();
+    // 열마다 다음에 요청할 OID (None이면 끝난 열)
+    let mut cursors: Vec<Option<Oid>> = columns.iter().cloned().map(Some).collect();
+
+    loop {
+        let active: Vec<usize> = (0..columns.len())
+            .filter(|&i| cursors[i].is_some())
+            .collect();
+        if active.is_empty() {
+            return Ok(rows);
+        }
+        let oids: Vec<Oid> = active.iter().filter_map(|&i| cursors[i].clone()).collect();
+        let varbinds = session.get_bulk(&oids, MAX_REPETITIONS)?;
+        if varbinds.is_empty() {
+            return Ok(rows);
+        }
+
+        // 응답은 행 우선 순서 (반복 1의 열들, 반복 2의 열들, ...)
+        let mut progressed = vec![false; columns.len()];
+        for (position, (oid, value)) in varbinds.into_iter().enumerate() {
+            let i = active[position % active.len()];
+            let Some(cursor) = &cursors[i] else {
+                continue;
+            };
+            let prefix = &columns[i];
+            let in_column = oid.len() == prefix.len() + 1 && oid.starts_with(prefix);
+            // OID가 커지지 않으면 잘못 구현된 에이전트 - 무한 반복을 막음
+            if !in_column || value.is_exception() || oid <= *cursor {
+                cursors[i] = None;
+                continue;
+            }
+            let index = oid[prefix.len()];
+            rows.entry(index)
+                .or_insert_with(|| vec![None; columns.len()])[i] = Some(value);
+            cursors[i] = Some(oid);
+            progressed[i] = true;
+        }
+        // 응답에 아무 값도 없던 열은 끝난 것으로 (에이전트가 varbind를 줄여서 보낸 경우)
+        for &i in &active {
+            if !progressed[i] {
+                cursors[i] = None;
+            }
+        }
+    }
+}
+
+// 행에서 i번째 값
+fn number(row: &[Option<Value>], i: usize) -> Option<u64> {
+    row.get(i)?.as_ref()?.as_u64()
+}
+
+fn text(row: &[Option<Value>], i: usize) -> Option<String> {
+    let bytes = row.get(i)?.as_ref()?.as_bytes()?;
+    let text = String::from_utf8_lossy(bytes).trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+/// 에이전트의 포트 목록 (ifIndex 순서)
+/// 이름은 ifName → ifDescr → "if<ifIndex>", 설명에는 ifAlias(포트 설명)를 붙임
+pub fn list_interfaces(session: &mut Session) -> Result<Vec<NetworkInterface>> {
+    let rows = walk(session, &discovery_columns())?;
+    Ok(rows
+        .iter()
+        .map(|(&index, row)| {
+            let name = text(row, 4)
+                .or_else(|| text(row, 0))
+                .unwrap_or_else(|| format!("if{}", index));
+            let description = match text(row, 6) {
+                Some(alias) => format!("{} ({})", name, alias),
+                None => name.clone(),
+            };
+            let mut interface = NetworkInterface::new(index, name, description);
+            if let Some(Some(Value::OctetString(mac))) = row.get(2) {
+                if !mac.is_empty() {
+                    interface.mac_address = NetworkInterface::format_mac_address(mac);
+                }
+            }
+            interface.is_loopback = number(row, 1) == Some(SOFTWARE_LOOPBACK);
+            interface.is_up = number(row, 3) == Some(OPER_STATUS_UP);
+            interface.speed = number(row, 5).unwrap_or(0) * 1_000_000;
+            interface
+        })
+        .collect())
+}
+
+/// 카운터를 한 번 읽어서 indices 순서의 통계로 (행이 없는 포트는 0)
+pub fn read_stats(session: &mut Session, indices: &[u32]) -> Result<Vec<InterfaceStats>> {
+    let rows = walk(session, &counter_columns())?;
+    if !indices.is_empty() && !rows.values().any(|row| row[0].is_some()) {
+        bail!("the agent does not provide 64-bit interface counters (ifXTable)");
+    }
+    let now = Instant::now();
+    Ok(indices
+        .iter()
+        .map(|&index| {
+            let mut stats = InterfaceStats::new(index);
+            stats.timestamp = Some(now);
+            if let Some(row) = rows.get(&index) {
+                stats.bytes_received = number(row, 0).unwrap_or(0);
+                stats.bytes_sent = number(row, 1).unwrap_or(0);
+                stats.packets_received = number(row, 2).unwrap_or(0);
+                stats.packets_sent = number(row, 3).unwrap_or(0);
+                stats.errors_in = number(row, 4).unwrap_or(0);
+                stats.errors_out = number(row, 5).unwrap_or(0);
+                stats.drops_in = number(row, 6).unwrap_or(0);
+                stats.drops_out = number(row, 7).unwrap_or(0);
+            }
+            stats
+        })
+        .collect())
+}
+
+// 매 틱 에이전트에서 카운터를 읽고 직전 값과 비교해 속도를 계산하는 공급원
+struct SnmpSource {
+    session: Session,
+    indices: Vec<u32>,
+    previous: Vec<InterfaceStats>,
+}
+
+impl StatsSource for SnmpSource {
+    fn fetch(&mut self) -> Result<Option<Update>> {
+        let stats = read_stats(&mut self.session, &self.indices)?;
+        let bandwidth: Vec<_> = stats
+            .iter()
+            .zip(&self.previous)
+            .map(|(current, previous)| current.calculate_bandwidth(previous))
+            .collect();
+        let samples = bandwidth
+            .iter()
+            .map(|bandwidth| bandwidth.iter().cloned().collect())
+            .collect();
+        self.previous = stats.clone();
+        // 카운터의 시각은 read_stats에서 기록
+        Ok(Some(Update {
+            stats,
+            bandwidth,
+            samples,
+            ages: Vec::new(),
+        }))
+    }
+}
+
+/// 에이전트의 포트 목록을 읽고, 이후 매 틱 SNMP로 카운터를 읽는 Sampler 생성
+pub fn connect(target: &Target, history_size: usize, interval: Duration) -> Result<Sampler> {
+    let mut session = Session::open(target)?;
+    let interfaces = list_interfaces(&mut session)?;
+    let indices: Vec<u32> = interfaces.iter().map(|i| i.index).collect();
+    let stats = read_stats(&mut session, &indices)?;
+
+    let snapshot = Snapshot {
+        bandwidth: vec![None; interfaces.len()],
+        histories: vec![InterfaceHistory::new(history_size, interval); interfaces.len()],
+        interfaces,
+        stats: stats.clone(),
+        ages: Vec::new(),
+    };
+    let source = SnmpSource {
+        session,
+        indices,
+        previous: stats,
+    };
+    Ok(Sampler::from_snapshot(snapshot, Box::new(source)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snmp::session::tests::fake_agent;
+    use crate::snmp::Security;
+
+    // 포트 두 개(1: Gi0/1 업링크, 2: Gi0/2 다운)의 IF-MIB
+    fn mib() -> Vec<(Oid, Value)> {
+        let mut mib = vec![
+            (
+                column(&IF_TABLE, 2),
+                1,
+                Value::OctetString(b"GigabitEthernet0/1".to_vec()),
+            ),
+            (
+                column(&IF_TABLE, 2),
+                2,
+                Value::OctetString(b"GigabitEthernet0/2".to_vec()),
+            ),
+            (column(&IF_TABLE, 3), 1, Value::Integer(6)),
+            (column(&IF_TABLE, 3), 2, Value::Integer(6)),
+            (
+                column(&IF_TABLE, 6),
+                1,
+                Value::OctetString(vec![0, 0x11, 0x22, 0x33, 0x44, 0x55]),
+            ),
+            (column(&IF_TABLE, 8), 1, Value::Integer(1)),
+            (column(&IF_TABLE, 8), 2, Value::Integer(2)),
+            (column(&IF_TABLE, 14), 1, Value::Counter32(3)),
+            (
+                column(&IF_X_TABLE, 1),
+                1,
+                Value::OctetString(b"Gi0/1".to_vec()),
+            ),
+            (
+                column(&IF_X_TABLE, 1),
+                2,
+                Value::OctetString(b"Gi0/2".to_vec()),
+            ),
+            (column(&IF_X_TABLE, 6), 1, Value::Counter64(5_000_000_000)),
+            (column(&IF_X_TABLE, 6), 2, Value::Counter64(0)),
+            (column(&IF_X_TABLE, 10), 1, Value::Counter64(42)),
+            (column(&IF_X_TABLE, 10), 2, Value::Counter64(0)),
+            (column(&IF_X_TABLE, 15), 1, Value::Gauge32(1000)),
+            (
+                column(&IF_X_TABLE, 18),
+                1,
+                Value::OctetString(b"uplink".to_vec()),
+            ),
+        ]
+        .into_iter()
+        .map(|(mut oid, index, value)| {
+            oid.push(index);
+            (oid, value)
+        })
+        .collect::<Vec<_>>();
+        mib.sort_by(|a, b| a.0.cmp(&b.0));
+        mib
+    }
+
+    #[test]
+    fn test_interfaces_and_counters() {
+        // GetBulk: 요청한 OID마다 다음 OID들을 반복 수만큼 (행 우선)
+        let address = fake_agent("public", |_, oids, repetitions| {
+            let mib = mib();
+            let mut cursors = oids.to_vec();
+            let mut varbinds = Vec::new();
+            for _ in 0..repetitions {
+                for cursor in cursors.iter_mut() {
+                    let next = mib.iter().find(|(oid, _)| oid > cursor);
+                    let (oid, value) = next
+                        .cloned()
+                        .unwrap_or((cursor.clone(), Value::EndOfMibView));
+                    *cursor = oid.clone();
+                    varbinds.push((oid, value));
+                }
+            }
+            varbinds
+        });
+        let target = Target {
+            address,
+            security: Security::Community("public".to_string()),
+        };
+
+        let mut sampler = connect(&target, 10, Duration::from_secs(1)).unwrap();
+        let interfaces = sampler.interfaces();
+        assert_eq!(interfaces.len(), 2);
+        assert_eq!(interfaces[0].index, 1);
+        assert_eq!(interfaces[0].name, "Gi0/1");
+        assert_eq!(interfaces[0].description, "Gi0/1 (uplink)");
+        assert_eq!(interfaces[0].mac_address, "00:11:22:33:44:55");
+        assert!(interfaces[0].is_up);
+        assert_eq!(interfaces[0].speed, 1_000_000_000);
+        assert_eq!(interfaces[1].description, "Gi0/2");
+        assert!(!interfaces[1].is_up);
+
+        let stats = &sampler.all_stats()[0];
+        assert_eq!(stats.bytes_received, 5_000_000_000);
+        assert_eq!(stats.bytes_sent, 42);
+        assert_eq!(stats.errors_in, 3);
+
+        // 다음 틱에는 카운터가 그대로이므로 속도는 0
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        sampler.update().unwrap();
+        let bandwidth = sampler.all_bandwidth()[0].as_ref().unwrap();
+        assert_eq!(bandwidth.download_rate, 0.0);
+        assert_eq!(bandwidth.total_downloaded, 5_000_000_000);
+    }
+}
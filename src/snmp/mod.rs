@@ -0,0 +1,30 @@
+// SNMP로 스위치/라우터의 포트 통계를 읽는 통계 공급원 (nbmon tui --snmp)
+// 외부 SNMP 라이브러리 없이 필요한 부분만 구현 - BER 인코딩, v2c 커뮤니티, v3 USM(SHA/AES), IF-MIB 걷기
+// 결과는 NetworkInterface와 InterfaceStats로 바뀌므로 TUI는 로컬 인터페이스와 똑같이 표시
+
+pub mod ber; // SNMP 메시지의 BER 인코딩/디코딩과 값 타입 (ber.rs)
+pub mod ifmib; // IF-MIB 테이블을 걸어서 인터페이스 목록과 카운터로 바꾸는 공급원 (ifmib.rs)
+pub mod session; // 에이전트와 UDP로 요청/응답을 주고받는 세션 (session.rs)
+pub mod usm; // SNMPv3 사용자 기반 보안 모델 - 키 지역화, 인증, 암호화 (usm.rs)
+
+pub use ifmib::connect;
+
+/// SNMP 에이전트 기본 포트
+pub const DEFAULT_PORT: u16 = 161;
+
+/// 폴링할 에이전트
+#[derive(Debug, Clone)]
+pub struct Target {
+    /// "host:port" 형식 (utils::net::with_default_port로 기본 포트를 붙임)
+    pub address: String,
+    pub security: Security,
+}
+
+/// 에이전트에 보낼 자격 증명
+#[derive(Debug, Clone)]
+pub enum Security {
+    /// SNMPv2c 커뮤니티 문자열
+    Community(String),
+    /// SNMPv3 사용자 (USM)
+    User(usm::User),
+}
@@ -0,0 +1,643 @@
+// 에이전트 하나와 UDP로 요청/응답을 주고받는 세션
+// v2c는 커뮤니티 문자열만 싣고, v3는 엔진 ID를 알아낸 뒤(discovery) USM으로 서명/암호화
+//
+//   v2c 메시지: SEQUENCE { version(1), community, PDU }
+//   v3 메시지:  SEQUENCE { version(3), SEQUENCE { msgID, maxSize, flags, model(3) },
+//                          OCTET STRING(UsmSecurityParameters), ScopedPDU 또는 암호화된 ScopedPDU }
+
+use anyhow::{bail, Context, Result};
+use ring::rand::{SecureRandom, SystemRandom};
+use std::io::ErrorKind;
+use std::net::{ToSocketAddrs, UdpSocket};
+use std::time::{Duration, Instant};
+
+use super::ber::{self, Oid, Reader, Value, OCTET_STRING, SEQUENCE};
+use super::usm::{self, Authenticator, Privacy, SecurityParameters, User};
+use super::{Security, Target};
+
+// PDU 태그 (context-specific, constructed)
+const GET_REQUEST: u8 = 0xa0;
+const GET_BULK_REQUEST: u8 = 0xa5;
+const RESPONSE: u8 = 0xa2;
+const REPORT: u8 = 0xa8;
+
+// 응답을 기다리는 시간과 재전송 횟수
+const TIMEOUT: Duration = Duration::from_secs(1);
+const ATTEMPTS: usize = 3;
+// 받을 수 있는 최대 메시지 크기 (msgMaxSize로 에이전트에 알림)
+const MAX_MESSAGE_SIZE: usize = 65507;
+
+// v3 msgFlags
+const FLAG_AUTH: u8 = 0x01;
+const FLAG_PRIV: u8 = 0x02;
+const FLAG_REPORTABLE: u8 = 0x04;
+
+// usmStats 카운터 (Report PDU로 돌아오는 USM 에러, RFC 3414 5)
+const USM_STATS: [u32; 9] = [1, 3, 6, 1, 6, 3, 15, 1, 1];
+const UNSUPPORTED_SEC_LEVELS: u32 = 1;
+const NOT_IN_TIME_WINDOWS: u32 = 2;
+const UNKNOWN_USER_NAMES: u32 = 3;
+const UNKNOWN_ENGINE_IDS: u32 = 4;
+const WRONG_DIGESTS: u32 = 5;
+const DECRYPTION_ERRORS: u32 = 6;
+
+/// 변수 바인딩 (OID와 값)
+pub type VarBind = (Oid, Value);
+
+// 응답 PDU
+struct Pdu {
+    tag: u8,
+    request_id: i32,
+    error_status: i64,
+    error_index: i64,
+    varbinds: Vec<VarBind>,
+}
+
+impl Pdu {
+    fn encode(tag: u8, request_id: i32, field1: i64, field2: i64, oids: &[Oid]) -> Vec<u8> {
+        let varbinds: Vec<Vec<u8>> = oids
+            .iter()
+            .map(|oid| ber::sequence(&[ber::object_id(oid), ber::null()]))
+            .collect();
+        ber::constructed(
+            tag,
+            &[
+                ber::integer(request_id as i64),
+                ber::integer(field1),
+                ber::integer(field2),
+                ber::sequence(&varbinds),
+            ],
+        )
+    }
+
+    fn decode(reader: &mut Reader) -> Result<Self> {
+        let tlv = reader.read()?;
+        let mut pdu = Reader::new(tlv.content);
+        let request_id = pdu.integer()? as i32;
+        let error_status = pdu.integer()?;
+        let error_index = pdu.integer()?;
+        let mut list = pdu.enter(SEQUENCE)?;
+        let mut varbinds = Vec::new();
+        while !list.is_empty() {
+            let mut varbind = list.enter(SEQUENCE)?;
+            let oid = varbind.object_id()?;
+            varbinds.push((oid, varbind.value()?));
+        }
+        Ok(Self {
+            tag: tlv.tag,
+            request_id,
+            error_status,
+            error_index,
+            varbinds,
+        })
+    }
+}
+
+// 에이전트의 SNMP 엔진 (v3 discovery로 알아냄)
+struct Engine {
+    id: Vec<u8>,
+    boots: u32,
+    time: u32,
+    /// time을 받은 시각 (지금의 엔진 시간 = time + 지난 시간)
+    received: Instant,
+}
+
+impl Engine {
+    fn now(&self) -> u32 {
+        self.time
+            .saturating_add(self.received.elapsed().as_secs() as u32)
+    }
+}
+
+// v3 상태 - 엔진을 알아낸 뒤에 지역화한 키를 만듦
+struct UsmState {
+    user: User,
+    engine: Option<Engine>,
+    auth: Option<Authenticator>,
+    privacy: Option<Privacy>,
+    msg_id: i32,
+    salt: u64,
+}
+
+enum Mode {
+    Community(Vec<u8>),
+    Usm(Box<UsmState>),
+}
+
+// 한 번의 주고받기 결과
+enum Reply {
+    Pdu(Pdu),
+    /// v3 Report (usmStats OID 번호, 보안 파라미터, MAC을 확인한 Report인지)
+    Report(Option<u32>, SecurityParameters, bool),
+}
+
+/// 에이전트 하나와의 세션
+pub struct Session {
+    socket: UdpSocket,
+    address: String,
+    mode: Mode,
+    request_id: i32,
+}
+
+impl Session {
+    /// UDP 소켓을 열고 에이전트 주소에 연결 (v3는 첫 요청 때 엔진을 알아냄)
+    pub fn open(target: &Target) -> Result<Self> {
+        let address = target
+            .address
+            .to_socket_addrs()
+            .with_context(|| format!("Failed to resolve {}", target.address))?
+            .next()
+            .with_context(|| format!("Failed to resolve {}", target.address))?;
+        let local = if address.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        };
+        let socket = UdpSocket::bind(local).context("Failed to open UDP socket")?;
+        socket
+            .connect(address)
+            .with_context(|| format!("Failed to connect to {}", target.address))?;
+        socket.set_read_timeout(Some(TIMEOUT))?;
+
+        let mode = match &target.security {
+            Security::Community(community) => Mode::Community(community.as_bytes().to_vec()),
+            Security::User(user) => {
+                user.validate()?;
+                Mode::Usm(Box::new(UsmState {
+                    user: user.clone(),
+                    engine: None,
+                    auth: None,
+                    privacy: None,
+                    msg_id: random_u32() as i32 & 0x7fff_ffff,
+                    salt: random_u64(),
+                }))
+            }
+        };
+        Ok(Self {
+            socket,
+            address: target.address.clone(),
+            mode,
+            request_id: random_u32() as i32 & 0x7fff_ffff,
+        })
+    }
+
+    /// GetRequest
+    pub fn get(&mut self, oids: &[Oid]) -> Result<Vec<VarBind>> {
+        self.request(GET_REQUEST, 0, 0, oids)
+    }
+
+    /// GetBulkRequest - 각 OID 다음의 값을 max_repetitions개까지 (열 여러 개를 함께 걸을 때 사용)
+    pub fn get_bulk(&mut self, oids: &[Oid], max_repetitions: u32) -> Result<Vec<VarBind>> {
+        self.request(GET_BULK_REQUEST, 0, max_repetitions as i64, oids)
+    }
+
+    fn request(&mut self, tag: u8, field1: i64, field2: i64, oids: &[Oid]) -> Result<Vec<VarBind>> {
+        if let Mode::Usm(state) = &self.mode {
+            if state.engine.is_none() {
+                self.discover()?;
+            }
+        }
+
+        // 시간 창을 벗어났다는 Report는 엔진 시간을 맞추고 한 번 더 시도
+        let mut resynced = false;
+        loop {
+            self.request_id = self.request_id.wrapping_add(1) & 0x7fff_ffff;
+            let pdu = Pdu::encode(tag, self.request_id, field1, field2, oids);
+            match self.exchange(&pdu, false)? {
+                Reply::Pdu(response) => {
+                    if response.error_status != 0 {
+                        bail!(
+                            "{} returned {} (index {})",
+                            self.address,
+                            error_status_name(response.error_status),
+                            response.error_index
+                        );
+                    }
+                    return Ok(response.varbinds);
+                }
+                // 시간 창 Report는 authNoPriv로 옴 (RFC 3414 3.2 7단계)
+                // 인증되지 않은 Report의 boots/time으로 시계를 맞추면 누구나 시계를 바꿀 수 있으므로 실패로 처리
+                Reply::Report(Some(NOT_IN_TIME_WINDOWS), params, true) if !resynced => {
+                    self.synchronize(&params);
+                    resynced = true;
+                }
+                Reply::Report(report, ..) => bail!(report_message(report)),
+            }
+        }
+    }
+
+    // 빈 GetRequest를 보내 Report로 엔진 ID, boots, time을 받고 키를 지역화
+    fn discover(&mut self) -> Result<()> {
+        self.request_id = self.request_id.wrapping_add(1) & 0x7fff_ffff;
+        let pdu = Pdu::encode(GET_REQUEST, self.request_id, 0, 0, &[]);
+        let params = match self.exchange(&pdu, true)? {
+            Reply::Report(_, params, _) => params,
+            Reply::Pdu(_) => bail!("{} did not report its SNMP engine ID", self.address),
+        };
+        if params.engine_id.is_empty() {
+            bail!("{} did not report its SNMP engine ID", self.address);
+        }
+
+        let Mode::Usm(state) = &mut self.mode else {
+            return Ok(());
+        };
+        if let Some((protocol, passphrase)) = &state.user.auth {
+            let key = usm::localize_key(*protocol, passphrase.as_bytes(), &params.engine_id);
+            state.auth = Some(Authenticator::new(*protocol, &key));
+            if let Some(passphrase) = &state.user.privacy {
+                // AES 키도 인증 프로토콜의 해시로 지역화 (RFC 3826 1.2)
+                let key = usm::localize_key(*protocol, passphrase.as_bytes(), &params.engine_id);
+                state.privacy = Some(Privacy::new(&key)?);
+            }
+        }
+        state.engine = Some(Engine {
+            id: params.engine_id,
+            boots: params.boots,
+            time: params.time,
+            received: Instant::now(),
+        });
+        Ok(())
+    }
+
+    // 에이전트가 알려준 boots/time으로 엔진 시간 갱신
+    fn synchronize(&mut self, params: &SecurityParameters) {
+        if let Mode::Usm(state) = &mut self.mode {
+            if let Some(engine) = &mut state.engine {
+                engine.boots = params.boots;
+                engine.time = params.time;
+                engine.received = Instant::now();
+            }
+        }
+    }
+
+    // 메시지를 보내고 같은 요청의 응답을 받음 (응답이 없으면 다시 보냄)
+    fn exchange(&mut self, pdu: &[u8], discovery: bool) -> Result<Reply> {
+        let mut buffer = vec![0u8; MAX_MESSAGE_SIZE];
+        for _ in 0..ATTEMPTS {
+            let (message, msg_id) = self.encode_message(pdu, discovery)?;
+            self.socket
+                .send(&message)
+                .with_context(|| format!("Failed to send to {}", self.address))?;
+
+            let deadline = Instant::now() + TIMEOUT;
+            while Instant::now() < deadline {
+                let len = match self.socket.recv(&mut buffer) {
+                    Ok(len) => len,
+                    Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                        break
+                    }
+                    // ICMP port unreachable 등
+                    Err(e) => {
+                        return Err(e).with_context(|| format!("Failed to reach {}", self.address))
+                    }
+                };
+                // 형식이 잘못됐거나 다른 요청의 응답이면 무시하고 계속 기다림
+                match self.decode_message(&buffer[..len], msg_id) {
+                    Ok(Some(reply)) => return Ok(reply),
+                    Ok(None) => {}
+                    Err(e) => {
+                        crate::debug_log!("Ignoring SNMP message: {}", e);
+                    }
+                }
+            }
+        }
+        bail!(
+            "no response from {} (check the address, community or credentials)",
+            self.address
+        )
+    }
+
+    fn encode_message(&mut self, pdu: &[u8], discovery: bool) -> Result<(Vec<u8>, i32)> {
+        let state = match &mut self.mode {
+            Mode::Community(community) => {
+                let message = ber::sequence(&[
+                    ber::integer(1), // version-2c
+                    ber::octet_string(community),
+                    pdu.to_vec(),
+                ]);
+                return Ok((message, 0));
+            }
+            Mode::Usm(state) => state,
+        };
+        state.msg_id = state.msg_id.wrapping_add(1) & 0x7fff_ffff;
+
+        // discovery는 인증 없이 빈 사용자와 엔진 ID로
+        let engine = state.engine.as_ref().filter(|_| !discovery);
+        let auth = state.auth.as_ref().filter(|_| engine.is_some());
+        let privacy = state.privacy.as_ref().filter(|_| engine.is_some());
+        let mut flags = FLAG_REPORTABLE;
+        if auth.is_some() {
+            flags |= FLAG_AUTH;
+        }
+        if privacy.is_some() {
+            flags |= FLAG_PRIV;
+        }
+
+        let engine_id = engine.map(|e| e.id.clone()).unwrap_or_default();
+        let (boots, time) = engine.map(|e| (e.boots, e.now())).unwrap_or((0, 0));
+        let scoped = ber::sequence(&[
+            ber::octet_string(&engine_id),
+            ber::octet_string(b""), // contextName
+            pdu.to_vec(),
+        ]);
+        let mut params = SecurityParameters {
+            engine_id: engine_id.clone(),
+            boots,
+            time,
+            user: if discovery {
+                Vec::new()
+            } else {
+                state.user.name.as_bytes().to_vec()
+            },
+            auth: auth.map(Authenticator::placeholder).unwrap_or_default(),
+            privacy: Vec::new(),
+        };
+        let data = match privacy {
+            Some(privacy) => {
+                state.salt = state.salt.wrapping_add(1);
+                params.privacy = state.salt.to_be_bytes().to_vec();
+                ber::octet_string(&privacy.encrypt(boots, time, &params.privacy, &scoped)?)
+            }
+            None => scoped,
+        };
+
+        let mut message = ber::sequence(&[
+            ber::integer(3),
+            ber::sequence(&[
+                ber::integer(state.msg_id as i64),
+                ber::integer(MAX_MESSAGE_SIZE as i64),
+                ber::octet_string(&[flags]),
+                ber::integer(3), // USM
+            ]),
+            ber::octet_string(&params.encode()),
+            data,
+        ]);
+        if let Some(auth) = auth {
+            auth.sign(&mut message)?;
+        }
+        Ok((message, state.msg_id))
+    }
+
+    // 기다리던 응답이면 Some, 다른 요청의 응답이면 None
+    fn decode_message(&mut self, message: &[u8], msg_id: i32) -> Result<Option<Reply>> {
+        let mut outer = Reader::new(message);
+        let mut reader = outer.enter(SEQUENCE)?;
+        let version = reader.integer()?;
+
+        let state = match &mut self.mode {
+            Mode::Community(_) => {
+                if version != 1 {
+                    bail!("unexpected SNMP version {}", version);
+                }
+                reader.octet_string()?; // community
+                let pdu = Pdu::decode(&mut reader)?;
+                if pdu.tag != RESPONSE || pdu.request_id != self.request_id {
+                    return Ok(None);
+                }
+                return Ok(Some(Reply::Pdu(pdu)));
+            }
+            Mode::Usm(state) => state,
+        };
+        if version != 3 {
+            bail!("unexpected SNMP version {}", version);
+        }
+
+        let mut global = reader.enter(SEQUENCE)?;
+        if global.integer()? as i32 != msg_id {
+            return Ok(None);
+        }
+        global.integer()?; // msgMaxSize
+        let flags = global.octet_string()?.first().copied().unwrap_or(0);
+        reader.expect(OCTET_STRING)?; // msgSecurityParameters (아래에서 따로 읽음)
+        let (params, _) = usm::security_parameters(message)?;
+
+        // 인증된 메시지는 MAC을 확인 (discovery의 Report는 인증 없이 옴)
+        if flags & FLAG_AUTH != 0 {
+            let auth = state
+                .auth
+                .as_ref()
+                .context("unexpected authenticated message")?;
+            if !auth.verify(message)? {
+                bail!("response failed authentication");
+            }
+        }
+
+        let scoped = if flags & FLAG_PRIV != 0 {
+            let privacy = state
+                .privacy
+                .as_ref()
+                .context("unexpected encrypted message")?;
+            let encrypted = reader.octet_string()?;
+            privacy.decrypt(params.boots, params.time, &params.privacy, encrypted)?
+        } else {
+            let tlv = reader.expect(SEQUENCE)?;
+            ber::tlv(SEQUENCE, tlv.content)
+        };
+        let mut scoped_reader = Reader::new(&scoped);
+        let mut scoped = scoped_reader.enter(SEQUENCE)?;
+        scoped.octet_string()?; // contextEngineID
+        scoped.octet_string()?; // contextName
+        let pdu = Pdu::decode(&mut scoped)?;
+
+        // 응답의 보안 수준은 요청과 같아야 함 (RFC 3414 3.2)
+        // 인증 없이 받아도 되는 것은 Report뿐 (시간 동기화에는 인증된 Report만 씀) - 서명 없는 평문 응답으로 인증을 건너뛸 수 없음
+        let downgraded = (state.auth.is_some() && flags & FLAG_AUTH == 0)
+            || (state.privacy.is_some() && flags & FLAG_PRIV == 0);
+        if downgraded && pdu.tag != REPORT {
+            bail!("response failed authentication");
+        }
+
+        // 인증된 응답의 엔진 시간으로 시계를 맞춤
+        if flags & FLAG_AUTH != 0 {
+            if let Some(engine) = &mut state.engine {
+                engine.boots = params.boots;
+                engine.time = params.time;
+                engine.received = Instant::now();
+            }
+        }
+
+        match pdu.tag {
+            REPORT => {
+                let report = pdu.varbinds.first().and_then(|(oid, _)| {
+                    (oid.len() == USM_STATS.len() + 2 && oid.starts_with(&USM_STATS))
+                        .then(|| oid[USM_STATS.len()])
+                });
+                Ok(Some(Reply::Report(report, params, flags & FLAG_AUTH != 0)))
+            }
+            RESPONSE if pdu.request_id == self.request_id => Ok(Some(Reply::Pdu(pdu))),
+            _ => Ok(None),
+        }
+    }
+}
+
+// Report로 돌아온 USM 에러의 설명
+fn report_message(report: Option<u32>) -> &'static str {
+    match report {
+        Some(UNSUPPORTED_SEC_LEVELS) => "the agent does not support this security level",
+        Some(NOT_IN_TIME_WINDOWS) => "the agent's engine time is out of sync",
+        Some(UNKNOWN_USER_NAMES) => "unknown SNMPv3 user",
+        Some(UNKNOWN_ENGINE_IDS) => "unknown SNMP engine ID",
+        Some(WRONG_DIGESTS) => "wrong SNMPv3 authentication passphrase or protocol",
+        Some(DECRYPTION_ERRORS) => "wrong SNMPv3 privacy passphrase",
+        _ => "the agent rejected the request",
+    }
+}
+
+// error-status 이름 (RFC 3416)
+fn error_status_name(status: i64) -> String {
+    let name = match status {
+        1 => "tooBig",
+        2 => "noSuchName",
+        3 => "badValue",
+        4 => "readOnly",
+        5 => "genErr",
+        6 => "noAccess",
+        16 => "authorizationError",
+        _ => return format!("error {}", status),
+    };
+    name.to_string()
+}
+
+fn random_u32() -> u32 {
+    let mut bytes = [0u8; 4];
+    let _ = SystemRandom::new().fill(&mut bytes);
+    u32::from_be_bytes(bytes)
+}
+
+fn random_u64() -> u64 {
+    let mut bytes = [0u8; 8];
+    let _ = SystemRandom::new().fill(&mut bytes);
+    u64::from_be_bytes(bytes)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::snmp::ber::INTEGER;
+    use std::thread;
+
+    /// 테스트용 v2c 에이전트 - 커뮤니티가 맞으면 요청마다 answer의 결과로 응답
+    pub(crate) fn fake_agent<F>(community: &'static str, mut answer: F) -> String
+    where
+        F: FnMut(u8, &[Oid], i64) -> Vec<VarBind> + Send + 'static,
+    {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let address = socket.local_addr().unwrap().to_string();
+        thread::spawn(move || {
+            let mut buffer = [0u8; 65535];
+            while let Ok((len, peer)) = socket.recv_from(&mut buffer) {
+                let mut outer = Reader::new(&buffer[..len]);
+                let mut message = outer.enter(SEQUENCE).unwrap();
+                message.expect(INTEGER).unwrap();
+                if message.octet_string().unwrap() != community.as_bytes() {
+                    continue;
+                }
+                let request = Pdu::decode(&mut message).unwrap();
+                let oids: Vec<Oid> = request.varbinds.into_iter().map(|(oid, _)| oid).collect();
+                let varbinds: Vec<Vec<u8>> = answer(request.tag, &oids, request.error_index)
+                    .iter()
+                    .map(|(oid, value)| ber::sequence(&[ber::object_id(oid), value.encode()]))
+                    .collect();
+                let response = ber::sequence(&[
+                    ber::integer(1),
+                    ber::octet_string(community.as_bytes()),
+                    ber::constructed(
+                        RESPONSE,
+                        &[
+                            ber::integer(request.request_id as i64),
+                            ber::integer(0),
+                            ber::integer(0),
+                            ber::sequence(&varbinds),
+                        ],
+                    ),
+                ]);
+                socket.send_to(&response, peer).unwrap();
+            }
+        });
+        address
+    }
+
+    #[test]
+    fn test_get_v2c() {
+        let sys_descr: Oid = vec![1, 3, 6, 1, 2, 1, 1, 1, 0];
+        let address = fake_agent("public", |tag, oids, _| {
+            assert_eq!(tag, GET_REQUEST);
+            oids.iter()
+                .map(|oid| (oid.clone(), Value::OctetString(b"test switch".to_vec())))
+                .collect()
+        });
+        let target = Target {
+            address,
+            security: Security::Community("public".to_string()),
+        };
+        let mut session = Session::open(&target).unwrap();
+        let varbinds = session.get(std::slice::from_ref(&sys_descr)).unwrap();
+        assert_eq!(varbinds.len(), 1);
+        assert_eq!(varbinds[0].0, sys_descr);
+        assert_eq!(varbinds[0].1.as_bytes(), Some(&b"test switch"[..]));
+    }
+
+    #[test]
+    fn test_v3_rejects_unauthenticated_response() {
+        let user = User {
+            name: "monitor".to_string(),
+            auth: Some((usm::AuthProtocol::Sha1, "authpass123".to_string())),
+            privacy: None,
+        };
+        let target = Target {
+            address: "127.0.0.1:161".to_string(),
+            security: Security::User(user),
+        };
+        let mut session = Session::open(&target).unwrap();
+        let engine_id = b"\x80\x00\x1f\x88\x80engine".to_vec();
+        if let Mode::Usm(state) = &mut session.mode {
+            let key = usm::localize_key(usm::AuthProtocol::Sha1, b"authpass123", &engine_id);
+            state.auth = Some(Authenticator::new(usm::AuthProtocol::Sha1, &key));
+            state.engine = Some(Engine {
+                id: engine_id.clone(),
+                boots: 1,
+                time: 100,
+                received: Instant::now(),
+            });
+        }
+
+        // 인증 플래그 없는 평문 메시지 (위조된 응답 또는 discovery Report)
+        let plaintext = |tag: u8, request_id: i32| {
+            let params = SecurityParameters {
+                engine_id: engine_id.clone(),
+                boots: 1,
+                time: 100,
+                user: b"monitor".to_vec(),
+                auth: Vec::new(),
+                privacy: Vec::new(),
+            };
+            ber::sequence(&[
+                ber::integer(3),
+                ber::sequence(&[
+                    ber::integer(7),
+                    ber::integer(MAX_MESSAGE_SIZE as i64),
+                    ber::octet_string(&[0]),
+                    ber::integer(3),
+                ]),
+                ber::octet_string(&params.encode()),
+                ber::sequence(&[
+                    ber::octet_string(&engine_id),
+                    ber::octet_string(b""),
+                    Pdu::encode(tag, request_id, 0, 0, &[]),
+                ]),
+            ])
+        };
+
+        let request_id = session.request_id;
+        let error = session
+            .decode_message(&plaintext(RESPONSE, request_id), 7)
+            .err()
+            .unwrap();
+        assert_eq!(error.to_string(), "response failed authentication");
+        // 평문 Report는 받지만 인증되지 않은 것으로 표시 (시간 동기화에 쓰지 않음)
+        assert!(matches!(
+            session.decode_message(&plaintext(REPORT, 0), 7),
+            Ok(Some(Reply::Report(_, _, false)))
+        ));
+    }
+}
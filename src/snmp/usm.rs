@@ -0,0 +1,327 @@
+// SNMPv3 사용자 기반 보안 모델 (USM, RFC 3414)
+// 비밀번호에서 에이전트별 키를 만들고(키 지역화), 메시지에 HMAC을 붙이고, 필요하면 AES로 암호화
+//
+//   인증: usmHMACSHAAuthProtocol (HMAC-SHA-1, 12바이트) 또는 usmHMAC192SHA256AuthProtocol (RFC 7860, 24바이트)
+//   암호화: usmAesCfb128Protocol (RFC 3826) - IV = engineBoots || engineTime || salt
+//
+// MD5 인증과 DES 암호화는 지원하지 않음 (ring이 제공하지 않고, 이미 권장되지 않는 방식)
+
+use aes::Aes128;
+use anyhow::{bail, Context, Result};
+use cfb_mode::cipher::{AsyncStreamCipher, KeyIvInit};
+use ring::{digest, hmac};
+use std::ops::Range;
+
+use super::ber::{self, Reader, INTEGER, OCTET_STRING, SEQUENCE};
+
+/// 비밀번호 최소 길이 (RFC 3414 11.2)
+const MIN_PASSPHRASE_LEN: usize = 8;
+// 키를 만들 때 비밀번호를 반복해서 채우는 길이 (1MB)
+const PASSPHRASE_EXPANSION: usize = 1024 * 1024;
+
+/// 인증 프로토콜
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthProtocol {
+    Sha1,
+    Sha256,
+}
+
+impl AuthProtocol {
+    /// --snmp-auth 값 (sha, sha256)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "sha" | "sha1" => Some(Self::Sha1),
+            "sha256" | "sha-256" => Some(Self::Sha256),
+            _ => None,
+        }
+    }
+
+    fn digest(self) -> &'static digest::Algorithm {
+        match self {
+            Self::Sha1 => &digest::SHA1_FOR_LEGACY_USE_ONLY,
+            Self::Sha256 => &digest::SHA256,
+        }
+    }
+
+    fn hmac(self) -> hmac::Algorithm {
+        match self {
+            Self::Sha1 => hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY,
+            Self::Sha256 => hmac::HMAC_SHA256,
+        }
+    }
+
+    /// 메시지에 싣는 MAC 길이 (HMAC 결과의 앞부분)
+    pub fn mac_len(self) -> usize {
+        match self {
+            Self::Sha1 => 12,
+            Self::Sha256 => 24,
+        }
+    }
+}
+
+/// v3 사용자와 보안 수준 (인증 없이, 인증만, 인증 + 암호화)
+#[derive(Debug, Clone)]
+pub struct User {
+    pub name: String,
+    pub auth: Option<(AuthProtocol, String)>,
+    /// AES-128 암호화 비밀번호 (인증이 있어야 함)
+    pub privacy: Option<String>,
+}
+
+impl User {
+    /// 비밀번호 길이와 보안 수준 조합 확인
+    pub fn validate(&self) -> Result<()> {
+        if self.privacy.is_some() && self.auth.is_none() {
+            bail!("SNMPv3 privacy requires an authentication passphrase");
+        }
+        let passphrases = self.auth.iter().map(|(_, p)| p).chain(&self.privacy);
+        for passphrase in passphrases {
+            if passphrase.len() < MIN_PASSPHRASE_LEN {
+                bail!(
+                    "SNMPv3 passphrases must be at least {} characters",
+                    MIN_PASSPHRASE_LEN
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 에이전트의 엔진 ID로 지역화한 키 (RFC 3414 A.2)
+/// Ku = H(비밀번호를 1MB까지 반복), Kul = H(Ku || engineID || Ku)
+pub fn localize_key(protocol: AuthProtocol, passphrase: &[u8], engine_id: &[u8]) -> Vec<u8> {
+    let mut context = digest::Context::new(protocol.digest());
+    if !passphrase.is_empty() {
+        let mut block = [0u8; 64];
+        let mut index = 0;
+        for _ in 0..PASSPHRASE_EXPANSION / block.len() {
+            for b in block.iter_mut() {
+                *b = passphrase[index % passphrase.len()];
+                index += 1;
+            }
+            context.update(&block);
+        }
+    }
+    let ku = context.finish();
+
+    let mut context = digest::Context::new(protocol.digest());
+    context.update(ku.as_ref());
+    context.update(engine_id);
+    context.update(ku.as_ref());
+    context.finish().as_ref().to_vec()
+}
+
+/// msgSecurityParameters 안의 UsmSecurityParameters
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SecurityParameters {
+    pub engine_id: Vec<u8>,
+    pub boots: u32,
+    pub time: u32,
+    pub user: Vec<u8>,
+    /// 메시지의 MAC (서명 전에는 0으로 채운 자리)
+    pub auth: Vec<u8>,
+    /// AES salt (8바이트)
+    pub privacy: Vec<u8>,
+}
+
+impl SecurityParameters {
+    pub fn encode(&self) -> Vec<u8> {
+        ber::sequence(&[
+            ber::octet_string(&self.engine_id),
+            ber::integer(self.boots as i64),
+            ber::integer(self.time as i64),
+            ber::octet_string(&self.user),
+            ber::octet_string(&self.auth),
+            ber::octet_string(&self.privacy),
+        ])
+    }
+
+    pub fn decode(reader: &mut Reader) -> Result<(Self, Range<usize>)> {
+        let mut usm = reader.enter(SEQUENCE)?;
+        let engine_id = usm.octet_string()?.to_vec();
+        let boots = usm.integer()?;
+        let time = usm.integer()?;
+        let user = usm.octet_string()?.to_vec();
+        let auth = usm.expect(OCTET_STRING)?;
+        let privacy = usm.octet_string()?.to_vec();
+        let params = Self {
+            engine_id,
+            boots: u32::try_from(boots).unwrap_or(0),
+            time: u32::try_from(time).unwrap_or(0),
+            user,
+            auth: auth.content.to_vec(),
+            privacy,
+        };
+        Ok((params, auth.offset..auth.offset + auth.content.len()))
+    }
+}
+
+/// v3 메시지에서 보안 파라미터와 MAC 자리 (메시지 안의 위치)
+pub fn security_parameters(message: &[u8]) -> Result<(SecurityParameters, Range<usize>)> {
+    let mut outer = Reader::new(message);
+    let mut message = outer.enter(SEQUENCE)?;
+    message.expect(INTEGER)?; // msgVersion
+    message.enter(SEQUENCE)?; // msgGlobalData
+    let mut params = message.enter(OCTET_STRING)?;
+    SecurityParameters::decode(&mut params).context("invalid USM security parameters")
+}
+
+/// 인증 키와 프로토콜
+pub struct Authenticator {
+    protocol: AuthProtocol,
+    key: hmac::Key,
+}
+
+impl Authenticator {
+    pub fn new(protocol: AuthProtocol, localized_key: &[u8]) -> Self {
+        Self {
+            protocol,
+            key: hmac::Key::new(protocol.hmac(), localized_key),
+        }
+    }
+
+    /// 서명 전 MAC 자리에 넣을 0
+    pub fn placeholder(&self) -> Vec<u8> {
+        vec![0; self.protocol.mac_len()]
+    }
+
+    /// MAC 자리를 0으로 둔 메시지 전체의 HMAC을 그 자리에 기록
+    pub fn sign(&self, message: &mut [u8]) -> Result<()> {
+        let (_, range) = security_parameters(message)?;
+        if range.len() != self.protocol.mac_len() {
+            bail!("authentication parameter has the wrong length");
+        }
+        let mac = hmac::sign(&self.key, message);
+        message[range.clone()].copy_from_slice(&mac.as_ref()[..range.len()]);
+        Ok(())
+    }
+
+    /// 받은 메시지의 MAC 확인
+    pub fn verify(&self, message: &[u8]) -> Result<bool> {
+        let (params, range) = security_parameters(message)?;
+        if range.len() != self.protocol.mac_len() {
+            return Ok(false);
+        }
+        let mut zeroed = message.to_vec();
+        zeroed[range.clone()].fill(0);
+        let mac = hmac::sign(&self.key, &zeroed);
+        // 상수 시간 비교
+        let expected = &mac.as_ref()[..range.len()];
+        let difference = expected
+            .iter()
+            .zip(&params.auth)
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        Ok(difference == 0)
+    }
+}
+
+/// AES-128 암호화 키 (지역화한 키의 앞 16바이트)
+pub struct Privacy {
+    key: [u8; 16],
+}
+
+impl Privacy {
+    pub fn new(localized_key: &[u8]) -> Result<Self> {
+        let key: [u8; 16] = localized_key
+            .get(..16)
+            .and_then(|key| key.try_into().ok())
+            .context("privacy key is too short")?;
+        Ok(Self { key })
+    }
+
+    // IV = engineBoots(4) || engineTime(4) || salt(8) (RFC 3826 3.1.2.1)
+    fn iv(boots: u32, time: u32, salt: &[u8]) -> Result<[u8; 16]> {
+        if salt.len() != 8 {
+            bail!("privacy parameter must be 8 bytes");
+        }
+        let mut iv = [0u8; 16];
+        iv[..4].copy_from_slice(&boots.to_be_bytes());
+        iv[4..8].copy_from_slice(&time.to_be_bytes());
+        iv[8..].copy_from_slice(salt);
+        Ok(iv)
+    }
+
+    /// AES-CFB-128 암호화 (패딩 없음, 길이 그대로)
+    pub fn encrypt(&self, boots: u32, time: u32, salt: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+        let iv = Self::iv(boots, time, salt)?;
+        let mut buffer = data.to_vec();
+        cfb_mode::Encryptor::<Aes128>::new(&self.key.into(), &iv.into()).encrypt(&mut buffer);
+        Ok(buffer)
+    }
+
+    pub fn decrypt(&self, boots: u32, time: u32, salt: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+        let iv = Self::iv(boots, time, salt)?;
+        let mut buffer = data.to_vec();
+        cfb_mode::Decryptor::<Aes128>::new(&self.key.into(), &iv.into()).decrypt(&mut buffer);
+        Ok(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_key_localization_and_signing() {
+        // RFC 3414 A.3.2 (SHA-1, 비밀번호 "maplesyrup")
+        let engine_id = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2];
+        let key = localize_key(AuthProtocol::Sha1, b"maplesyrup", &engine_id);
+        assert_eq!(hex(&key), "6695febc9288e36282235fc7151f128497b38f3f");
+
+        // MAC 자리를 찾아 서명하고, 한 바이트라도 바뀌면 검증 실패
+        let auth = Authenticator::new(AuthProtocol::Sha256, &key);
+        let params = SecurityParameters {
+            engine_id: engine_id.to_vec(),
+            boots: 1,
+            time: 100,
+            user: b"monitor".to_vec(),
+            auth: auth.placeholder(),
+            privacy: Vec::new(),
+        };
+        let mut message = ber::sequence(&[
+            ber::integer(3),
+            ber::sequence(&[ber::integer(1)]),
+            ber::octet_string(&params.encode()),
+            ber::null(),
+        ]);
+        auth.sign(&mut message).unwrap();
+        assert!(auth.verify(&message).unwrap());
+        let (signed, range) = security_parameters(&message).unwrap();
+        assert_eq!(range.len(), 24);
+        assert_eq!(signed.user, b"monitor");
+        let last = message.len() - 1;
+        message[last] ^= 1;
+        assert!(!auth.verify(&message).unwrap());
+    }
+
+    #[test]
+    fn test_privacy_iv_and_cfb() {
+        // NIST SP 800-38A F.3.13 (CFB128-AES128) - IV 000102..0f를 boots, time, salt로 나눠 넣음
+        let unhex = |text: &str| -> Vec<u8> {
+            (0..text.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&text[i..i + 2], 16).unwrap())
+                .collect()
+        };
+        let privacy = Privacy::new(&unhex("2b7e151628aed2a6abf7158809cf4f3c00000000")).unwrap();
+        let salt = unhex("08090a0b0c0d0e0f");
+        let plain = unhex("6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51");
+        let cipher = privacy
+            .encrypt(0x00010203, 0x04050607, &salt, &plain)
+            .unwrap();
+        assert_eq!(
+            hex(&cipher),
+            "3b3fd92eb72dad20333449f8e83cfb4ac8a64537a0b3a93fcde3cdad9f1ce58b"
+        );
+        // 마지막 블록이 일부만 있어도 길이 그대로 복호화
+        let partial = privacy
+            .decrypt(0x00010203, 0x04050607, &salt, &cipher[..20])
+            .unwrap();
+        assert_eq!(partial, plain[..20]);
+        assert!(privacy.encrypt(0, 0, &salt[..4], &plain).is_err());
+    }
+}
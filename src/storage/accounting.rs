@@ -0,0 +1,507 @@
+// 인터페이스별 트래픽 집계 데이터베이스 (vnstat 스타일)
+// 커널 카운터의 변화량을 시간/일/월 단위로 누적하여 JSON 파일에 저장
+// 재시작/재부팅 후에도 마지막으로 본 카운터와 비교(reconcile)하여 누락 없이 이어서 집계
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::network::{interface::NetworkInterface, sampler::Sampler, stats::InterfaceStats};
+use crate::storage::rates::{MinuteAggregator, RateLog, RATES_DIR};
+use crate::utils::time::{self, DateTime};
+
+/// 데이터 디렉터리 안의 집계 파일 이름
+pub const ACCOUNTING_FILE: &str = "accounting.json";
+
+// 파일 형식 버전 (형식이 바뀌면 증가)
+const DB_VERSION: u32 = 1;
+
+// 단위별 보관 기간 (버킷 수)
+const HOURLY_RETENTION: usize = 24 * 7; // 7일
+const DAILY_RETENTION: usize = 365 * 2; // 2년
+const MONTHLY_RETENTION: usize = 12 * 10; // 10년
+
+/// 실행 중인 TUI가 디스크에 집계를 반영하는 간격
+pub const FLUSH_INTERVAL_SECS: u64 = 60;
+
+/// 수신/송신 바이트 합계
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Traffic {
+    pub rx: u64,
+    pub tx: u64,
+}
+
+impl Traffic {
+    pub fn new(rx: u64, tx: u64) -> Self {
+        Self { rx, tx }
+    }
+
+    pub fn total(&self) -> u64 {
+        self.rx.saturating_add(self.tx)
+    }
+
+    fn add(&mut self, other: Traffic) {
+        self.rx = self.rx.saturating_add(other.rx);
+        self.tx = self.tx.saturating_add(other.tx);
+    }
+
+    // 방향별로 더 큰 값을 유지 (가져오기 병합용)
+    fn max(self, other: Traffic) -> Traffic {
+        Traffic::new(self.rx.max(other.rx), self.tx.max(other.tx))
+    }
+}
+
+/// 집계 단위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    Hourly,
+    Daily,
+    Monthly,
+}
+
+impl Period {
+    pub const ALL: [Period; 3] = [Period::Hourly, Period::Daily, Period::Monthly];
+
+    /// 표 머리글에 쓰는 이름
+    pub fn label(self) -> &'static str {
+        match self {
+            Period::Hourly => "hour",
+            Period::Daily => "day",
+            Period::Monthly => "month",
+        }
+    }
+
+    // Unix 시간이 속하는 버킷 키
+    fn key(self, unix: i64) -> String {
+        let dt = DateTime::local(unix);
+        match self {
+            Period::Hourly => dt.hour_key(),
+            Period::Daily => dt.day_key(),
+            Period::Monthly => dt.month_key(),
+        }
+    }
+
+    fn retention(self) -> usize {
+        match self {
+            Period::Hourly => HOURLY_RETENTION,
+            Period::Daily => DAILY_RETENTION,
+            Period::Monthly => MONTHLY_RETENTION,
+        }
+    }
+}
+
+/// 하나의 인터페이스에 대한 집계 기록
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InterfaceAccount {
+    /// 마지막으로 본 커널 수신 카운터
+    pub last_rx: u64,
+    /// 마지막으로 본 커널 송신 카운터
+    pub last_tx: u64,
+    /// 카운터를 읽었을 때의 부팅 ID (재부팅 감지용, 지원되지 않는 OS에서는 None)
+    #[serde(default)]
+    pub boot_id: Option<String>,
+    /// 마지막 갱신 시각 (Unix 초)
+    pub last_updated: i64,
+    /// 시간별 합계 ("YYYY-MM-DD HH:00" → 바이트)
+    #[serde(default)]
+    pub hourly: BTreeMap<String, Traffic>,
+    /// 일별 합계 ("YYYY-MM-DD" → 바이트)
+    #[serde(default)]
+    pub daily: BTreeMap<String, Traffic>,
+    /// 월별 합계 ("YYYY-MM" → 바이트)
+    #[serde(default)]
+    pub monthly: BTreeMap<String, Traffic>,
+}
+
+impl InterfaceAccount {
+    /// 단위별 버킷 목록 (키 오름차순)
+    pub fn entries(&self, period: Period) -> &BTreeMap<String, Traffic> {
+        match period {
+            Period::Hourly => &self.hourly,
+            Period::Daily => &self.daily,
+            Period::Monthly => &self.monthly,
+        }
+    }
+
+    fn entries_mut(&mut self, period: Period) -> &mut BTreeMap<String, Traffic> {
+        match period {
+            Period::Hourly => &mut self.hourly,
+            Period::Daily => &mut self.daily,
+            Period::Monthly => &mut self.monthly,
+        }
+    }
+
+    /// 특정 시점의 트래픽을 시간/일/월 버킷에 더함 (오래된 버킷은 정리)
+    pub fn add(&mut self, unix: i64, traffic: Traffic) {
+        for period in Period::ALL {
+            let key = period.key(unix);
+            let entries = self.entries_mut(period);
+            entries.entry(key).or_default().add(traffic);
+            prune(entries, period);
+        }
+    }
+
+    /// 다른 도구에서 가져온 집계를 병합하고 바뀐 버킷 수를 반환
+    /// 겹치는 버킷은 방향별로 더 큰 값을 유지하므로 같은 데이터를 다시 가져와도 중복 집계되지 않음
+    pub fn merge(&mut self, imported: &InterfaceAccount) -> usize {
+        let mut changed = 0;
+        for period in Period::ALL {
+            let entries = self.entries_mut(period);
+            for (key, traffic) in imported.entries(period) {
+                let entry = entries.entry(key.clone()).or_default();
+                let merged = entry.max(*traffic);
+                if merged != *entry {
+                    *entry = merged;
+                    changed += 1;
+                }
+            }
+            prune(entries, period);
+        }
+        changed
+    }
+
+    /// 새 카운터 값을 마지막 값과 비교하여 그 사이의 트래픽을 계산
+    /// 재부팅되었거나 카운터가 줄었으면(인터페이스 재생성/오버플로) 새 카운터 전체를 증가분으로 봄
+    fn reconcile(&mut self, rx: u64, tx: u64, boot_id: Option<&str>) -> Traffic {
+        let rebooted = match (&self.boot_id, boot_id) {
+            (Some(previous), Some(current)) => previous != current,
+            _ => false,
+        };
+
+        let delta = |current: u64, last: u64| {
+            if rebooted || current < last {
+                current
+            } else {
+                current - last
+            }
+        };
+        let traffic = Traffic::new(delta(rx, self.last_rx), delta(tx, self.last_tx));
+
+        self.last_rx = rx;
+        self.last_tx = tx;
+        self.boot_id = boot_id.map(str::to_string);
+        traffic
+    }
+}
+
+// 보관 기간을 넘은 버킷 제거
+// BTreeMap은 키 순서로 정렬되므로 앞쪽(가장 오래된)부터 제거
+fn prune(entries: &mut BTreeMap<String, Traffic>, period: Period) {
+    while entries.len() > period.retention() {
+        entries.pop_first();
+    }
+}
+
+/// 전체 집계 데이터베이스 (인터페이스 이름 → 기록)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountingDb {
+    pub version: u32,
+    pub interfaces: BTreeMap<String, InterfaceAccount>,
+}
+
+impl Default for AccountingDb {
+    fn default() -> Self {
+        Self {
+            version: DB_VERSION,
+            interfaces: BTreeMap::new(),
+        }
+    }
+}
+
+impl AccountingDb {
+    /// 기본 집계 파일 경로 (데이터 디렉터리/accounting.json)
+    pub fn default_path() -> Result<PathBuf> {
+        Ok(super::data_dir()?.join(ACCOUNTING_FILE))
+    }
+
+    /// 파일에서 데이터베이스를 읽음 - 파일이 없으면 빈 데이터베이스
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("Accounting database {} is corrupted", path.display()))
+    }
+
+    /// 데이터베이스를 파일에 원자적으로 저장
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let contents =
+            serde_json::to_vec_pretty(self).context("Failed to serialize accounting database")?;
+        super::write_atomic(path, &contents)
+    }
+
+    /// 파일을 잠근 채로 읽고, change로 고친 뒤 저장하여 반환
+    /// 여러 nbmon 프로세스(데몬, TUI, record, web 등)가 동시에 기록해도 서로의 변경을 덮어쓰지 않음
+    pub fn modify(path: &Path, change: impl FnOnce(&mut Self)) -> Result<Self> {
+        let _lock = super::lock_exclusive(path)?;
+        let mut db = Self::load(path)?;
+        change(&mut db);
+        db.save(path)?;
+        Ok(db)
+    }
+
+    /// 인터페이스의 한 기간(key: 날짜/월 키) 합계 (기록이 없으면 0)
+    pub fn period_total(&self, name: &str, period: Period, key: &str) -> Traffic {
+        self.interfaces
+            .get(name)
+            .and_then(|account| account.entries(period).get(key).copied())
+            .unwrap_or_default()
+    }
+
+    /// 인터페이스의 현재 커널 카운터를 반영하고 이번에 집계된 트래픽을 반환
+    /// 처음 보는 인터페이스는 기준값만 기록 (언제부터 쌓인 카운터인지 알 수 없으므로)
+    /// 가져오기로만 만들어진 기록(last_updated == 0)도 아직 기준값이 없으므로 같은 방식으로 처리
+    pub fn update_counters(
+        &mut self,
+        name: &str,
+        rx: u64,
+        tx: u64,
+        boot_id: Option<&str>,
+        now: i64,
+    ) -> Traffic {
+        let is_new = self
+            .interfaces
+            .get(name)
+            .is_none_or(|account| account.last_updated == 0);
+        let account = self.interfaces.entry(name.to_string()).or_default();
+
+        let traffic = account.reconcile(rx, tx, boot_id);
+        account.last_updated = now;
+
+        if is_new {
+            return Traffic::default();
+        }
+        if traffic.total() > 0 {
+            account.add(now, traffic);
+        }
+        traffic
+    }
+
+    /// 가져온 인터페이스 기록을 병합하고 바뀐 버킷 수를 반환
+    pub fn import(&mut self, name: &str, imported: &InterfaceAccount) -> usize {
+        self.interfaces
+            .entry(name.to_string())
+            .or_default()
+            .merge(imported)
+    }
+
+    /// 수집된 통계로 모든 (루프백이 아닌) 인터페이스의 카운터를 반영
+    pub fn record_stats(
+        &mut self,
+        interfaces: &[NetworkInterface],
+        stats: &[InterfaceStats],
+        now: i64,
+    ) {
+        let boot_id = boot_id();
+        for (interface, stats) in interfaces.iter().zip(stats) {
+            // 통계를 읽지 못한 인터페이스(카운터 0)는 카운터 리셋으로 오인하지 않도록 건너뜀
+            if interface.is_loopback || (stats.bytes_received == 0 && stats.bytes_sent == 0) {
+                continue;
+            }
+            self.update_counters(
+                &interface.name,
+                stats.bytes_received,
+                stats.bytes_sent,
+                boot_id.as_deref(),
+                now,
+            );
+        }
+    }
+}
+
+/// 현재 부팅을 식별하는 ID (Linux: /proc/sys/kernel/random/boot_id)
+#[cfg(target_os = "linux")]
+pub fn boot_id() -> Option<String> {
+    fs::read_to_string("/proc/sys/kernel/random/boot_id")
+        .ok()
+        .map(|id| id.trim().to_string())
+}
+
+/// 현재 부팅을 식별하는 ID (지원되지 않는 OS에서는 카운터 감소로만 재부팅 감지)
+#[cfg(not(target_os = "linux"))]
+pub fn boot_id() -> Option<String> {
+    None
+}
+
+/// 실행 중인 애플리케이션에서 주기적으로 집계 파일과 분 단위 속도 기록을 갱신하는 헬퍼
+/// 매번 파일을 잠그고 다시 읽어서 반영하므로 여러 nbmon 프로세스가 동시에 실행되어도 중복 집계되거나 누락되지 않음
+pub struct Recorder {
+    path: PathBuf,
+    last_flush: Option<Instant>,
+    flush_interval: Duration,
+    disabled: bool, // 저장에 실패하면 화면을 어지럽히지 않도록 이후 기록을 중단
+    database: Option<AccountingDb>, // 마지막으로 저장한 데이터베이스 (한도 표시 등에 사용)
+    rate_log: Option<RateLog>, // 분 단위 속도 기록 (기록에 실패하면 None)
+    minutes: MinuteAggregator,
+    last_prune_day: Option<i64>, // 오래된 속도 기록을 마지막으로 정리한 날 (하루 한 번)
+}
+
+impl Recorder {
+    /// 기본 경로에 기록하는 Recorder 생성
+    pub fn new() -> Result<Self> {
+        Ok(Self::with_path(AccountingDb::default_path()?))
+    }
+
+    /// 집계 파일 경로를 지정하여 생성 (속도 기록은 같은 디렉터리의 rates/ 아래)
+    pub fn with_path(path: PathBuf) -> Self {
+        Self {
+            rate_log: Some(RateLog::new(path.with_file_name(RATES_DIR))),
+            path,
+            last_flush: None,
+            flush_interval: Duration::from_secs(FLUSH_INTERVAL_SECS),
+            disabled: false,
+            database: None,
+            minutes: MinuteAggregator::default(),
+            last_prune_day: None,
+        }
+    }
+
+    /// 마지막으로 저장한 집계 데이터베이스 (아직 기록 전이면 None)
+    pub fn database(&self) -> Option<&AccountingDb> {
+        self.database.as_ref()
+    }
+
+    /// 매 틱마다 호출 - 속도를 분 단위로 모으고, flush 간격이 지났으면 집계 파일에 기록
+    pub fn maybe_flush(&mut self, sampler: &Sampler) {
+        self.record_rates(sampler);
+
+        let due = self
+            .last_flush
+            .map(|last| last.elapsed() >= self.flush_interval)
+            .unwrap_or(true);
+        if due {
+            self.flush(sampler);
+        }
+    }
+
+    /// 즉시 집계 파일에 반영 (종료 시 호출)
+    pub fn flush(&mut self, sampler: &Sampler) {
+        if self.disabled {
+            return;
+        }
+        self.last_flush = Some(Instant::now());
+
+        let result = AccountingDb::modify(&self.path, |db| {
+            db.record_stats(sampler.interfaces(), sampler.all_stats(), time::now_unix());
+        });
+
+        match result {
+            Ok(db) => self.database = Some(db),
+            Err(e) => {
+                crate::debug_log!("Traffic accounting disabled: {:?}", e);
+                self.disabled = true;
+            }
+        }
+    }
+
+    // 끝난 분의 속도 기록을 파일에 추가하고 하루 한 번 오래된 파일 정리
+    fn record_rates(&mut self, sampler: &Sampler) {
+        let Some(rate_log) = &self.rate_log else {
+            return;
+        };
+
+        let now = time::now_unix();
+        let completed = self
+            .minutes
+            .push(sampler.interfaces(), sampler.all_bandwidth(), now);
+        if completed.is_empty() {
+            return;
+        }
+
+        let today = now.div_euclid(86_400);
+        let result = rate_log.append(&completed).and_then(|()| {
+            if self.last_prune_day != Some(today) {
+                self.last_prune_day = Some(today);
+                rate_log.prune(now)?;
+            }
+            Ok(())
+        });
+
+        if let Err(e) = result {
+            crate::debug_log!("Rate logging disabled: {:?}", e);
+            self.rate_log = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reconcile_handles_restart_and_reboot() {
+        let mut db = AccountingDb::default();
+        let now = 1_700_000_000;
+
+        // 처음 보는 인터페이스는 기준값만 기록
+        assert_eq!(
+            db.update_counters("eth0", 1_000, 500, Some("boot-a"), now),
+            Traffic::default()
+        );
+        // 같은 부팅에서 증가한 만큼 집계 (nbmon 재시작 후에도 동일)
+        assert_eq!(
+            db.update_counters("eth0", 1_600, 700, Some("boot-a"), now + 60),
+            Traffic::new(600, 200)
+        );
+        // 재부팅 후에는 카운터가 0부터 다시 시작하므로 새 값 전체가 증가분
+        assert_eq!(
+            db.update_counters("eth0", 300, 100, Some("boot-b"), now + 120),
+            Traffic::new(300, 100)
+        );
+
+        let account = &db.interfaces["eth0"];
+        let day_total: u64 = account.daily.values().map(Traffic::total).sum();
+        assert_eq!(day_total, 600 + 200 + 300 + 100);
+
+        // 이번 달 합계 - 기록이 없는 인터페이스나 기간은 0
+        let month = crate::utils::time::DateTime::local(now).month_key();
+        assert_eq!(
+            db.period_total("eth0", Period::Monthly, &month),
+            Traffic::new(900, 300)
+        );
+        assert_eq!(
+            db.period_total("eth1", Period::Monthly, &month),
+            Traffic::default()
+        );
+        assert_eq!(
+            db.period_total("eth0", Period::Monthly, "1999-01"),
+            Traffic::default()
+        );
+    }
+
+    // 여러 기록자가 동시에 읽고-고치고-써도 어느 쪽의 변경도 사라지지 않음
+    #[test]
+    fn test_concurrent_modify_keeps_every_change() {
+        let dir = std::env::temp_dir().join(format!("nbmon-accounting-{}", std::process::id()));
+        let path = dir.join(ACCOUNTING_FILE);
+
+        let writers: Vec<_> = (0..4)
+            .map(|_| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..25 {
+                        AccountingDb::modify(&path, |db| {
+                            let account = db.interfaces.entry("eth0".to_string()).or_default();
+                            account.add(1_700_000_000, Traffic::new(1, 0));
+                        })
+                        .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        let db = AccountingDb::load(&path).unwrap();
+        let rx: u64 = db.interfaces["eth0"].daily.values().map(|t| t.rx).sum();
+        assert_eq!(rx, 100);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
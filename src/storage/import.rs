@@ -0,0 +1,338 @@
+// 다른 도구의 트래픽 기록을 nbmon 집계 데이터베이스 형식으로 변환하는 모듈
+// - vnstat --json (jsonversion 1, 2)
+// - sar -n DEV 텍스트 출력 (sysstat)
+// nbmon을 설치하기 전의 기록도 report/quota에서 볼 수 있도록 함
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::storage::accounting::{InterfaceAccount, Traffic};
+use crate::utils::time::DateTime;
+
+/// 인터페이스 이름 → 가져온 시간/일/월 집계
+pub type ImportedAccounts = BTreeMap<String, InterfaceAccount>;
+
+// vnstat 1.x의 JSON은 KiB 단위, 2.x는 바이트 단위
+const VNSTAT_V1_UNIT: u64 = 1024;
+
+// sysstat의 kB는 1024바이트
+const SAR_KB: f64 = 1024.0;
+
+// ---- vnstat ----
+
+#[derive(Deserialize)]
+struct VnstatDump {
+    #[serde(default)]
+    jsonversion: serde_json::Value,
+    interfaces: Vec<VnstatInterface>,
+}
+
+#[derive(Deserialize)]
+struct VnstatInterface {
+    // 2.x는 "name", 1.x는 "id"에 인터페이스 이름이 들어 있음
+    name: Option<String>,
+    id: Option<String>,
+    traffic: VnstatTraffic,
+}
+
+#[derive(Deserialize)]
+struct VnstatTraffic {
+    #[serde(default, alias = "hours")]
+    hour: Vec<VnstatEntry>,
+    #[serde(default, alias = "days")]
+    day: Vec<VnstatEntry>,
+    #[serde(default, alias = "months")]
+    month: Vec<VnstatEntry>,
+}
+
+#[derive(Deserialize)]
+struct VnstatEntry {
+    // 1.x의 시간별 항목은 id가 시(0-23)
+    id: Option<u64>,
+    date: VnstatDate,
+    time: Option<VnstatTime>,
+    rx: u64,
+    tx: u64,
+}
+
+#[derive(Deserialize)]
+struct VnstatDate {
+    year: i32,
+    month: u32,
+    day: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct VnstatTime {
+    hour: u32,
+}
+
+/// `vnstat --json` 출력 변환
+/// vnstat도 로컬 시간 기준으로 집계하므로 날짜 필드로 바로 nbmon 버킷 키를 만듦
+pub fn parse_vnstat_json(text: &str) -> Result<ImportedAccounts> {
+    let dump: VnstatDump =
+        serde_json::from_str(text).context("Input is not vnstat --json output")?;
+
+    let version_one =
+        dump.jsonversion.as_str() == Some("1") || dump.jsonversion.as_u64() == Some(1);
+    let unit = if version_one { VNSTAT_V1_UNIT } else { 1 };
+    let traffic = |entry: &VnstatEntry| {
+        Traffic::new(entry.rx.saturating_mul(unit), entry.tx.saturating_mul(unit))
+    };
+
+    let mut accounts = ImportedAccounts::new();
+    for interface in dump.interfaces {
+        let name = interface
+            .name
+            .or(interface.id)
+            .context("vnstat interface entry has no name")?;
+        let mut account = InterfaceAccount::default();
+
+        for entry in &interface.traffic.hour {
+            let hour = entry
+                .time
+                .as_ref()
+                .map(|time| time.hour)
+                .or(entry.id.map(|id| id as u32))
+                .context("vnstat hourly entry has no hour")?;
+            let key = date_time(&entry.date, hour).hour_key();
+            account.hourly.insert(key, traffic(entry));
+        }
+        for entry in &interface.traffic.day {
+            account
+                .daily
+                .insert(date_time(&entry.date, 0).day_key(), traffic(entry));
+        }
+        for entry in &interface.traffic.month {
+            account
+                .monthly
+                .insert(date_time(&entry.date, 0).month_key(), traffic(entry));
+        }
+
+        accounts.insert(name, account);
+    }
+    Ok(accounts)
+}
+
+fn date_time(date: &VnstatDate, hour: u32) -> DateTime {
+    DateTime {
+        year: date.year,
+        month: date.month,
+        day: date.day.unwrap_or(1),
+        hour,
+        minute: 0,
+        second: 0,
+    }
+}
+
+// ---- sar ----
+
+/// `sar -n DEV` 텍스트 출력 변환
+/// 각 표본의 평균 속도(rxkB/s, txkB/s) × 구간 길이로 바이트를 구해 구간 중간 시각의 버킷에 더함
+/// 날짜는 "Linux ..." 머리글에서 읽고, 시각이 되돌아가면 다음 날로 넘어간 것으로 봄
+pub fn parse_sar_dev(text: &str) -> Result<ImportedAccounts> {
+    let mut accounts = ImportedAccounts::new();
+
+    let mut date: Option<DateTime> = None; // 현재 날짜 (자정)
+    let mut last_clock: Option<u32> = None; // 마지막으로 본 시각 (자정 이후 초)
+    let mut columns: Option<(usize, usize)> = None; // IFACE 다음의 (rxkB/s, txkB/s) 위치
+    let mut interval_start: Option<i64> = None;
+    let mut current_time: Option<i64> = None;
+
+    for (number, line) in text.lines().enumerate() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let Some(first) = tokens.first() else {
+            continue;
+        };
+
+        // 파일 머리글: "Linux 6.1.0 (host)  01/15/2024  _x86_64_  (4 CPU)"
+        if *first == "Linux" {
+            date = tokens.iter().find_map(|token| parse_sar_date(token));
+            last_clock = None;
+            interval_start = None;
+            current_time = None;
+            continue;
+        }
+
+        // 평균/요약 줄과 알 수 없는 줄은 무시
+        let Some((clock, rest)) = parse_sar_clock(&tokens) else {
+            continue;
+        };
+        let Some(day) = date.as_mut() else {
+            bail!(
+                "line {}: sar output has no 'Linux ...' header with the date",
+                number + 1
+            );
+        };
+
+        // 자정을 지나면 날짜를 하루 넘김
+        if last_clock.is_some_and(|last| clock < last) {
+            *day = DateTime::from_unix(day.to_unix(0) + 86_400, 0);
+        }
+        last_clock = Some(clock);
+        let timestamp = DateTime {
+            hour: clock / 3_600,
+            minute: clock % 3_600 / 60,
+            second: clock % 60,
+            ..*day
+        }
+        .to_unix_local();
+
+        if rest.first() == Some(&"LINUX") {
+            // "LINUX RESTART": 재부팅 전후는 하나의 구간으로 보지 않음
+            interval_start = None;
+            current_time = None;
+            continue;
+        }
+
+        if rest.first() == Some(&"IFACE") {
+            let position = |name: &str| rest.iter().position(|column| *column == name);
+            columns = position("rxkB/s").zip(position("txkB/s"));
+            if columns.is_none() {
+                bail!(
+                    "line {}: sar header has no rxkB/s/txkB/s columns",
+                    number + 1
+                );
+            }
+            // 머리글의 시각은 다음 표본 구간의 시작
+            interval_start = Some(timestamp);
+            current_time = None;
+            continue;
+        }
+
+        let Some((rx_column, tx_column)) = columns else {
+            bail!("line {}: sar data before the IFACE header", number + 1);
+        };
+
+        // 새 시각의 표본 묶음이 시작되면 이전 묶음의 시각이 구간 시작
+        if let Some(previous) = current_time.filter(|previous| *previous != timestamp) {
+            interval_start = Some(previous);
+        }
+        current_time = Some(timestamp);
+
+        let Some(start) = interval_start.filter(|start| *start < timestamp) else {
+            continue;
+        };
+        let seconds = (timestamp - start) as f64;
+
+        let rate = |column: usize| -> Result<f64> {
+            let value = rest
+                .get(column)
+                .with_context(|| format!("line {}: missing column", number + 1))?;
+            // 일부 로캘은 소수점으로 쉼표를 사용
+            value
+                .replace(',', ".")
+                .parse::<f64>()
+                .with_context(|| format!("line {}: invalid number '{}'", number + 1, value))
+        };
+        let traffic = Traffic::new(
+            (rate(rx_column)? * SAR_KB * seconds) as u64,
+            (rate(tx_column)? * SAR_KB * seconds) as u64,
+        );
+
+        accounts
+            .entry(rest[0].to_string())
+            .or_default()
+            .add(start + (timestamp - start) / 2, traffic);
+    }
+
+    Ok(accounts)
+}
+
+// "12:10:01 AM eth0 ..." 또는 "00:10:01 eth0 ..." → (자정 이후 초, 나머지 열)
+fn parse_sar_clock<'a>(tokens: &'a [&'a str]) -> Option<(u32, &'a [&'a str])> {
+    let mut parts = tokens.first()?.split(':');
+    let mut hour: u32 = parts.next()?.parse().ok()?;
+    let minute: u32 = parts.next()?.parse().ok()?;
+    let second: u32 = parts.next()?.parse().ok()?;
+
+    let mut rest = &tokens[1..];
+    match rest.first() {
+        Some(&"AM") => {
+            hour %= 12;
+            rest = &rest[1..];
+        }
+        Some(&"PM") => {
+            hour = hour % 12 + 12;
+            rest = &rest[1..];
+        }
+        _ => {}
+    }
+
+    (hour < 24 && minute < 60 && second < 61).then_some((hour * 3_600 + minute * 60 + second, rest))
+}
+
+// 머리글 날짜: "2024-01-15"(S_TIME_FORMAT=ISO), "01/15/2024", "01/15/24"
+// 첫 숫자가 12보다 크면 일/월/연 순서로 봄 (유럽 로캘)
+fn parse_sar_date(token: &str) -> Option<DateTime> {
+    let (year, month, day) = if let Some((year, rest)) = token.split_once('-') {
+        let (month, day) = rest.split_once('-')?;
+        (year.parse().ok()?, month.parse().ok()?, day.parse().ok()?)
+    } else {
+        let mut parts = token.split('/');
+        let first: u32 = parts.next()?.parse().ok()?;
+        let second: u32 = parts.next()?.parse().ok()?;
+        let year: i32 = parts.next()?.parse().ok()?;
+        let year = if year < 100 { 2000 + year } else { year };
+        if first > 12 {
+            (year, second, first)
+        } else {
+            (year, first, second)
+        }
+    };
+
+    ((1..=12).contains(&month) && (1..=31).contains(&day)).then_some(DateTime {
+        year,
+        month,
+        day,
+        hour: 0,
+        minute: 0,
+        second: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vnstat_json_v2() {
+        let json = r#"{"vnstatversion":"2.9","jsonversion":"2","interfaces":[{"name":"eth0",
+            "traffic":{"total":{"rx":3000,"tx":600},
+            "hour":[{"id":1,"date":{"year":2023,"month":5,"day":2},"time":{"hour":13,"minute":0},"timestamp":0,"rx":100,"tx":20}],
+            "day":[{"id":2,"date":{"year":2023,"month":5,"day":2},"timestamp":0,"rx":1000,"tx":200}],
+            "month":[{"id":3,"date":{"year":2023,"month":5},"timestamp":0,"rx":3000,"tx":600}]}}]}"#;
+
+        let accounts = parse_vnstat_json(json).unwrap();
+        let eth0 = &accounts["eth0"];
+        assert_eq!(eth0.hourly["2023-05-02 13:00"], Traffic::new(100, 20));
+        assert_eq!(eth0.daily["2023-05-02"], Traffic::new(1000, 200));
+        assert_eq!(eth0.monthly["2023-05"], Traffic::new(3000, 600));
+    }
+
+    #[test]
+    fn test_sar_dev_intervals_and_midnight() {
+        let text = "\
+Linux 6.1.0 (host) \t01/15/2024 \t_x86_64_\t(4 CPU)
+
+11:50:00 PM     IFACE   rxpck/s   txpck/s    rxkB/s    txkB/s   rxcmp/s   txcmp/s  rxmcst/s   %ifutil
+11:55:00 PM      eth0     10.00      5.00      2.00      1.00      0.00      0.00      0.00      0.00
+
+11:55:00 PM     IFACE   rxpck/s   txpck/s    rxkB/s    txkB/s   rxcmp/s   txcmp/s  rxmcst/s   %ifutil
+12:05:00 AM      eth0     10.00      5.00      4.00      0.00      0.00      0.00      0.00      0.00
+
+Average:         eth0     10.00      5.00      3.00      0.50      0.00      0.00      0.00      0.00
+";
+        let accounts = parse_sar_dev(text).unwrap();
+        let eth0 = &accounts["eth0"];
+
+        // 300초 × 2 kB/s, 구간 중간(23:52:30)은 15일
+        assert_eq!(
+            eth0.daily["2024-01-15"],
+            Traffic::new(2 * 1024 * 300, 1024 * 300)
+        );
+        // 600초 × 4 kB/s, 구간 중간(00:00:00)은 16일
+        assert_eq!(eth0.daily["2024-01-16"], Traffic::new(4 * 1024 * 600, 0));
+    }
+}
@@ -0,0 +1,81 @@
+// 디스크에 저장되는 데이터(트래픽 집계 등)를 다루는 모듈
+// 데이터 파일은 XDG 데이터 디렉터리 아래 nbmon/ 에 보관
+
+pub mod accounting; // 인터페이스별 시간/일/월 트래픽 집계 (accounting.rs)
+pub mod import; // vnstat/sar 기록 가져오기 (import.rs)
+pub mod query; // 분 단위 속도 기록 조회/집계 (query.rs)
+pub mod quota; // 청구 주기별 데이터 사용량 한도 (quota.rs)
+pub mod rates; // 분 단위 속도 기록 (rates.rs)
+pub mod report; // 집계 표 출력 (report.rs)
+
+use anyhow::{Context, Result};
+use std::collections::hash_map::RandomState;
+use std::fs::{self, File, OpenOptions};
+use std::hash::{BuildHasher, Hasher};
+use std::path::{Path, PathBuf};
+
+/// nbmon 데이터 디렉터리 경로
+/// 우선순위: $NBMON_DATA_DIR → $XDG_DATA_HOME/nbmon → ~/.local/share/nbmon (Windows: %APPDATA%\nbmon)
+pub fn data_dir() -> Result<PathBuf> {
+    if let Some(dir) = std::env::var_os("NBMON_DATA_DIR") {
+        return Ok(PathBuf::from(dir));
+    }
+
+    if let Some(dir) = std::env::var_os("XDG_DATA_HOME").filter(|d| !d.is_empty()) {
+        return Ok(PathBuf::from(dir).join("nbmon"));
+    }
+
+    #[cfg(windows)]
+    let base = std::env::var_os("APPDATA").map(PathBuf::from);
+
+    #[cfg(not(windows))]
+    let base = std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share"));
+
+    base.map(|dir| dir.join("nbmon"))
+        .context("Cannot determine the data directory (set XDG_DATA_HOME or NBMON_DATA_DIR)")
+}
+
+/// 파일을 원자적으로 기록 - 임시 파일에 쓴 뒤 rename하여 중간에 종료되어도 기존 파일이 깨지지 않음
+/// 임시 파일 이름에 프로세스 ID와 무작위 값을 붙여서 여러 프로세스가 같은 임시 파일을 덮어쓰지 않음
+pub fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    create_parent(path)?;
+
+    // RandomState는 프로세스마다 무작위 키로 시작하므로 별도 난수 생성기 없이 충분히 겹치지 않는 값
+    let suffix = RandomState::new().build_hasher().finish();
+    let tmp_path = path.with_extension(format!("tmp.{}.{:08x}", std::process::id(), suffix as u32));
+    let result = fs::write(&tmp_path, contents)
+        .with_context(|| format!("Failed to write {}", tmp_path.display()))
+        .and_then(|()| {
+            fs::rename(&tmp_path, path)
+                .with_context(|| format!("Failed to replace {}", path.display()))
+        });
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+    result
+}
+
+/// 데이터 파일을 읽고-고치고-쓰는 동안 잡는 배타적 권고 잠금 (파일 옆의 .lock 파일)
+/// 다른 nbmon 프로세스가 같은 파일을 고치는 중이면 끝날 때까지 기다림, 반환된 파일을 drop하면 풀림
+pub fn lock_exclusive(path: &Path) -> Result<File> {
+    create_parent(path)?;
+
+    let lock_path = path.with_extension("lock");
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .with_context(|| format!("Failed to open {}", lock_path.display()))?;
+    file.lock()
+        .with_context(|| format!("Failed to lock {}", lock_path.display()))?;
+    Ok(file)
+}
+
+fn create_parent(path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,281 @@
+// 분 단위 속도 기록을 시간 구간별로 집계하는 모듈 (nbmon query)
+// 예: "eth0의 02:00~03:00 사이 최고 속도", "지난 하루의 5분 단위 95퍼센타일"
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::i18n::{t, Msg};
+use crate::storage::rates::RateRecord;
+use crate::utils::{format, time::DateTime};
+
+// 기록 한 줄이 나타내는 시간 (초)
+const RECORD_SECS: f64 = 60.0;
+
+// 열 너비
+const TIME_WIDTH: usize = 21;
+const NAME_WIDTH: usize = 12;
+const VALUE_WIDTH: usize = 14;
+
+/// 구간마다 값을 하나로 줄이는 방법
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    /// 분 평균들의 95퍼센타일 (회선 과금 기준)
+    P95,
+    /// 평균 속도
+    Avg,
+    /// 최고 속도 (분 안의 순간 최대값 기준)
+    Max,
+    /// 분 평균 중 최저 속도
+    Min,
+    /// 전송량 합계 (바이트)
+    Sum,
+}
+
+impl Aggregation {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "p95" => Some(Aggregation::P95),
+            "avg" | "mean" => Some(Aggregation::Avg),
+            "max" => Some(Aggregation::Max),
+            "min" => Some(Aggregation::Min),
+            "sum" => Some(Aggregation::Sum),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Aggregation::P95 => "p95",
+            Aggregation::Avg => "avg",
+            Aggregation::Max => "max",
+            Aggregation::Min => "min",
+            Aggregation::Sum => "sum",
+        }
+    }
+
+    /// 결과 값의 단위
+    pub fn unit(self) -> &'static str {
+        match self {
+            Aggregation::Sum => "bytes",
+            _ => "bytes/s",
+        }
+    }
+
+    // 구간 안의 분 단위 값들을 하나로 줄임 (values는 비어 있지 않음)
+    fn reduce(self, values: &mut [f64]) -> f64 {
+        match self {
+            Aggregation::Avg => values.iter().sum::<f64>() / values.len() as f64,
+            Aggregation::Max => values.iter().copied().fold(0.0, f64::max),
+            Aggregation::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+            Aggregation::Sum => values.iter().sum::<f64>() * RECORD_SECS,
+            Aggregation::P95 => {
+                // nearest-rank 방식: 정렬 후 상위 5%를 제외한 가장 큰 값
+                values.sort_by(f64::total_cmp);
+                let rank = (values.len() as f64 * 0.95).ceil() as usize;
+                values[rank.saturating_sub(1)]
+            }
+        }
+    }
+}
+
+/// 조회 조건
+#[derive(Debug, Clone)]
+pub struct Query {
+    pub interface: Option<String>,
+    pub since: i64,
+    pub until: i64,
+    pub aggregation: Aggregation,
+    /// 구간 길이 (초), None이면 전체 범위를 하나의 구간으로
+    pub bucket: Option<i64>,
+}
+
+/// 한 구간, 한 인터페이스의 집계 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QueryRow {
+    /// 구간 시작 (Unix 초)
+    pub timestamp: i64,
+    pub interface: String,
+    pub rx: f64,
+    pub tx: f64,
+    /// 구간 안에 기록이 있는 분 수
+    pub minutes: usize,
+}
+
+// 한 구간, 한 인터페이스에 모인 분 단위 값들
+#[derive(Default)]
+struct Samples {
+    rx: Vec<f64>,
+    tx: Vec<f64>,
+}
+
+impl Query {
+    // 기록이 속하는 구간의 시작 시각
+    // 구간은 시계에 맞춰 정렬됨 (5m → :00, :05, ...)
+    fn bucket_start(&self, time: i64) -> i64 {
+        match self.bucket {
+            Some(bucket) => (time.div_euclid(bucket) * bucket).max(self.since),
+            None => self.since,
+        }
+    }
+
+    /// 기록을 구간/인터페이스별로 집계 (시간순, 같은 시각은 인터페이스 이름순)
+    pub fn run(&self, records: &[RateRecord]) -> Vec<QueryRow> {
+        let mut groups: BTreeMap<(i64, &str), Samples> = BTreeMap::new();
+
+        for record in records {
+            if record.time < self.since || record.time >= self.until {
+                continue;
+            }
+            if self
+                .interface
+                .as_deref()
+                .is_some_and(|name| name != record.interface)
+            {
+                continue;
+            }
+
+            let samples = groups
+                .entry((self.bucket_start(record.time), &record.interface))
+                .or_default();
+            if self.aggregation == Aggregation::Max {
+                samples.rx.push(record.rx_max);
+                samples.tx.push(record.tx_max);
+            } else {
+                samples.rx.push(record.rx_avg);
+                samples.tx.push(record.tx_avg);
+            }
+        }
+
+        groups
+            .into_iter()
+            .map(|((timestamp, interface), mut samples)| QueryRow {
+                timestamp,
+                interface: interface.to_string(),
+                minutes: samples.rx.len(),
+                rx: self.aggregation.reduce(&mut samples.rx),
+                tx: self.aggregation.reduce(&mut samples.tx),
+            })
+            .collect()
+    }
+
+    /// 결과를 표로 출력
+    pub fn render_table(&self, rows: &[QueryRow]) -> String {
+        let mut out = String::new();
+        if rows.is_empty() {
+            let _ = writeln!(out, "{}", t(Msg::QueryNoData));
+            let _ = writeln!(out, "{}", t(Msg::QueryRecordedBy));
+            return out;
+        }
+
+        let value = |value: f64| match self.aggregation {
+            Aggregation::Sum => format::format_bytes(value as u64),
+            _ => format::format_bytes_per_sec(value),
+        };
+
+        // writeln!은 String에 대해 실패하지 않으므로 결과를 무시
+        let _ = writeln!(
+            out,
+            " {:<tw$}{:<nw$}{:>vw$}{:>vw$}{:>9}",
+            "time",
+            "iface",
+            format!("rx {}", self.aggregation.label()),
+            format!("tx {}", self.aggregation.label()),
+            "minutes",
+            tw = TIME_WIDTH,
+            nw = NAME_WIDTH,
+            vw = VALUE_WIDTH
+        );
+        let _ = writeln!(
+            out,
+            " {}",
+            "-".repeat(TIME_WIDTH + NAME_WIDTH + VALUE_WIDTH * 2 + 9)
+        );
+        for row in rows {
+            let _ = writeln!(
+                out,
+                " {:<tw$}{:<nw$}{:>vw$}{:>vw$}{:>9}",
+                DateTime::local(row.timestamp).to_string(),
+                row.interface,
+                value(row.rx),
+                value(row.tx),
+                row.minutes,
+                tw = TIME_WIDTH,
+                nw = NAME_WIDTH,
+                vw = VALUE_WIDTH
+            );
+        }
+        out
+    }
+
+    /// 결과를 JSON으로 출력 (값은 가공하지 않은 바이트 또는 바이트/초)
+    pub fn render_json(&self, rows: &[QueryRow]) -> Result<String> {
+        #[derive(Serialize)]
+        struct Output<'a> {
+            interface: Option<&'a str>,
+            since: i64,
+            until: i64,
+            aggregation: &'static str,
+            bucket_secs: Option<i64>,
+            unit: &'static str,
+            rows: &'a [QueryRow],
+        }
+
+        serde_json::to_string_pretty(&Output {
+            interface: self.interface.as_deref(),
+            since: self.since,
+            until: self.until,
+            aggregation: self.aggregation.label(),
+            bucket_secs: self.bucket,
+            unit: self.aggregation.unit(),
+            rows,
+        })
+        .context("Failed to serialize query result")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(time: i64, rx_avg: f64) -> RateRecord {
+        RateRecord {
+            time,
+            interface: "eth0".to_string(),
+            rx_avg,
+            rx_max: rx_avg * 2.0,
+            tx_avg: 0.0,
+            tx_max: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_buckets_and_aggregations() {
+        // 00:00부터 10분간 분당 1..=10 바이트/초
+        let records: Vec<RateRecord> = (0..10).map(|i| record(i * 60, (i + 1) as f64)).collect();
+        let mut query = Query {
+            interface: Some("eth0".to_string()),
+            since: 0,
+            until: 600,
+            aggregation: Aggregation::Avg,
+            bucket: Some(300),
+        };
+
+        let rows = query.run(&records);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            (rows[0].timestamp, rows[0].rx, rows[0].minutes),
+            (0, 3.0, 5)
+        );
+        assert_eq!((rows[1].timestamp, rows[1].rx), (300, 8.0));
+
+        query.bucket = None;
+        query.aggregation = Aggregation::P95;
+        assert_eq!(query.run(&records)[0].rx, 10.0);
+        query.aggregation = Aggregation::Max;
+        assert_eq!(query.run(&records)[0].rx, 20.0);
+        query.aggregation = Aggregation::Sum;
+        assert_eq!(query.run(&records)[0].rx, 55.0 * 60.0);
+    }
+}
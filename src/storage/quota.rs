@@ -0,0 +1,297 @@
+// 인터페이스별 데이터 사용량 한도(quota) 관리 모듈
+// 청구 주기(매월 N일 리셋) 동안의 사용량을 일별 집계에서 계산하고
+// 현재 평균 속도로 한도에 도달할 예상 시점을 추정
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::storage::accounting::{AccountingDb, InterfaceAccount, Traffic};
+use crate::utils::time::{self, DateTime};
+
+/// 데이터 디렉터리 안의 한도 설정 파일 이름
+pub const QUOTA_FILE: &str = "quotas.json";
+
+/// 사용량에 포함할 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuotaCount {
+    /// 수신만 (대부분의 LTE 요금제)
+    Rx,
+    /// 송신만
+    Tx,
+    /// 수신 + 송신
+    #[default]
+    Both,
+}
+
+impl QuotaCount {
+    /// 문자열("rx", "tx", "both"/"rx+tx")에서 변환
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "rx" => Some(QuotaCount::Rx),
+            "tx" => Some(QuotaCount::Tx),
+            "both" | "rx+tx" => Some(QuotaCount::Both),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            QuotaCount::Rx => "rx",
+            QuotaCount::Tx => "tx",
+            QuotaCount::Both => "rx+tx",
+        }
+    }
+
+    fn count(self, traffic: &Traffic) -> u64 {
+        match self {
+            QuotaCount::Rx => traffic.rx,
+            QuotaCount::Tx => traffic.tx,
+            QuotaCount::Both => traffic.total(),
+        }
+    }
+}
+
+/// 하나의 인터페이스에 대한 한도 설정
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Quota {
+    /// 청구 주기당 허용 바이트
+    pub limit: u64,
+    /// 주기가 시작되는 날짜 (1-31, 짧은 달에서는 말일로 조정)
+    pub reset_day: u32,
+    /// 사용량에 포함할 방향
+    #[serde(default)]
+    pub count: QuotaCount,
+}
+
+/// 모든 인터페이스의 한도 설정 (인터페이스 이름 → 한도)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuotaSet {
+    pub quotas: BTreeMap<String, Quota>,
+}
+
+impl QuotaSet {
+    /// 기본 한도 설정 파일 경로 (데이터 디렉터리/quotas.json)
+    pub fn default_path() -> Result<PathBuf> {
+        Ok(super::data_dir()?.join(QUOTA_FILE))
+    }
+
+    /// 파일에서 읽음 - 파일이 없으면 빈 설정
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("Quota file {} is invalid", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let contents = serde_json::to_vec_pretty(self).context("Failed to serialize quotas")?;
+        super::write_atomic(path, &contents)
+    }
+
+    pub fn get(&self, interface: &str) -> Option<&Quota> {
+        self.quotas.get(interface)
+    }
+}
+
+/// 현재 청구 주기의 사용 현황
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuotaStatus {
+    pub limit: u64,
+    pub used: u64,
+    /// 주기 시작 시각 (Unix 초, 로컬 자정)
+    pub cycle_start: i64,
+    /// 다음 리셋 시각 (Unix 초, 로컬 자정)
+    pub cycle_end: i64,
+    /// 현재 주기의 평균 속도로 한도에 도달할 예상 시각 (리셋 전에 도달하지 않으면 None)
+    pub projected_exhaustion: Option<i64>,
+}
+
+impl QuotaStatus {
+    pub fn remaining(&self) -> u64 {
+        self.limit.saturating_sub(self.used)
+    }
+
+    /// 사용 비율 (0.0 ~ 1.0 이상)
+    pub fn ratio(&self) -> f64 {
+        if self.limit == 0 {
+            return 1.0;
+        }
+        self.used as f64 / self.limit as f64
+    }
+
+    pub fn is_exceeded(&self) -> bool {
+        self.used >= self.limit
+    }
+}
+
+impl Quota {
+    /// 집계 기록으로 현재 주기의 사용 현황 계산
+    pub fn status(&self, account: Option<&InterfaceAccount>, now: i64) -> QuotaStatus {
+        let (cycle_start, cycle_end) = cycle_bounds(self.reset_day, now);
+
+        // 주기 시작일 이후의 일별 합계를 더함 (키가 "YYYY-MM-DD"라 문자열 비교로 범위 선택 가능)
+        let start_key = DateTime::local(cycle_start).day_key();
+        let used = account
+            .map(|account| {
+                account
+                    .daily
+                    .range(start_key..)
+                    .map(|(_, traffic)| self.count.count(traffic))
+                    .sum()
+            })
+            .unwrap_or(0);
+
+        QuotaStatus {
+            limit: self.limit,
+            used,
+            cycle_start,
+            cycle_end,
+            projected_exhaustion: project_exhaustion(self.limit, used, cycle_start, cycle_end, now),
+        }
+    }
+}
+
+/// 모든 한도 설정에 대한 현재 사용 현황
+pub fn statuses(quotas: &QuotaSet, db: &AccountingDb, now: i64) -> Vec<(String, QuotaStatus)> {
+    quotas
+        .quotas
+        .iter()
+        .map(|(name, quota)| (name.clone(), quota.status(db.interfaces.get(name), now)))
+        .collect()
+}
+
+// 현재 평균 속도(주기 시작 이후 사용량 / 경과 시간)로 한도 도달 시각 추정
+fn project_exhaustion(
+    limit: u64,
+    used: u64,
+    cycle_start: i64,
+    cycle_end: i64,
+    now: i64,
+) -> Option<i64> {
+    if used >= limit {
+        return Some(now);
+    }
+
+    let elapsed = (now - cycle_start).max(1) as f64;
+    let rate = used as f64 / elapsed; // 바이트/초
+    if rate <= 0.0 {
+        return None;
+    }
+
+    let eta = now + ((limit - used) as f64 / rate) as i64;
+    (eta < cycle_end).then_some(eta)
+}
+
+/// reset_day 기준으로 now가 속한 청구 주기의 (시작, 끝) Unix 시간
+pub fn cycle_bounds(reset_day: u32, now: i64) -> (i64, i64) {
+    let today = DateTime::local(now);
+
+    // 이번 달의 리셋일이 아직 오지 않았으면 지난 달에 시작한 주기
+    let (mut year, mut month) = (today.year, today.month);
+    if today.day < clamp_day(year, month, reset_day) {
+        (year, month) = previous_month(year, month);
+    }
+    let (next_year, next_month) = next_month(year, month);
+
+    (
+        midnight(year, month, clamp_day(year, month, reset_day)),
+        midnight(
+            next_year,
+            next_month,
+            clamp_day(next_year, next_month, reset_day),
+        ),
+    )
+}
+
+// 짧은 달에서는 말일로 조정 (예: 31일 리셋 → 2월은 28/29일)
+fn clamp_day(year: i32, month: u32, day: u32) -> u32 {
+    day.clamp(1, time::days_in_month(year, month))
+}
+
+fn previous_month(year: i32, month: u32) -> (i32, u32) {
+    if month == 1 {
+        (year - 1, 12)
+    } else {
+        (year, month - 1)
+    }
+}
+
+fn next_month(year: i32, month: u32) -> (i32, u32) {
+    if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    }
+}
+
+fn midnight(year: i32, month: u32, day: u32) -> i64 {
+    DateTime {
+        year,
+        month,
+        day,
+        hour: 0,
+        minute: 0,
+        second: 0,
+    }
+    .to_unix_local()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_counts_only_current_cycle() {
+        let now = DateTime {
+            year: 2025,
+            month: 3,
+            day: 20,
+            hour: 12,
+            minute: 0,
+            second: 0,
+        }
+        .to_unix_local();
+
+        let mut account = InterfaceAccount::default();
+        for (day, rx) in [("2025-03-14", 700), ("2025-03-15", 100), ("2025-03-20", 50)] {
+            account.daily.insert(day.to_string(), Traffic::new(rx, 1));
+        }
+
+        let quota = Quota {
+            limit: 1_000,
+            reset_day: 15,
+            count: QuotaCount::Rx,
+        };
+        let status = quota.status(Some(&account), now);
+
+        // 3월 14일은 이전 주기
+        assert_eq!(status.used, 150);
+        assert_eq!(status.remaining(), 850);
+        assert_eq!(DateTime::local(status.cycle_start).day_key(), "2025-03-15");
+        assert_eq!(DateTime::local(status.cycle_end).day_key(), "2025-04-15");
+    }
+
+    #[test]
+    fn test_reset_day_is_clamped_to_month_length() {
+        let now = DateTime {
+            year: 2025,
+            month: 2,
+            day: 28,
+            hour: 8,
+            minute: 0,
+            second: 0,
+        }
+        .to_unix_local();
+
+        let (start, end) = cycle_bounds(31, now);
+        assert_eq!(DateTime::local(start).day_key(), "2025-02-28");
+        assert_eq!(DateTime::local(end).day_key(), "2025-03-31");
+    }
+}
@@ -0,0 +1,275 @@
+// 분 단위 속도 기록 모듈 (nbmon query의 데이터 원본)
+// 실행 중인 TUI가 매 틱의 속도를 인터페이스별로 1분씩 모아 평균/최대값을 디스크에 추가 기록
+// 파일은 UTC 날짜별로 나뉘어 있어 조회 범위에 해당하는 파일만 읽음
+//
+// 파일 형식 (rates/YYYY-MM-DD.tsv, 한 줄에 한 분):
+//   <분 시작 Unix 초>\t<인터페이스>\t<rx 평균>\t<rx 최대>\t<tx 평균>\t<tx 최대>   (바이트/초)
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::network::{interface::NetworkInterface, stats::BandwidthStats};
+use crate::utils::time::DateTime;
+
+/// 데이터 디렉터리 안의 속도 기록 디렉터리 이름
+pub const RATES_DIR: &str = "rates";
+
+// 속도 기록 보관 기간 (일)
+const RATE_RETENTION_DAYS: i64 = 31;
+
+const SECS_PER_MINUTE: i64 = 60;
+const SECS_PER_DAY: i64 = 86_400;
+
+/// 한 인터페이스의 1분간 속도 요약
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateRecord {
+    /// 분 시작 시각 (Unix 초)
+    pub time: i64,
+    pub interface: String,
+    pub rx_avg: f64,
+    pub rx_max: f64,
+    pub tx_avg: f64,
+    pub tx_max: f64,
+}
+
+impl RateRecord {
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{:.0}\t{:.0}\t{:.0}\t{:.0}\n",
+            self.time, self.interface, self.rx_avg, self.rx_max, self.tx_avg, self.tx_max
+        )
+    }
+
+    // 잘못된 줄(기록 중 종료되어 잘린 줄 등)은 None
+    fn from_line(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t');
+        let record = Self {
+            time: fields.next()?.parse().ok()?,
+            interface: fields.next()?.to_string(),
+            rx_avg: fields.next()?.parse().ok()?,
+            rx_max: fields.next()?.parse().ok()?,
+            tx_avg: fields.next()?.parse().ok()?,
+            tx_max: fields.next()?.parse().ok()?,
+        };
+        fields.next().is_none().then_some(record)
+    }
+}
+
+/// 날짜별 속도 기록 파일 묶음
+pub struct RateLog {
+    dir: PathBuf,
+}
+
+impl RateLog {
+    /// 기본 속도 기록 디렉터리 (데이터 디렉터리/rates)
+    pub fn default_dir() -> Result<PathBuf> {
+        Ok(super::data_dir()?.join(RATES_DIR))
+    }
+
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    // 해당 시각이 속한 UTC 날짜의 파일
+    fn file_for(&self, unix: i64) -> PathBuf {
+        self.dir
+            .join(format!("{}.tsv", DateTime::from_unix(unix, 0).day_key()))
+    }
+
+    /// 기록 추가 (파일 끝에 이어 씀)
+    pub fn append(&self, records: &[RateRecord]) -> Result<()> {
+        fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create directory {}", self.dir.display()))?;
+
+        // 날짜가 바뀌는 경계에서는 기록이 두 파일로 나뉠 수 있음
+        let mut by_file: HashMap<PathBuf, String> = HashMap::new();
+        for record in records {
+            by_file
+                .entry(self.file_for(record.time))
+                .or_default()
+                .push_str(&record.to_line());
+        }
+
+        for (path, lines) in by_file {
+            // 한 번의 write로 기록하여 다른 nbmon 프로세스의 기록과 줄이 섞이지 않도록 함
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .and_then(|mut file| file.write_all(lines.as_bytes()))
+                .with_context(|| format!("Failed to append to {}", path.display()))?;
+        }
+        Ok(())
+    }
+
+    /// [since, until) 범위의 기록을 시간순으로 읽음 (interface가 주어지면 해당 인터페이스만)
+    pub fn read(&self, since: i64, until: i64, interface: Option<&str>) -> Result<Vec<RateRecord>> {
+        let mut records = Vec::new();
+
+        let mut day = since.div_euclid(SECS_PER_DAY) * SECS_PER_DAY;
+        while day < until {
+            let path = self.file_for(day);
+            day += SECS_PER_DAY;
+            if !path.exists() {
+                continue;
+            }
+
+            let contents = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            records.extend(
+                contents
+                    .lines()
+                    .filter_map(RateRecord::from_line)
+                    .filter(|record| record.time >= since && record.time < until)
+                    .filter(|record| interface.is_none_or(|name| name == record.interface)),
+            );
+        }
+
+        records.sort_by(|a, b| (a.time, &a.interface).cmp(&(b.time, &b.interface)));
+        Ok(records)
+    }
+
+    /// 보관 기간이 지난 파일 삭제
+    pub fn prune(&self, now: i64) -> Result<()> {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return Ok(()); // 아직 기록이 없음
+        };
+
+        // 파일 이름이 "YYYY-MM-DD.tsv"이므로 문자열 비교로 오래된 파일을 찾을 수 있음
+        let oldest = format!(
+            "{}.tsv",
+            DateTime::from_unix(now - RATE_RETENTION_DAYS * SECS_PER_DAY, 0).day_key()
+        );
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.ends_with(".tsv") && name < oldest {
+                fs::remove_file(entry.path())
+                    .with_context(|| format!("Failed to remove {}", entry.path().display()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// 진행 중인 1분의 누적값
+#[derive(Debug, Clone, Copy)]
+struct MinuteAccumulator {
+    minute: i64,
+    rx_sum: f64,
+    rx_max: f64,
+    tx_sum: f64,
+    tx_max: f64,
+    count: u32,
+}
+
+impl MinuteAccumulator {
+    fn new(minute: i64) -> Self {
+        Self {
+            minute,
+            rx_sum: 0.0,
+            rx_max: 0.0,
+            tx_sum: 0.0,
+            tx_max: 0.0,
+            count: 0,
+        }
+    }
+
+    fn push(&mut self, bandwidth: &BandwidthStats) {
+        self.rx_sum += bandwidth.download_rate;
+        self.tx_sum += bandwidth.upload_rate;
+        self.rx_max = self.rx_max.max(bandwidth.download_rate);
+        self.tx_max = self.tx_max.max(bandwidth.upload_rate);
+        self.count += 1;
+    }
+
+    fn finish(&self, interface: &str) -> RateRecord {
+        let count = self.count.max(1) as f64;
+        RateRecord {
+            time: self.minute,
+            interface: interface.to_string(),
+            rx_avg: self.rx_sum / count,
+            rx_max: self.rx_max,
+            tx_avg: self.tx_sum / count,
+            tx_max: self.tx_max,
+        }
+    }
+}
+
+/// 매 틱의 속도를 분 단위 기록으로 모으는 집계기
+#[derive(Default)]
+pub struct MinuteAggregator {
+    current: HashMap<String, MinuteAccumulator>,
+}
+
+impl MinuteAggregator {
+    /// 이번 틱의 속도를 더하고 끝난 분의 기록을 반환
+    /// 종료 시 진행 중이던 분은 기록하지 않음 (1분 미만의 평균으로 합계가 왜곡되지 않도록)
+    pub fn push(
+        &mut self,
+        interfaces: &[NetworkInterface],
+        bandwidth: &[Option<BandwidthStats>],
+        now: i64,
+    ) -> Vec<RateRecord> {
+        let minute = now.div_euclid(SECS_PER_MINUTE) * SECS_PER_MINUTE;
+        let mut completed = Vec::new();
+
+        for (interface, bandwidth) in interfaces.iter().zip(bandwidth) {
+            let Some(bandwidth) = bandwidth else {
+                continue;
+            };
+            if interface.is_loopback {
+                continue;
+            }
+
+            let accumulator = self
+                .current
+                .entry(interface.name.clone())
+                .or_insert_with(|| MinuteAccumulator::new(minute));
+            if accumulator.minute != minute {
+                completed.push(accumulator.finish(&interface.name));
+                *accumulator = MinuteAccumulator::new(minute);
+            }
+            accumulator.push(bandwidth);
+        }
+
+        completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_round_trip_and_range() {
+        let dir = std::env::temp_dir().join(format!("nbmon-rates-{}", std::process::id()));
+        let log = RateLog::new(dir.clone());
+
+        // UTC 자정을 사이에 둔 두 기록은 서로 다른 파일에 저장됨
+        let midnight = 1_700_006_400;
+        let record = |time: i64, interface: &str| RateRecord {
+            time,
+            interface: interface.to_string(),
+            rx_avg: 1_000.0,
+            rx_max: 2_000.0,
+            tx_avg: 10.0,
+            tx_max: 20.0,
+        };
+        log.append(&[
+            record(midnight - 60, "eth0"),
+            record(midnight, "eth0"),
+            record(midnight, "wlan0"),
+        ])
+        .unwrap();
+
+        let all = log.read(midnight - 3_600, midnight + 3_600, None).unwrap();
+        assert_eq!(all.len(), 3);
+        let eth0 = log.read(midnight, midnight + 60, Some("eth0")).unwrap();
+        assert_eq!(eth0, vec![record(midnight, "eth0")]);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,96 @@
+// 집계 데이터베이스를 사람이 읽기 쉬운 표로 출력하는 모듈 (nbmon report)
+
+use std::fmt::Write;
+
+use crate::storage::accounting::{AccountingDb, InterfaceAccount, Period, Traffic};
+use crate::utils::format;
+
+// 단위별로 표시할 최근 버킷 수 (vnstat 기본값과 비슷하게)
+const HOURLY_ROWS: usize = 24;
+const DAILY_ROWS: usize = 30;
+const MONTHLY_ROWS: usize = 12;
+
+// 열 너비
+const KEY_WIDTH: usize = 16;
+const VALUE_WIDTH: usize = 13;
+
+/// 단위별 기본 표시 행 수
+pub fn default_rows(period: Period) -> usize {
+    match period {
+        Period::Hourly => HOURLY_ROWS,
+        Period::Daily => DAILY_ROWS,
+        Period::Monthly => MONTHLY_ROWS,
+    }
+}
+
+/// 모든 인터페이스(또는 지정한 인터페이스)의 집계를 표로 만듦
+pub fn render_report(db: &AccountingDb, period: Period, interface: Option<&str>) -> String {
+    let mut out = String::new();
+
+    let accounts: Vec<(&String, &InterfaceAccount)> = db
+        .interfaces
+        .iter()
+        .filter(|(name, _)| interface.is_none_or(|wanted| wanted == name.as_str()))
+        .collect();
+
+    if accounts.is_empty() {
+        match interface {
+            Some(name) => out.push_str(&format!("No traffic recorded for '{}' yet.\n", name)),
+            None => out.push_str("No traffic recorded yet. Run nbmon for a while first.\n"),
+        }
+        return out;
+    }
+
+    for (name, account) in accounts {
+        render_interface(&mut out, name, account, period);
+        out.push('\n');
+    }
+    out
+}
+
+// 인터페이스 하나의 표
+fn render_interface(out: &mut String, name: &str, account: &InterfaceAccount, period: Period) {
+    let entries = account.entries(period);
+    let skip = entries.len().saturating_sub(default_rows(period));
+
+    // writeln!은 String에 대해 실패하지 않으므로 결과를 무시
+    let _ = writeln!(out, " {} ({})", name, period.label());
+    let _ = writeln!(
+        out,
+        " {:<kw$}{:>vw$}{:>vw$}{:>vw$}",
+        period.label(),
+        "rx",
+        "tx",
+        "total",
+        kw = KEY_WIDTH,
+        vw = VALUE_WIDTH
+    );
+    let _ = writeln!(out, " {}", "-".repeat(KEY_WIDTH + VALUE_WIDTH * 3));
+
+    let mut sum = Traffic::default();
+    for (key, traffic) in entries.iter().skip(skip) {
+        sum.rx += traffic.rx;
+        sum.tx += traffic.tx;
+        write_row(out, key, traffic);
+    }
+
+    if entries.is_empty() {
+        let _ = writeln!(out, " (no data)");
+    } else {
+        let _ = writeln!(out, " {}", "-".repeat(KEY_WIDTH + VALUE_WIDTH * 3));
+        write_row(out, "sum", &sum);
+    }
+}
+
+fn write_row(out: &mut String, key: &str, traffic: &Traffic) {
+    let _ = writeln!(
+        out,
+        " {:<kw$}{:>vw$}{:>vw$}{:>vw$}",
+        key,
+        format::format_bytes(traffic.rx),
+        format::format_bytes(traffic.tx),
+        format::format_bytes(traffic.total()),
+        kw = KEY_WIDTH,
+        vw = VALUE_WIDTH
+    );
+}
@@ -10,44 +10,85 @@ use ratatui::{
 };
 use std::time::{Duration, Instant};
 
-use crate::network::parallel_stats::collect_all_stats_parallel;
+use crate::config::{Colors, KeyBindings};
+use crate::export::csv::CsvWriter;
+use crate::i18n::{t, tf, Msg};
 use crate::network::{
-    interface::NetworkInterface,
-    public_ip,
-    stats::{BandwidthStats, InterfaceStats},
+    filter::InterfaceFilter, history::RollupSeries, interface::NetworkInterface, public_ip,
+    sampler::Sampler,
 };
+use crate::storage::accounting::Recorder;
+use crate::ui::MonitorOptions;
 use crate::utils::format;
 
+// 인터페이스별로 1초 해상도로 보관할 히스토리 샘플 수 (60초)
+const HISTORY_SIZE: usize = 60;
+// 목록의 각 행에 표시할 미니 스파크라인 길이
+const MINI_SPARKLINE_WIDTH: usize = 20;
+
 pub struct App {
-    pub interfaces: Vec<NetworkInterface>,
-    pub interface_stats: Vec<InterfaceStats>,
-    pub bandwidth_stats: Vec<Option<BandwidthStats>>,
+    pub sampler: Sampler,
+    pub recorder: Option<Recorder>,
+    pub csv: Option<CsvWriter>,
+    pub filter: InterfaceFilter,
+    // 필터를 통과한 인터페이스의 인덱스들 (목록에 표시되는 순서)
+    pub visible: Vec<usize>,
+    // visible에서의 선택 위치
     pub selected_interface: usize,
     pub last_update: Instant,
     pub update_interval: Duration,
+    pub colors: Colors,
+    pub keys: KeyBindings,
     pub should_quit: bool,
 }
 
 impl App {
     pub fn new(interfaces: Vec<NetworkInterface>) -> Result<Self> {
-        let interface_count = interfaces.len();
-        let mut interface_stats = Vec::new();
-
-        // Initialize stats for all interfaces
-        for interface in &interfaces {
-            match crate::network::stats::get_interface_stats(interface.index) {
-                Ok(stats) => interface_stats.push(stats),
-                Err(_) => interface_stats.push(InterfaceStats::new(interface.index)),
-            }
+        Self::with_options(interfaces, &MonitorOptions::default())
+    }
+
+    pub fn with_options(
+        interfaces: Vec<NetworkInterface>,
+        options: &MonitorOptions,
+    ) -> Result<Self> {
+        // Initialize stats and history buffers for all interfaces
+        let sampler = Sampler::new(
+            interfaces,
+            options.history.unwrap_or(HISTORY_SIZE),
+            options.interval,
+        );
+        Self::with_sampler(sampler, options)
+    }
+
+    // 데몬이나 원격 에이전트를 따라가는 Sampler로 시작
+    pub fn with_sampler(sampler: Sampler, options: &MonitorOptions) -> Result<Self> {
+        let interfaces = sampler.interfaces();
+        let visible = options.filters.visible_indices(interfaces);
+        if visible.is_empty() {
+            anyhow::bail!(t(Msg::NoInterfacesMatch));
         }
 
+        // --interface로 지정한 인터페이스를 처음에 선택
+        let selected_interface = match &options.interface {
+            Some(name) => visible
+                .iter()
+                .position(|&idx| interfaces[idx].name == *name)
+                .ok_or_else(|| anyhow::anyhow!(tf(Msg::ErrInterfaceHidden, &[name])))?,
+            None => 0,
+        };
+
         Ok(Self {
-            interfaces,
-            interface_stats,
-            bandwidth_stats: vec![None; interface_count],
-            selected_interface: 0,
+            sampler,
+            // 데이터 디렉터리를 알 수 없으면 트래픽 집계 없이 실행
+            recorder: Recorder::new().ok(),
+            csv: None,
+            filter: options.filters.clone(),
+            visible,
+            selected_interface,
             last_update: Instant::now(),
-            update_interval: Duration::from_secs(1),
+            update_interval: options.interval,
+            colors: options.colors.clone(),
+            keys: options.keys,
             should_quit: false,
         })
     }
@@ -59,26 +100,46 @@ impl App {
             if event::poll(Duration::from_millis(100))? {
                 if let Event::Key(key) = event::read()? {
                     if key.kind == KeyEventKind::Press {
-                        match key.code {
-                            KeyCode::Char('q') => {
+                        // 설정 파일의 [keys]에서 바꾼 문자 키도 화살표 키와 같이 동작
+                        let keys = self.keys;
+                        let code = match key.code {
+                            KeyCode::Char(c) if c == keys.previous_interface => KeyCode::Up,
+                            KeyCode::Char(c) if c == keys.next_interface => KeyCode::Down,
+                            code => code,
+                        };
+                        match code {
+                            KeyCode::Char(c) if c == keys.quit => {
                                 self.should_quit = true;
                                 break;
                             }
-                            KeyCode::Up => {
-                                if self.selected_interface > 0 {
-                                    self.selected_interface -= 1;
-                                }
+                            KeyCode::Up if self.selected_interface > 0 => {
+                                self.selected_interface -= 1;
                             }
-                            KeyCode::Down => {
-                                if self.selected_interface < self.interfaces.len().saturating_sub(1)
-                                {
-                                    self.selected_interface += 1;
-                                }
+                            KeyCode::Down
+                                if self.selected_interface
+                                    < self.visible.len().saturating_sub(1) =>
+                            {
+                                self.selected_interface += 1;
                             }
-                            KeyCode::Char(' ') => {
+                            KeyCode::Char(c) if c == keys.refresh => {
                                 // Force update
                                 self.update_stats()?;
                             }
+                            KeyCode::Char(c) if c == keys.toggle_patterns => {
+                                self.change_filter(|filter| {
+                                    filter.patterns_paused = !filter.patterns_paused
+                                })
+                            }
+                            KeyCode::Char(c) if c == keys.toggle_virtual => self
+                                .change_filter(|filter| filter.hide_virtual = !filter.hide_virtual),
+                            KeyCode::Char(c) if c == keys.toggle_down => {
+                                self.change_filter(|filter| filter.show_down = !filter.show_down)
+                            }
+                            KeyCode::Char(c) if c == keys.toggle_loopback => {
+                                self.change_filter(|filter| {
+                                    filter.show_loopback = !filter.show_loopback
+                                })
+                            }
                             _ => {}
                         }
                     }
@@ -90,43 +151,56 @@ impl App {
                 self.update_stats()?;
             }
         }
+
+        // 종료 전에 마지막 집계를 디스크에 반영
+        if let Some(recorder) = &mut self.recorder {
+            recorder.flush(&self.sampler);
+        }
         Ok(())
     }
 
+    pub fn interfaces(&self) -> &[NetworkInterface] {
+        self.sampler.interfaces()
+    }
+
+    // 목록에서 선택된 인터페이스
+    pub fn selected(&self) -> Option<&NetworkInterface> {
+        self.visible
+            .get(self.selected_interface)
+            .map(|&idx| &self.interfaces()[idx])
+    }
+
+    // 필터를 바꾸고 표시 목록을 다시 계산 (선택은 유지, 아무것도 남지 않는 변경은 무시)
+    fn change_filter(&mut self, change: impl FnOnce(&mut InterfaceFilter)) {
+        let mut filter = self.filter.clone();
+        change(&mut filter);
+
+        let visible = filter.visible_indices(self.interfaces());
+        if visible.is_empty() {
+            return;
+        }
+
+        let selected = self.visible.get(self.selected_interface).copied();
+        self.selected_interface = visible
+            .iter()
+            .position(|&idx| Some(idx) == selected)
+            .unwrap_or(0);
+        self.visible = visible;
+        self.filter = filter;
+    }
+
     fn update_stats(&mut self) -> Result<()> {
-        let prev_stats = self.interface_stats.clone();
-
-        // 병렬로 모든 인터페이스의 통계 수집
-        match collect_all_stats_parallel(&self.interfaces) {
-            Ok(new_stats) => {
-                // 성공적으로 수집된 통계 업데이트
-                for (i, current_stats) in new_stats.into_iter().enumerate() {
-                    if i < prev_stats.len() {
-                        self.bandwidth_stats[i] = current_stats.calculate_bandwidth(&prev_stats[i]);
-                    }
-                    if i < self.interface_stats.len() {
-                        self.interface_stats[i] = current_stats;
-                    }
-                }
-            }
-            Err(e) => {
-                // 병렬 수집 실패 시 순차 수집으로 폴백
-                eprintln!(
-                    "Warning: Parallel stats collection failed: {}, using sequential fallback",
-                    e
-                );
-                for (i, interface) in self.interfaces.iter().enumerate() {
-                    if let Ok(current_stats) =
-                        crate::network::stats::get_interface_stats(interface.index)
-                    {
-                        if i < prev_stats.len() {
-                            self.bandwidth_stats[i] =
-                                current_stats.calculate_bandwidth(&prev_stats[i]);
-                        }
-                        self.interface_stats[i] = current_stats;
-                    }
-                }
-            }
+        // 모든 인터페이스의 통계 수집 (병렬, 실패시 순차 폴백)
+        self.sampler.update()?;
+
+        // --csv로 지정한 파일에 이번 틱 기록
+        if let Some(csv) = &mut self.csv {
+            csv.write_tick(&self.sampler)?;
+        }
+
+        // 분 단위 속도 기록과 주기적인 트래픽 집계 파일 갱신
+        if let Some(recorder) = &mut self.recorder {
+            recorder.maybe_flush(&self.sampler);
         }
 
         self.last_update = Instant::now();
@@ -152,27 +226,32 @@ impl App {
     }
 
     fn render_header(&self, f: &mut Frame, area: Rect) {
-        let header = Paragraph::new("NBMon - Cross-platform Network Bandwidth Monitor")
-            .style(Style::default().fg(Color::Cyan))
-            .block(Block::default().borders(Borders::ALL).title("Status"));
+        let header = Paragraph::new(t(Msg::TitleClassic))
+            .style(Style::default().fg(self.colors.title))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(t(Msg::BlockStatus)),
+            );
         f.render_widget(header, area);
     }
 
     fn render_interface_list(&self, f: &mut Frame, area: Rect) {
         let items: Vec<ListItem> = self
-            .interfaces
+            .visible
             .iter()
-            .enumerate()
-            .map(|(i, interface)| {
-                let bandwidth_text = if let Some(Some(bandwidth)) = self.bandwidth_stats.get(i) {
-                    format!(
-                        " ↓{} ↑{}",
-                        format::format_bytes_per_sec(bandwidth.download_rate),
-                        format::format_bytes_per_sec(bandwidth.upload_rate)
-                    )
-                } else {
-                    String::new()
-                };
+            .map(|&i| {
+                let interface = &self.interfaces()[i];
+                let bandwidth_text =
+                    if let Some(Some(bandwidth)) = self.sampler.all_bandwidth().get(i) {
+                        format!(
+                            " ↓{} ↑{}",
+                            format::format_bytes_per_sec(bandwidth.download_rate),
+                            format::format_bytes_per_sec(bandwidth.upload_rate)
+                        )
+                    } else {
+                        String::new()
+                    };
 
                 let status_color = if interface.is_up {
                     Color::Green
@@ -182,7 +261,14 @@ impl App {
 
                 let mut spans = vec![
                     Span::styled(
-                        format!("[{}] ", if interface.is_up { "UP" } else { "DOWN" }),
+                        format!(
+                            "[{}] ",
+                            t(if interface.is_up {
+                                Msg::StatusUp
+                            } else {
+                                Msg::StatusDown
+                            })
+                        ),
                         Style::default().fg(status_color),
                     ),
                     Span::raw(format!("{} ", interface.display_name())),
@@ -191,7 +277,21 @@ impl App {
                 if !bandwidth_text.is_empty() {
                     spans.push(Span::styled(
                         bandwidth_text,
-                        Style::default().fg(Color::Yellow),
+                        Style::default().fg(self.colors.label),
+                    ));
+                }
+
+                // 모든 행에 최근 다운로드/업로드 미니 스파크라인 표시
+                if let Some(history) = self.sampler.history(i).filter(|h| !h.is_empty()) {
+                    spans.push(Span::raw(" "));
+                    spans.push(Span::styled(
+                        mini_sparkline(history.download(), history.max_download_rate()),
+                        Style::default().fg(self.colors.download),
+                    ));
+                    spans.push(Span::raw(" "));
+                    spans.push(Span::styled(
+                        mini_sparkline(history.upload(), history.max_upload_rate()),
+                        Style::default().fg(self.colors.upload),
                     ));
                 }
 
@@ -200,11 +300,10 @@ impl App {
             .collect();
 
         let list = List::new(items)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title("Network Interfaces"),
-            )
+            .block(Block::default().borders(Borders::ALL).title(tf(
+                Msg::BlockInterfaces,
+                &[&self.filter.summary_or_default()],
+            )))
             .highlight_style(Style::default().bg(Color::DarkGray))
             .highlight_symbol("> ");
 
@@ -217,22 +316,25 @@ impl App {
     }
 
     fn render_interface_details(&self, f: &mut Frame, area: Rect) {
-        if let Some(interface) = self.interfaces.get(self.selected_interface) {
+        if let Some(interface) = self.selected() {
             let mut lines = vec![
                 Line::from(vec![
-                    Span::raw("Name: "),
-                    Span::styled(&interface.name, Style::default().fg(Color::Cyan)),
+                    Span::raw(format!("{}: ", t(Msg::LabelName))),
+                    Span::styled(&interface.name, Style::default().fg(self.colors.title)),
                 ]),
                 Line::from(vec![
-                    Span::raw("Index: "),
+                    Span::raw(format!("{}: ", t(Msg::LabelIndex))),
                     Span::raw(interface.index.to_string()),
                 ]),
-                Line::from(vec![Span::raw("MAC: "), Span::raw(&interface.mac_address)]),
+                Line::from(vec![
+                    Span::raw(format!("{}: ", t(Msg::LabelMac))),
+                    Span::raw(&interface.mac_address),
+                ]),
             ];
 
             if interface.speed > 0 {
                 lines.push(Line::from(vec![
-                    Span::raw("Speed: "),
+                    Span::raw(format!("{}: ", t(Msg::LabelSpeed))),
                     Span::styled(
                         format::format_bits_per_sec(interface.speed),
                         Style::default().fg(Color::Green),
@@ -245,43 +347,57 @@ impl App {
                 let is_private = public_ip::is_private_ip(local_ip);
                 let local_str = local_ip.to_string();
 
-                if is_private {
+                if is_private && public_ip::is_enabled() {
                     // 사설 IP인 경우 Public IP도 함께 표시
                     if let Some(public_ip) = public_ip::get_public_ip() {
-                        format!("{} (Public: {})", local_str, public_ip)
+                        tf(Msg::IpWithPublic, &[&local_str, &public_ip])
                     } else {
-                        format!("{} (Fetching public IP...)", local_str)
+                        tf(Msg::IpFetchingPublic, &[&local_str])
                     }
                 } else {
                     // 이미 공인 IP인 경우
                     local_str
                 }
             } else if cfg!(unix) {
-                "None (install 'ip' or 'ifconfig')".to_string()
+                t(Msg::IpNoneInstallTools).to_string()
             } else {
-                "None".to_string()
+                t(Msg::IpNone).to_string()
             };
 
             lines.push(Line::from(vec![
-                Span::raw("IP: "),
-                Span::styled(ip_text, Style::default().fg(Color::Yellow)),
+                Span::raw(format!("{}: ", t(Msg::LabelIp))),
+                Span::styled(ip_text, Style::default().fg(self.colors.label)),
             ]));
 
             let text = Text::from(lines);
             let paragraph = Paragraph::new(text).block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Interface Details"),
+                    .title(t(Msg::BlockDetails)),
             );
             f.render_widget(paragraph, area);
         }
     }
 
     fn render_help(&self, f: &mut Frame, area: Rect) {
-        let help_text = "Controls: ↑/↓ Select interface | Space: Update | q: Quit";
+        let help_text = tf(
+            Msg::HelpClassic,
+            &[
+                &KeyBindings::label(self.keys.previous_interface),
+                &KeyBindings::label(self.keys.next_interface),
+                &KeyBindings::label(self.keys.refresh),
+                &self.keys.filter_hint(),
+                &KeyBindings::label(self.keys.quit),
+            ],
+        );
         let help = Paragraph::new(help_text)
             .style(Style::default().fg(Color::Gray))
             .block(Block::default().borders(Borders::ALL));
         f.render_widget(help, area);
     }
 }
+
+// 히스토리의 최근 MINI_SPARKLINE_WIDTH개 샘플로 미니 스파크라인 생성
+fn mini_sparkline(series: &RollupSeries, max_rate: f64) -> String {
+    format::format_sparkline(&series.recent(MINI_SPARKLINE_WIDTH), max_rate as u64)
+}
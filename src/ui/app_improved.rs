@@ -13,25 +13,37 @@ use ratatui::{
     Frame,
     Terminal, // 화면 그리기 관련
 };
-use std::collections::VecDeque; // 양방향 큐 (히스토리 데이터 저장용)
 use std::time::{Duration, Instant}; // 시간 측정 및 간격 제어
 
-use crate::network::{interface::NetworkInterface, public_ip, stats::InterfaceStats};
-use crate::utils::format; // 데이터 포맷팅 유틸리티
+use crate::config::{Colors, KeyBindings}; // 설정 파일의 색상과 단축키
+use crate::export::csv::CsvWriter; // --csv 틱별 기록
+use crate::i18n::{t, tf, Msg}; // 메시지 카탈로그
+use crate::network::{
+    filter::InterfaceFilter,
+    history::{self, Bucket, InterfaceHistory, RollupSeries, TimeRange},
+    interface::NetworkInterface,
+    public_ip,
+    sampler::Sampler,
+    stats::InterfaceStats,
+};
+use crate::storage::accounting::Recorder; // 트래픽 집계 저장
+use crate::storage::quota::{QuotaSet, QuotaStatus}; // 데이터 사용량 한도
+use crate::ui::MonitorOptions; // --interval/--history/--interface 옵션
+use crate::utils::format;
+use crate::utils::time::{self, DateTime}; // 데이터 포맷팅 유틸리티
 
 // 애플리케이션 설정 상수들 (매직 넘버 제거)
-const HISTORY_SIZE: usize = 60; // 히스토리 데이터를 60초간 보관
-const UPDATE_INTERVAL_SECS: u64 = 1; // 통계 업데이트 간격 (초)
+const HISTORY_SIZE: usize = 600; // 1초 해상도 히스토리를 10분간 보관 (이후는 롤업)
 const POLL_INTERVAL_MS: u64 = 100; // 키 입력 폴링 간격 (밀리초)
-const DEFAULT_MAX_RATE_MBPS: f64 = 1.0; // 기본 최대 속도 (MB/s)
 const HEADER_HEIGHT: u16 = 4; // 헤더 영역 높이
+const QUOTA_GAUGE_HEIGHT: u16 = 1; // 한도가 설정된 인터페이스의 헤더에 추가되는 게이지 높이
+const QUOTA_WARNING_RATIO: f64 = 0.8; // 게이지를 경고 색으로 표시하는 사용 비율
 const HELP_HEIGHT: u16 = 3; // 도움말 영역 높이
 const MIN_SECTION_HEIGHT: u16 = 8; // 트래픽 섹션 최소 높이
 const RATE_DISPLAY_HEIGHT: u16 = 1; // 속도 표시 영역 높이
 const MIN_GRAPH_HEIGHT: u16 = 4; // 그래프 최소 높이
 const LEGEND_WIDTH: u16 = 10; // 범례 영역 너비
 const MIN_GRAPH_WIDTH: u16 = 10; // 그래프 최소 너비
-const RATE_SCALE_MULTIPLIER: f64 = 1.1; // 최대 속도 스케일링 배수
 
 // 에러 처리 개선을 위한 헬퍼 함수들은 메인 impl 블록에 통합됩니다
 
@@ -47,68 +59,111 @@ struct TrafficSectionConfig {
 // 네트워크 인터페이스 정보와 실시간 통계를 관리
 pub struct ImprovedApp {
     // private 필드들로 변경하여 내부 상태를 보호
-    interfaces: Vec<NetworkInterface>, // 모든 네트워크 인터페이스 목록
-    active_interfaces: Vec<usize>,     // 활성화된(UP, 비-루프백) 인터페이스의 인덱스들
-    current_interface_idx: usize,      // active_interfaces에서의 현재 선택된 인덱스
-    interface_stats: Vec<InterfaceStats>, // 각 인터페이스의 통계 정보
-    download_history: VecDeque<u64>,   // 다운로드 속도 히스토리 (바이트/초)
-    upload_history: VecDeque<u64>,     // 업로드 속도 히스토리 (바이트/초)
-    last_update: Instant,              // 마지막 업데이트 시간
-    update_interval: Duration,         // 업데이트 간격 (1초)
-    should_quit: bool,                 // 애플리케이션 종료 플래그
-    max_download_rate: f64,            // 그래프 스케일링용 최대 다운로드 속도
-    max_upload_rate: f64,              // 그래프 스케일링용 최대 업로드 속도
-    public_ip: Option<String>,         // Public IP 주소 (캐시됨)
+    sampler: Sampler,              // 모든 인터페이스의 통계와 인터페이스별 히스토리
+    recorder: Option<Recorder>,    // 디스크 트래픽 집계 (데이터 디렉터리가 없으면 None)
+    quotas: QuotaSet,              // 인터페이스별 데이터 사용량 한도
+    csv: Option<CsvWriter>,        // --csv로 지정한 틱별 기록 파일
+    host: Option<String>,          // 원격 호스트를 볼 때 인터페이스 이름 앞에 표시할 호스트 이름
+    filter: InterfaceFilter,       // 표시할 인터페이스 필터 (TUI에서 켜고 끌 수 있음)
+    active_interfaces: Vec<usize>, // 필터를 통과한 인터페이스의 인덱스들
+    current_interface_idx: usize,  // active_interfaces에서의 현재 선택된 인덱스
+    last_update: Instant,          // 마지막 업데이트 시간
+    update_interval: Duration,     // 업데이트 간격 (기본 1초, --interval)
+    should_quit: bool,             // 애플리케이션 종료 플래그
+    time_range: TimeRange,         // 그래프에 표시할 시간 범위 (줌 단계)
+    colors: Colors,                // 화면 색상 (설정 파일 [colors])
+    keys: KeyBindings,             // 단축키 (설정 파일 [keys])
+    public_ip: Option<String>,     // Public IP 주소 (캐시됨)
     last_public_ip_update: Option<Instant>, // Public IP 마지막 업데이트 시간
 }
 
 // ImprovedApp 구조체의 메서드 구현
 impl ImprovedApp {
-    // 새로운 애플리케이션 인스턴스를 생성하는 생성자
+    // 새로운 애플리케이션 인스턴스를 생성하는 생성자 (기본 옵션)
     pub fn new(interfaces: Vec<NetworkInterface>) -> Result<Self> {
-        // 활성화되고 루프백이 아닌 인터페이스들만 필터링
-        // iterator 체인: iter() → enumerate() → filter() → map() → collect()
-        let active_interfaces: Vec<usize> = interfaces
-            .iter() // immutable 참조 iterator
-            .enumerate() // (index, item) 형태로 변환
-            .filter(|(_, iface)| iface.is_up && !iface.is_loopback) // UP 상태이고 루프백이 아닌 것만
-            .map(|(idx, _)| idx) // 인덱스만 추출
-            .collect(); // Vec<usize>로 수집
-
-        // 활성 인터페이스가 없으면 에러 반환
+        Self::with_options(interfaces, &MonitorOptions::default())
+    }
+
+    // 실행 옵션(업데이트 간격, 히스토리 크기, 처음 선택할 인터페이스)을 지정하는 생성자
+    pub fn with_options(
+        interfaces: Vec<NetworkInterface>,
+        options: &MonitorOptions,
+    ) -> Result<Self> {
+        // 모든 인터페이스의 초기 통계를 읽고 히스토리 버퍼 준비
+        let sampler = Sampler::new(
+            interfaces,
+            options.history.unwrap_or(HISTORY_SIZE),
+            options.interval,
+        );
+        Self::with_sampler(sampler, options)
+    }
+
+    // 미리 만든 Sampler로 시작하는 생성자 (데몬에 붙은 Sampler는 히스토리가 이미 채워져 있음)
+    pub fn with_sampler(sampler: Sampler, options: &MonitorOptions) -> Result<Self> {
+        let interfaces = sampler.interfaces();
+
+        // 필터를 통과한 인터페이스만 표시 (기본: UP 상태이고 루프백이 아닌 것)
+        let active_interfaces = options.filters.visible_indices(interfaces);
+
+        // 표시할 인터페이스가 없으면 에러 반환
         if active_interfaces.is_empty() {
             // anyhow::anyhow!: 에러 메시지로 에러 생성
-            return Err(anyhow::anyhow!("No active network interfaces found"));
+            return Err(anyhow::anyhow!(t(Msg::NoInterfacesMatch)));
         }
 
-        let mut interface_stats = Vec::new();
-        for interface in &interfaces {
-            match crate::network::stats::get_interface_stats(interface.index) {
-                Ok(stats) => interface_stats.push(stats),
-                Err(_) => interface_stats.push(InterfaceStats::new(interface.index)),
-            }
-        }
+        // --interface로 지정한 인터페이스를 처음에 선택
+        let current_interface_idx = match &options.interface {
+            Some(name) => active_interfaces
+                .iter()
+                .position(|&idx| interfaces[idx].name == *name)
+                .ok_or_else(|| anyhow::anyhow!(tf(Msg::ErrInterfaceHidden, &[name])))?,
+            None => 0,
+        };
 
         // 백그라운드에서 Public IP 가져오기 시작
         public_ip::update_public_ip_async();
 
         Ok(Self {
-            interfaces,
+            sampler,
+            recorder: Recorder::new().ok(),
+            // 한도 설정을 읽지 못하면 한도 없이 실행
+            quotas: QuotaSet::default_path()
+                .and_then(|path| QuotaSet::load(&path))
+                .unwrap_or_default(),
+            csv: None,
+            host: None,
+            filter: options.filters.clone(),
             active_interfaces,
-            current_interface_idx: 0,
-            interface_stats,
-            download_history: VecDeque::with_capacity(HISTORY_SIZE),
-            upload_history: VecDeque::with_capacity(HISTORY_SIZE),
+            current_interface_idx,
             last_update: Instant::now(),
-            update_interval: Duration::from_secs(UPDATE_INTERVAL_SECS),
+            update_interval: options.interval,
             should_quit: false,
-            max_download_rate: DEFAULT_MAX_RATE_MBPS * 1024.0 * 1024.0, // MB/s를 bytes/s로 변환
-            max_upload_rate: DEFAULT_MAX_RATE_MBPS * 1024.0 * 1024.0,
+            time_range: TimeRange::OneMinute,
+            colors: options.colors.clone(),
+            keys: options.keys,
             public_ip: None,
             last_public_ip_update: None,
         })
     }
 
+    // 트래픽 집계를 기록하지 않음 (원격 호스트를 볼 때, builder 스타일)
+    pub fn without_recorder(mut self) -> Self {
+        self.recorder = None;
+        self
+    }
+
+    // 헤더의 인터페이스 이름 앞에 호스트 이름 표시 (원격 호스트를 볼 때, builder 스타일)
+    pub fn with_host(mut self, host: &str) -> Self {
+        self.host = Some(host.to_string());
+        self
+    }
+
+    // 매 틱의 통계를 CSV 파일에도 기록하도록 설정 (builder 스타일)
+    pub fn with_csv(mut self, csv: CsvWriter) -> Self {
+        self.csv = Some(csv);
+        self
+    }
+
     // 메인 애플리케이션 루프 - TUI를 실행하고 사용자 입력 처리
     // <B: Backend>: 제네릭 타입 매개변수, Backend trait를 구현한 타입
     pub fn run<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<()> {
@@ -139,6 +194,11 @@ impl ImprovedApp {
                 self.update_stats()?;
             }
         }
+
+        // 종료 전에 마지막 집계를 디스크에 반영
+        if let Some(recorder) = &mut self.recorder {
+            recorder.flush(&self.sampler);
+        }
         Ok(()) // 정상 종료
     }
 
@@ -147,33 +207,54 @@ impl ImprovedApp {
     // 현재 선택된 인터페이스 정보 반환
     pub fn current_interface(&self) -> &NetworkInterface {
         let interface_idx = self.active_interfaces[self.current_interface_idx];
-        &self.interfaces[interface_idx]
+        &self.sampler.interfaces()[interface_idx]
     }
 
     // 현재 인터페이스의 통계 정보 반환
     pub fn current_interface_stats(&self) -> &InterfaceStats {
         let interface_idx = self.active_interfaces[self.current_interface_idx];
-        &self.interface_stats[interface_idx]
+        &self.sampler.all_stats()[interface_idx]
+    }
+
+    // 현재 인터페이스의 히스토리 (백그라운드에서 모든 인터페이스가 수집됨)
+    pub fn current_history(&self) -> &InterfaceHistory {
+        let interface_idx = self.active_interfaces[self.current_interface_idx];
+        self.sampler
+            .history(interface_idx)
+            .expect("every interface has a history buffer")
     }
 
     // 다운로드 히스토리에 대한 읽기 전용 접근
-    pub fn download_history(&self) -> &VecDeque<u64> {
-        &self.download_history
+    pub fn download_history(&self) -> &RollupSeries {
+        self.current_history().download()
     }
 
     // 업로드 히스토리에 대한 읽기 전용 접근
-    pub fn upload_history(&self) -> &VecDeque<u64> {
-        &self.upload_history
+    pub fn upload_history(&self) -> &RollupSeries {
+        self.current_history().upload()
+    }
+
+    // 현재 그래프 시간 범위 반환
+    pub fn time_range(&self) -> TimeRange {
+        self.time_range
+    }
+
+    // 현재 인터페이스의 청구 주기 사용 현황 (한도가 없거나 집계 전이면 None)
+    pub fn current_quota_status(&self) -> Option<QuotaStatus> {
+        let interface = self.current_interface();
+        let quota = self.quotas.get(&interface.name)?;
+        let db = self.recorder.as_ref()?.database()?;
+        Some(quota.status(db.interfaces.get(&interface.name), time::now_unix()))
     }
 
     // 최대 다운로드 속도 반환
     pub fn max_download_rate(&self) -> f64 {
-        self.max_download_rate
+        self.current_history().max_download_rate()
     }
 
     // 최대 업로드 속도 반환
     pub fn max_upload_rate(&self) -> f64 {
-        self.max_upload_rate
+        self.current_history().max_upload_rate()
     }
 
     // 애플리케이션 종료 상태 확인
@@ -191,66 +272,110 @@ impl ImprovedApp {
     // 안전한 현재 인터페이스 인덱스 접근
     fn get_current_interface_index(&self) -> Result<usize> {
         if self.current_interface_idx >= self.active_interfaces.len() {
-            return Err(anyhow::anyhow!(
-                "Current interface index {} is out of bounds (max: {})",
-                self.current_interface_idx,
-                self.active_interfaces.len().saturating_sub(1)
-            ));
+            return Err(anyhow::anyhow!(tf(
+                Msg::ErrIndexOutOfBounds,
+                &[
+                    &self.current_interface_idx,
+                    &self.active_interfaces.len().saturating_sub(1)
+                ]
+            )));
         }
         Ok(self.active_interfaces[self.current_interface_idx])
     }
 
-    // 컨텍스트가 포함된 통계 정보 가져오기
-    fn get_interface_stats_with_context(
-        &self,
-        interface_index: u32,
-    ) -> Result<crate::network::stats::InterfaceStats> {
-        crate::network::stats::get_interface_stats(interface_index).with_context(|| {
-            format!(
-                "Failed to get network statistics for interface {}",
-                interface_index
-            )
-        })
-    }
-
     // 키 이벤트 처리를 분리한 메서드 (대형 메서드 분할)
     // 반환값: true면 애플리케이션 종료, false면 계속 실행
+    // 문자 키는 설정 파일의 [keys]로 바꿀 수 있음 (괄호 안은 기본값)
     fn handle_key_event(&mut self, key_code: KeyCode) -> Result<bool> {
+        let keys = self.keys;
+        // 화살표 키는 항상 이전/다음 인터페이스
+        let key_code = match key_code {
+            KeyCode::Left => KeyCode::Char(keys.previous_interface),
+            KeyCode::Right => KeyCode::Char(keys.next_interface),
+            other => other,
+        };
         match key_code {
-            KeyCode::Char('q') => {
+            KeyCode::Char(c) if c == keys.quit => {
                 // 'q' 키: 종료
                 self.should_quit = true;
                 Ok(true) // 종료 요청
             }
-            KeyCode::Left | KeyCode::Char('h') => {
+            KeyCode::Char(c) if c == keys.previous_interface => {
                 // 왼쪽 화살표 또는 'h': 이전 인터페이스
                 self.switch_to_previous_interface();
                 Ok(false)
             }
-            KeyCode::Right | KeyCode::Char('l') => {
+            KeyCode::Char(c) if c == keys.next_interface => {
                 // 오른쪽 화살표 또는 'l': 다음 인터페이스
                 self.switch_to_next_interface();
                 Ok(false)
             }
-            KeyCode::Char(' ') => {
+            KeyCode::Char(c) if c == keys.refresh => {
                 // 스페이스바: 수동 업데이트
                 self.update_stats()?;
                 Ok(false)
             }
-            KeyCode::Char('r') => {
+            KeyCode::Char(c) if c == keys.reset_history => {
                 // 'r' 키: 히스토리 리셋
                 self.clear_history();
                 Ok(false)
             }
+            KeyCode::Char(c) if c == keys.zoom_in || (c == '=' && keys.zoom_in == '+') => {
+                // '+' 키: 더 짧은 시간 범위로 줌 인 (24h → 1h → 10m → 1m)
+                self.time_range = self.time_range.zoom_in();
+                Ok(false)
+            }
+            KeyCode::Char(c) if c == keys.zoom_out => {
+                // '-' 키: 더 긴 시간 범위로 줌 아웃 (1m → 10m → 1h → 24h)
+                self.time_range = self.time_range.zoom_out();
+                Ok(false)
+            }
+            // 필터 토글: 'f' 이름 패턴, 'v' 가상, 'd' DOWN, 'o' 루프백
+            KeyCode::Char(c) if c == keys.toggle_patterns => {
+                self.change_filter(|filter| filter.patterns_paused = !filter.patterns_paused);
+                Ok(false)
+            }
+            KeyCode::Char(c) if c == keys.toggle_virtual => {
+                self.change_filter(|filter| filter.hide_virtual = !filter.hide_virtual);
+                Ok(false)
+            }
+            KeyCode::Char(c) if c == keys.toggle_down => {
+                self.change_filter(|filter| filter.show_down = !filter.show_down);
+                Ok(false)
+            }
+            KeyCode::Char(c) if c == keys.toggle_loopback => {
+                self.change_filter(|filter| filter.show_loopback = !filter.show_loopback);
+                Ok(false)
+            }
             _ => Ok(false), // 다른 키는 무시
         }
     }
 
+    // 필터를 바꾸고 표시 목록을 다시 계산
+    // 선택된 인터페이스가 남아 있으면 계속 선택하고, 아무것도 남지 않는 변경은 무시
+    fn change_filter(&mut self, change: impl FnOnce(&mut InterfaceFilter)) {
+        let mut filter = self.filter.clone();
+        change(&mut filter);
+
+        let active_interfaces = filter.visible_indices(self.sampler.interfaces());
+        if active_interfaces.is_empty() {
+            return;
+        }
+
+        let selected = self.active_interfaces[self.current_interface_idx];
+        self.current_interface_idx = active_interfaces
+            .iter()
+            .position(|&idx| idx == selected)
+            .unwrap_or(0);
+        self.active_interfaces = active_interfaces;
+        self.filter = filter;
+    }
+
     // 이전 인터페이스로 전환 (메서드 분할)
+    // 모든 인터페이스의 히스토리가 백그라운드로 유지되므로 전환시 초기화하지 않음
     fn switch_to_previous_interface(&mut self) {
         if self.current_interface_idx > 0 {
             self.current_interface_idx -= 1;
-            self.clear_history(); // 히스토리 초기화
         }
     }
 
@@ -259,15 +384,14 @@ impl ImprovedApp {
         // saturating_sub(): 언더플로우 방지 (0보다 작아지면 0)
         if self.current_interface_idx < self.active_interfaces.len().saturating_sub(1) {
             self.current_interface_idx += 1;
-            self.clear_history();
         }
     }
 
     fn update_stats(&mut self) -> Result<()> {
-        // Public IP 업데이트 (5분마다 또는 처음)
+        // Public IP 업데이트 (설정한 간격마다(기본 5분) 또는 처음)
         let should_update_public_ip = self
             .last_public_ip_update
-            .map(|last| last.elapsed() > Duration::from_secs(300))
+            .map(|last| last.elapsed() > public_ip::refresh_interval())
             .unwrap_or(true);
 
         if should_update_public_ip {
@@ -277,54 +401,33 @@ impl ImprovedApp {
             }
         }
 
-        // 개선된 에러 처리: 안전한 인덱스 접근
-        let interface_idx = self.get_current_interface_index()?;
-        let interface = &self.interfaces[interface_idx];
-
-        // 개선된 에러 처리: 컨텍스트가 포함된 통계 정보 가져오기
-        match self.get_interface_stats_with_context(interface.index) {
-            Ok(current_stats) => {
-                if let Some(bandwidth) =
-                    current_stats.calculate_bandwidth(&self.interface_stats[interface_idx])
-                {
-                    // Add to history
-                    self.download_history
-                        .push_back(bandwidth.download_rate as u64);
-                    self.upload_history.push_back(bandwidth.upload_rate as u64);
-
-                    // Trim history
-                    if self.download_history.len() > HISTORY_SIZE {
-                        self.download_history.pop_front();
-                        self.upload_history.pop_front();
-                    }
-
-                    // Update max rates for scaling (스케일링 배수 상수 사용)
-                    self.max_download_rate = self
-                        .max_download_rate
-                        .max(bandwidth.download_rate * RATE_SCALE_MULTIPLIER);
-                    self.max_upload_rate = self
-                        .max_upload_rate
-                        .max(bandwidth.upload_rate * RATE_SCALE_MULTIPLIER);
+        // 선택된 인터페이스만이 아니라 모든 인터페이스를 샘플링
+        // 에러가 발생해도 애플리케이션을 중단하지 않고 로그만 남김
+        match self.sampler.update().context(t(Msg::ErrUpdateStats)) {
+            // CSV 기록 실패는 데이터가 조용히 빠지지 않도록 애플리케이션을 종료
+            Ok(()) => {
+                if let Some(csv) = &mut self.csv {
+                    csv.write_tick(&self.sampler)?;
                 }
-
-                self.interface_stats[interface_idx] = current_stats;
-            }
-            Err(e) => {
-                // 에러가 발생해도 애플리케이션을 중단하지 않고 로그만 남김
-                eprintln!("Warning: {}", e);
             }
+            Err(e) => eprintln!("{}", tf(Msg::Warning, &[&e])),
+        }
+
+        // 분 단위 속도 기록과 주기적인 트래픽 집계 파일 갱신
+        if let Some(recorder) = &mut self.recorder {
+            recorder.maybe_flush(&self.sampler);
         }
 
         self.last_update = Instant::now();
         Ok(())
     }
 
+    // 현재 인터페이스의 히스토리만 초기화 ('r' 키)
     fn clear_history(&mut self) {
-        self.download_history.clear();
-        self.upload_history.clear();
-        // 기본값으로 리셋 (상수 사용)
-        self.max_download_rate = DEFAULT_MAX_RATE_MBPS * 1024.0 * 1024.0;
-        self.max_upload_rate = DEFAULT_MAX_RATE_MBPS * 1024.0 * 1024.0;
+        // 개선된 에러 처리: 안전한 인덱스 접근
+        if let Ok(interface_idx) = self.get_current_interface_index() {
+            self.sampler.clear_history(interface_idx);
+        }
     }
 
     // 메인 UI 렌더링 함수 - 화면을 4개 섹션으로 나누어 구성
@@ -332,10 +435,18 @@ impl ImprovedApp {
         // Layout::default(): 기본 레이아웃 생성
         // Direction::Vertical: 수직으로 분할
         // constraints: 각 영역의 크기 제약 조건
+        // 한도가 설정된 인터페이스는 헤더에 게이지 한 줄 추가
+        let quota_status = self.current_quota_status();
+        let header_height = if quota_status.is_some() {
+            HEADER_HEIGHT + QUOTA_GAUGE_HEIGHT
+        } else {
+            HEADER_HEIGHT
+        };
+
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(HEADER_HEIGHT), // 고정 높이: 헤더 (인터페이스 정보)
+                Constraint::Length(header_height), // 고정 높이: 헤더 (인터페이스 정보)
                 Constraint::Min(MIN_SECTION_HEIGHT), // 최소 높이: 다운로드 섹션 (가변)
                 Constraint::Min(MIN_SECTION_HEIGHT), // 최소 높이: 업로드 섹션 (가변)
                 Constraint::Length(HELP_HEIGHT),   // 고정 높이: 도움말
@@ -343,35 +454,33 @@ impl ImprovedApp {
             .split(f.size()); // 전체 터미널 크기를 위 조건으로 분할
 
         // 각 섹션을 순서대로 렌더링
-        self.render_combined_header(f, chunks[0]); // 헤더 영역
-                                                   // Download와 Upload 섹션을 통합된 메서드로 렌더링
+        self.render_combined_header(f, chunks[0], quota_status.as_ref()); // 헤더 영역
+                                                                          // Download와 Upload 섹션을 통합된 메서드로 렌더링
         self.render_traffic_section(
             f,
             chunks[1],
             &TrafficSectionConfig {
-                title: "Download",
-                label_prefix: "Incoming:",
-                color: Color::Green,
+                title: t(Msg::Download),
+                label_prefix: t(Msg::Incoming),
+                color: self.colors.download,
             },
             self.download_history(),
-            self.max_download_rate(),
         );
 
         self.render_traffic_section(
             f,
             chunks[2],
             &TrafficSectionConfig {
-                title: "Upload",
-                label_prefix: "Outgoing:",
-                color: Color::Red,
+                title: t(Msg::Upload),
+                label_prefix: t(Msg::Outgoing),
+                color: self.colors.upload,
             },
             self.upload_history(),
-            self.max_upload_rate(),
         );
         self.render_help(f, chunks[3]); // 도움말 영역
     }
 
-    fn render_combined_header(&self, f: &mut Frame, area: Rect) {
+    fn render_combined_header(&self, f: &mut Frame, area: Rect, quota: Option<&QuotaStatus>) {
         // 캡슐화된 getter 메서드 사용
         let interface = self.current_interface();
         let stats = self.current_interface_stats();
@@ -379,8 +488,8 @@ impl ImprovedApp {
         // Create single unified header block with program title
         let main_block = Block::default()
             .borders(Borders::ALL)
-            .title("nbmon: Cross-platform Network Bandwidth Monitor")
-            .style(Style::default().fg(Color::Cyan));
+            .title(t(Msg::TitleImproved))
+            .style(Style::default().fg(self.colors.title));
         let inner_area = main_block.inner(area);
         f.render_widget(main_block, area);
 
@@ -388,11 +497,16 @@ impl ImprovedApp {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(1), // Title line
-                Constraint::Length(1), // Details line
+                Constraint::Length(1),                  // Title line
+                Constraint::Length(1),                  // Details line
+                Constraint::Length(QUOTA_GAUGE_HEIGHT), // Quota gauge (한도가 있을 때만)
             ])
             .split(inner_area);
 
+        if let Some(quota) = quota {
+            self.render_quota_gauge(f, chunks[2], quota);
+        }
+
         // Interface name with navigation and speed (getter 메서드 사용)
         let (current_idx, total_count) = self.current_interface_display_info();
         let mut interface_line = format!(
@@ -401,6 +515,9 @@ impl ImprovedApp {
             current_idx,
             total_count
         );
+        if let Some(host) = &self.host {
+            interface_line.insert_str(0, &format!("{}: ", host));
+        }
 
         if interface.speed > 0 {
             interface_line.push_str(&format!(
@@ -412,7 +529,7 @@ impl ImprovedApp {
         let interface_paragraph = Paragraph::new(interface_line)
             .style(
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(self.colors.title)
                     .add_modifier(Modifier::BOLD),
             )
             .alignment(Alignment::Center);
@@ -423,36 +540,47 @@ impl ImprovedApp {
             let is_private = public_ip::is_private_ip(local_ip);
             let local_str = local_ip.to_string();
 
-            if is_private {
+            if is_private && public_ip::is_enabled() {
                 // 사설 IP인 경우 Public IP도 함께 표시
                 if let Some(ref public_ip) = self.public_ip {
-                    format!("{} (Public: {})", local_str, public_ip)
+                    tf(Msg::IpWithPublic, &[&local_str, public_ip])
                 } else {
-                    format!("{} (Public: Loading...)", local_str)
+                    tf(Msg::IpFetchingPublic, &[&local_str])
                 }
             } else {
                 // 이미 공인 IP인 경우
                 local_str
             }
         } else if cfg!(unix) {
-            "None (install 'ip' or 'ifconfig')".to_string()
+            t(Msg::IpNoneInstallTools).to_string()
         } else {
-            "None".to_string()
+            t(Msg::IpNone).to_string()
         };
 
         // Single line with all essential info
         let details_line = vec![
-            Span::styled("MAC: ", Style::default().fg(Color::Yellow)),
+            Span::styled(
+                format!("{}: ", t(Msg::LabelMac)),
+                Style::default().fg(self.colors.label),
+            ),
             Span::raw(&interface.mac_address),
             Span::raw("  "),
-            Span::styled("IP: ", Style::default().fg(Color::Yellow)),
+            Span::styled(
+                format!("{}: ", t(Msg::LabelIp)),
+                Style::default().fg(self.colors.label),
+            ),
             Span::raw(ip_display),
             Span::raw("  "),
-            Span::styled("Total: ", Style::default().fg(Color::Yellow)),
-            Span::raw(format!(
-                "{} ↓ {} ↑",
-                format::format_bytes(stats.bytes_received),
-                format::format_bytes(stats.bytes_sent)
+            Span::styled(
+                format!("{}: ", t(Msg::LabelTotal)),
+                Style::default().fg(self.colors.label),
+            ),
+            Span::raw(tf(
+                Msg::TotalDownUp,
+                &[
+                    &format::format_bytes(stats.bytes_received),
+                    &format::format_bytes(stats.bytes_sent),
+                ],
             )),
         ];
 
@@ -461,6 +589,47 @@ impl ImprovedApp {
         f.render_widget(details_paragraph, chunks[1]);
     }
 
+    // 청구 주기 사용량 게이지 - 사용/남은 양과 현재 평균 속도 기준 한도 도달 예상일
+    fn render_quota_gauge(&self, f: &mut Frame, area: Rect, quota: &QuotaStatus) {
+        let projection = if quota.is_exceeded() {
+            t(Msg::QuotaCapReached).to_string()
+        } else {
+            match quota.projected_exhaustion {
+                Some(eta) => tf(Msg::QuotaCapEta, &[&DateTime::local(eta).day_key()]),
+                None => tf(
+                    Msg::QuotaResets,
+                    &[&DateTime::local(quota.cycle_end).day_key()],
+                ),
+            }
+        };
+
+        let label = tf(
+            Msg::QuotaGauge,
+            &[
+                &format::format_bytes(quota.used),
+                &format::format_bytes(quota.limit),
+                &format!("{:.0}", quota.ratio() * 100.0),
+                &format::format_bytes(quota.remaining()),
+                &projection,
+            ],
+        );
+
+        // 사용 비율에 따라 색상 변경 (정상 → 경고 → 초과)
+        let color = if quota.is_exceeded() {
+            Color::Red
+        } else if quota.ratio() >= QUOTA_WARNING_RATIO {
+            Color::Yellow
+        } else {
+            Color::Green
+        };
+
+        let gauge = Gauge::default()
+            .gauge_style(Style::default().fg(color))
+            .ratio(quota.ratio().min(1.0))
+            .label(label);
+        f.render_widget(gauge, area);
+    }
+
     // 통합된 트래픽 섹션 렌더링 메서드 (DRY 원칙 적용)
     // Download와 Upload 섹션의 중복 코드를 제거하고 하나의 메서드로 통합
     fn render_traffic_section(
@@ -468,13 +637,16 @@ impl ImprovedApp {
         f: &mut Frame,
         area: Rect,
         config: &TrafficSectionConfig,
-        history: &VecDeque<u64>,
-        max_rate: f64,
+        series: &RollupSeries,
     ) {
-        let current_rate = history.back().copied().unwrap_or(0) as f64;
-
-        // Create unified box with internal divisions
-        let main_block = Block::default().borders(Borders::ALL).title(config.title); // 설정에서 제목 가져오기
+        let current_rate = series.latest().unwrap_or(0) as f64;
+        // 현재 줌 단계의 버킷들과 그에 맞는 스케일
+        let buckets = series.range(self.time_range);
+        let max_rate = history::scale_for(&buckets);
+
+        // Create unified box with internal divisions (제목에 시간 범위 표시)
+        let title = format!("{} [{}]", config.title, self.time_range.label());
+        let main_block = Block::default().borders(Borders::ALL).title(title);
         let inner_area = main_block.inner(area);
         f.render_widget(main_block, area);
 
@@ -489,9 +661,9 @@ impl ImprovedApp {
 
         // Current rate gauge (설정에서 라벨과 색상 가져오기)
         let rate_text = format!(
-            "{} {}/s",
+            "{} {}",
             config.label_prefix,
-            format::format_bytes(current_rate as u64)
+            format::format_bytes_per_sec(current_rate)
         );
         let rate_gauge = Gauge::default()
             .gauge_style(Style::default().fg(config.color))
@@ -513,8 +685,8 @@ impl ImprovedApp {
         );
 
         // 히스토리가 있으면 스파크라인과 범례 렌더링 (메서드 분할)
-        if !history.is_empty() {
-            self.render_sparkline_with_legend(f, graph_area, config, history, max_rate);
+        if !buckets.is_empty() {
+            self.render_sparkline_with_legend(f, graph_area, config, &buckets, max_rate);
         }
     }
 
@@ -524,7 +696,7 @@ impl ImprovedApp {
         f: &mut Frame,
         graph_area: Rect,
         config: &TrafficSectionConfig,
-        history: &VecDeque<u64>,
+        buckets: &[Bucket],
         max_rate: f64,
     ) {
         // Split sparkline area into left legend and graph
@@ -536,11 +708,14 @@ impl ImprovedApp {
             ])
             .split(graph_area);
 
+        // 그래프 너비에 맞게 버킷을 합침 - 합쳐도 max가 유지되므로 피크가 보임
+        let buckets = history::downsample(buckets, sparkline_chunks[1].width as usize);
+
         // 범례 렌더링 (메서드 분할)
-        self.render_legend(f, sparkline_chunks[0], config.color, history, max_rate);
+        self.render_legend(f, sparkline_chunks[0], config.color, &buckets, max_rate);
 
         // 스파크라인 그래프 렌더링 (메서드 분할)
-        self.render_sparkline_graph(f, sparkline_chunks[1], config.color, history, max_rate);
+        self.render_sparkline_graph(f, sparkline_chunks[1], config.color, &buckets, max_rate);
     }
 
     // 범례 렌더링 메서드 (Max/Min 표시)
@@ -549,7 +724,7 @@ impl ImprovedApp {
         f: &mut Frame,
         legend_area: Rect,
         color: Color,
-        history: &VecDeque<u64>,
+        buckets: &[Bucket],
         max_rate: f64,
     ) {
         // Left legend split vertically for Max/Min
@@ -562,32 +737,38 @@ impl ImprovedApp {
             .split(legend_area);
 
         // Max legend (top)
-        let max_legend =
-            Paragraph::new(format!("Max\n{}/s", format::format_bytes(max_rate as u64)))
-                .style(Style::default().fg(color))
-                .alignment(Alignment::Center)
-                .block(Block::default().borders(Borders::RIGHT));
+        let max_legend = Paragraph::new(format!(
+            "{}\n{}",
+            t(Msg::Max),
+            format::format_bytes_per_sec(max_rate)
+        ))
+        .style(Style::default().fg(color))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::RIGHT));
         f.render_widget(max_legend, legend_chunks[0]);
 
-        // Min legend (bottom)
-        let min_rate = *history.iter().min().unwrap_or(&0) as f64;
-        let min_legend =
-            Paragraph::new(format!("Min\n{}/s", format::format_bytes(min_rate as u64)))
-                .style(Style::default().fg(Color::Gray))
-                .alignment(Alignment::Center);
+        // Min legend (bottom) - 버킷별 최솟값 중 가장 작은 값
+        let min_rate = buckets.iter().map(|b| b.min).min().unwrap_or(0) as f64;
+        let min_legend = Paragraph::new(format!(
+            "{}\n{}",
+            t(Msg::Min),
+            format::format_bytes_per_sec(min_rate)
+        ))
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
         f.render_widget(min_legend, legend_chunks[1]);
     }
 
-    // 스파크라인 그래프 렌더링 메서드
+    // 스파크라인 그래프 렌더링 메서드 - 각 열은 버킷의 최댓값을 표시
     fn render_sparkline_graph(
         &self,
         f: &mut Frame,
         graph_area: Rect,
         color: Color,
-        history: &VecDeque<u64>,
+        buckets: &[Bucket],
         max_rate: f64,
     ) {
-        let data: Vec<u64> = history.iter().copied().collect();
+        let data: Vec<u64> = buckets.iter().map(|b| b.max).collect();
         let sparkline = Sparkline::default()
             .data(&data)
             .max(max_rate as u64)
@@ -596,11 +777,28 @@ impl ImprovedApp {
     }
 
     fn render_help(&self, f: &mut Frame, area: Rect) {
-        let help_text = "←/→ or h/l: Switch interface | Space: Update | r: Reset history | q: Quit";
+        // 설정 파일에서 바꾼 단축키가 그대로 보이도록 키 이름으로 생성
+        let key = |c: char| KeyBindings::label(c);
+        let keys = &self.keys;
+        let help_text = tf(
+            Msg::HelpImproved,
+            &[
+                &key(keys.previous_interface),
+                &key(keys.next_interface),
+                &key(keys.zoom_in),
+                &key(keys.zoom_out),
+                &key(keys.refresh),
+                &key(keys.reset_history),
+                &key(keys.quit),
+            ],
+        );
         let help = Paragraph::new(help_text)
             .style(Style::default().fg(Color::Gray))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL));
+            .block(Block::default().borders(Borders::ALL).title(tf(
+                Msg::FilterTitle,
+                &[&self.filter.summary_or_default(), &keys.filter_hint()],
+            )));
         f.render_widget(help, area);
     }
 }
@@ -0,0 +1,490 @@
+// 여러 호스트를 한 화면에 (nbmon dashboard)
+// 호스트마다 백그라운드 스레드가 에이전트(nbmon agent)에 붙어서 인터페이스별 속도와 최근 샘플을 공유 상태에 올리고,
+// 화면은 모든 호스트 × 인터페이스를 가장 바쁜 순서로 표에 표시
+// Enter로 고른 호스트를 ImprovedApp으로 열고, 그 화면을 닫으면 다시 표로 돌아옴
+
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame, Terminal,
+};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+use std::time::Duration;
+
+use crate::config::{Colors, HostConfig, KeyBindings};
+use crate::daemon::{self, agent, client::Client};
+use crate::export::push::Backoff;
+use crate::i18n::{t, tf, Msg};
+use crate::network::{filter::InterfaceFilter, sampler::Sampler};
+use crate::ui::{ImprovedApp, MonitorOptions};
+use crate::utils::{format, net};
+
+// 키 입력 폴링 간격 (밀리초)
+const POLL_INTERVAL_MS: u64 = 100;
+// 각 행에 표시할 스파크라인 길이
+const SPARKLINE_WIDTH: usize = 20;
+
+/// 대시보드가 연결할 호스트 (키 파일은 시작할 때 한 번 읽음)
+struct Host {
+    name: String,
+    address: String,
+    psk: Option<Vec<u8>>,
+}
+
+impl Host {
+    fn load(config: &HostConfig) -> Result<Self> {
+        Ok(Self {
+            name: config.display_name().to_string(),
+            address: net::with_default_port(&config.address, agent::DEFAULT_PORT),
+            psk: config
+                .psk_file
+                .as_deref()
+                .map(net::read_secret)
+                .transpose()?
+                .map(String::into_bytes),
+        })
+    }
+
+    // 에이전트에 붙어서 에이전트의 히스토리로 시작하는 Sampler
+    fn connect(&self) -> Result<Sampler> {
+        let client = Client::connect_tcp(&self.address, self.psk.as_deref())?;
+        daemon::client::attach(client, Ok)
+    }
+}
+
+/// 인터페이스 한 줄의 최신 값
+#[derive(Debug, Clone, Default)]
+pub struct InterfaceRow {
+    pub interface: String,
+    pub download: f64,
+    pub upload: f64,
+    /// 최근 SPARKLINE_WIDTH개 샘플
+    pub download_recent: Vec<u64>,
+    pub upload_recent: Vec<u64>,
+    /// 스파크라인 높이 기준 (히스토리의 최대 속도)
+    pub max_download: f64,
+    pub max_upload: f64,
+}
+
+impl InterfaceRow {
+    fn total(&self) -> f64 {
+        self.download + self.upload
+    }
+}
+
+/// 호스트 하나의 연결 상태 (작업 스레드가 갱신하고 화면이 읽음)
+#[derive(Debug, Clone)]
+pub enum HostStatus {
+    Connecting,
+    Connected(Vec<InterfaceRow>),
+    Failed(String),
+}
+
+/// 표의 한 줄 - 호스트 인덱스와 인터페이스 (연결되지 않은 호스트는 None으로 한 줄)
+pub type TableRow<'a> = (usize, Option<&'a InterfaceRow>);
+
+/// 모든 호스트의 인터페이스를 가장 바쁜(다운로드 + 업로드) 순서로
+/// 연결되지 않은 호스트는 상태를 보여주도록 맨 아래에 한 줄씩
+pub fn table_rows(statuses: &[HostStatus]) -> Vec<TableRow<'_>> {
+    let mut rows: Vec<TableRow> = statuses
+        .iter()
+        .enumerate()
+        .flat_map(|(host, status)| match status {
+            HostStatus::Connected(interfaces) => interfaces
+                .iter()
+                .map(|row| (host, Some(row)))
+                .collect::<Vec<_>>(),
+            _ => vec![(host, None)],
+        })
+        .collect();
+    // 안정 정렬이므로 같은 속도는 설정 파일의 호스트 순서, 인터페이스 순서 유지
+    rows.sort_by(|a, b| match (a.1, b.1) {
+        (Some(a), Some(b)) => b.total().total_cmp(&a.total()),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+    rows
+}
+
+// 필터를 통과한 인터페이스의 최신 값
+fn interface_rows(sampler: &Sampler, filter: &InterfaceFilter) -> Vec<InterfaceRow> {
+    filter
+        .visible_indices(sampler.interfaces())
+        .into_iter()
+        .map(|i| {
+            let bandwidth = sampler.all_bandwidth()[i].clone().unwrap_or_default();
+            let mut row = InterfaceRow {
+                interface: sampler.interfaces()[i].name.clone(),
+                download: bandwidth.download_rate,
+                upload: bandwidth.upload_rate,
+                ..Default::default()
+            };
+            if let Some(history) = sampler.history(i) {
+                row.download_recent = history.download().recent(SPARKLINE_WIDTH);
+                row.upload_recent = history.upload().recent(SPARKLINE_WIDTH);
+                row.max_download = history.max_download_rate();
+                row.max_upload = history.max_upload_rate();
+            }
+            row
+        })
+        .collect()
+}
+
+// 작업 스레드 - 연결이 끊기면 점점 길게 기다렸다가 다시 연결
+fn watch(
+    host: Arc<Host>,
+    filter: InterfaceFilter,
+    interval: Duration,
+    status: Arc<Mutex<HostStatus>>,
+) {
+    let set = |value: HostStatus| *status.lock().unwrap_or_else(PoisonError::into_inner) = value;
+    let mut backoff = Backoff::default();
+    loop {
+        let result = host.connect().and_then(|mut sampler| -> Result<()> {
+            backoff.reset();
+            loop {
+                set(HostStatus::Connected(interface_rows(&sampler, &filter)));
+                thread::sleep(interval);
+                sampler.update()?;
+            }
+        });
+        if let Err(e) = result {
+            set(HostStatus::Failed(format!("{:#}", e)));
+        }
+        thread::sleep(backoff.next_delay());
+    }
+}
+
+/// 여러 호스트 대시보드
+pub struct Dashboard {
+    hosts: Vec<Arc<Host>>,
+    statuses: Vec<Arc<Mutex<HostStatus>>>,
+    options: MonitorOptions,
+    colors: Colors,
+    keys: KeyBindings,
+    /// 선택한 줄 (호스트, 인터페이스) - 순서가 바뀌어도 같은 줄을 따라감
+    selected: Option<(usize, Option<String>)>,
+    /// 호스트를 열지 못했을 때 도움말 대신 표시할 에러
+    message: Option<String>,
+}
+
+impl Dashboard {
+    /// 키 파일을 읽고 호스트마다 작업 스레드 시작
+    pub fn new(hosts: &[HostConfig], options: &MonitorOptions) -> Result<Self> {
+        let hosts = hosts
+            .iter()
+            .map(|config| Host::load(config).map(Arc::new))
+            .collect::<Result<Vec<_>>>()?;
+        let statuses: Vec<_> = hosts
+            .iter()
+            .map(|host| {
+                let status = Arc::new(Mutex::new(HostStatus::Connecting));
+                let (host, shared) = (Arc::clone(host), Arc::clone(&status));
+                let (filter, interval) = (options.filters.clone(), options.interval);
+                thread::spawn(move || watch(host, filter, interval, shared));
+                status
+            })
+            .collect();
+
+        Ok(Self {
+            hosts,
+            statuses,
+            options: options.clone(),
+            colors: options.colors.clone(),
+            keys: options.keys,
+            selected: None,
+            message: None,
+        })
+    }
+
+    pub fn run<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<()> {
+        loop {
+            terminal.draw(|f| self.ui(f))?;
+
+            if !event::poll(Duration::from_millis(POLL_INTERVAL_MS))? {
+                continue;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            self.message = None;
+            // 설정 파일의 [keys]에서 바꾼 문자 키도 화살표 키와 같이 동작
+            let keys = self.keys;
+            match key.code {
+                KeyCode::Char(c) if c == keys.quit => break,
+                KeyCode::Up => self.move_selection(-1),
+                KeyCode::Char(c) if c == keys.previous_interface => self.move_selection(-1),
+                KeyCode::Down => self.move_selection(1),
+                KeyCode::Char(c) if c == keys.next_interface => self.move_selection(1),
+                KeyCode::Enter => self.open_selected(terminal)?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    // 현재 상태의 복사본 (화면을 그리는 동안 작업 스레드를 막지 않도록)
+    fn statuses(&self) -> Vec<HostStatus> {
+        self.statuses
+            .iter()
+            .map(|status| {
+                status
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .clone()
+            })
+            .collect()
+    }
+
+    // 선택한 줄의 현재 위치 (없어졌으면 첫 줄)
+    fn selected_index(&self, rows: &[TableRow]) -> usize {
+        let Some((host, interface)) = &self.selected else {
+            return 0;
+        };
+        rows.iter()
+            .position(|(h, row)| h == host && row.map(|row| &row.interface) == interface.as_ref())
+            .unwrap_or(0)
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let statuses = self.statuses();
+        let rows = table_rows(&statuses);
+        if rows.is_empty() {
+            return;
+        }
+        let index = self
+            .selected_index(&rows)
+            .saturating_add_signed(delta)
+            .min(rows.len() - 1);
+        let (host, row) = rows[index];
+        self.selected = Some((host, row.map(|row| row.interface.clone())));
+    }
+
+    // 선택한 호스트를 새 연결로 열어서 ImprovedApp 실행 (종료 키로 대시보드로 돌아옴)
+    fn open_selected<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<()> {
+        let statuses = self.statuses();
+        let rows = table_rows(&statuses);
+        let Some(&(host, row)) = rows.get(self.selected_index(&rows)) else {
+            return Ok(());
+        };
+        let host = Arc::clone(&self.hosts[host]);
+        let mut options = self.options.clone();
+        options.interface = row.map(|row| row.interface.clone());
+
+        // 두 화면이 서로 남긴 칸이 섞이지 않도록 전환할 때마다 전체를 다시 그림
+        terminal.clear()?;
+        let result = host.connect().and_then(|sampler| {
+            ImprovedApp::with_sampler(sampler, &options)?
+                .without_recorder()
+                .with_host(&host.name)
+                .run(terminal)
+        });
+        if let Err(e) = result {
+            self.message = Some(format!("{}: {:#}", host.name, e));
+        }
+        terminal.clear()?;
+        Ok(())
+    }
+
+    fn ui(&self, f: &mut Frame) {
+        let statuses = self.statuses();
+        let rows = table_rows(&statuses);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3), // 요약
+                Constraint::Min(5),    // 호스트 × 인터페이스 표
+                Constraint::Length(3), // 도움말
+            ])
+            .split(f.size());
+
+        self.render_summary(f, chunks[0], &statuses, &rows);
+        self.render_table(f, chunks[1], &statuses, &rows);
+        self.render_help(f, chunks[2], &statuses, &rows);
+    }
+
+    // 연결된 호스트 수와 전체 합계 속도
+    fn render_summary(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        statuses: &[HostStatus],
+        rows: &[TableRow],
+    ) {
+        let connected = statuses
+            .iter()
+            .filter(|status| matches!(status, HostStatus::Connected(_)))
+            .count();
+        let (download, upload) = rows
+            .iter()
+            .filter_map(|(_, row)| *row)
+            .fold((0.0, 0.0), |(d, u), row| (d + row.download, u + row.upload));
+
+        let line = Line::from(vec![
+            Span::raw(tf(Msg::DashboardHosts, &[&connected, &self.hosts.len()])),
+            Span::raw("   "),
+            Span::styled(
+                format!("↓ {}", format::format_bytes_per_sec(download)),
+                Style::default().fg(self.colors.download),
+            ),
+            Span::raw("  "),
+            Span::styled(
+                format!("↑ {}", format::format_bytes_per_sec(upload)),
+                Style::default().fg(self.colors.upload),
+            ),
+        ]);
+        let summary = Paragraph::new(line).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(t(Msg::TitleDashboard))
+                .style(Style::default().fg(self.colors.title)),
+        );
+        f.render_widget(summary, area);
+    }
+
+    fn render_table(&self, f: &mut Frame, area: Rect, statuses: &[HostStatus], rows: &[TableRow]) {
+        let table_rows = rows.iter().map(|&(host, row)| {
+            let name = Cell::from(self.hosts[host].name.as_str());
+            let Some(row) = row else {
+                let (text, color) = match &statuses[host] {
+                    HostStatus::Failed(_) => (t(Msg::DashboardUnreachable), Color::Red),
+                    _ => (t(Msg::DashboardConnecting), Color::Gray),
+                };
+                return Row::new(vec![
+                    name,
+                    Cell::from(text).style(Style::default().fg(color)),
+                ]);
+            };
+            let download = Style::default().fg(self.colors.download);
+            let upload = Style::default().fg(self.colors.upload);
+            Row::new(vec![
+                name,
+                Cell::from(row.interface.as_str()),
+                Cell::from(format::format_bytes_per_sec(row.download)).style(download),
+                Cell::from(format::format_bytes_per_sec(row.upload)).style(upload),
+                Cell::from(format::format_sparkline(
+                    &row.download_recent,
+                    row.max_download as u64,
+                ))
+                .style(download),
+                Cell::from(format::format_sparkline(
+                    &row.upload_recent,
+                    row.max_upload as u64,
+                ))
+                .style(upload),
+            ])
+        });
+
+        let header = Row::new(vec![
+            t(Msg::ColumnHost),
+            t(Msg::ColumnInterface),
+            t(Msg::Download),
+            t(Msg::Upload),
+            "",
+            "",
+        ])
+        .style(
+            Style::default()
+                .fg(self.colors.label)
+                .add_modifier(Modifier::BOLD),
+        );
+        let widths = [
+            Constraint::Length(16),
+            Constraint::Length(16),
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Length(SPARKLINE_WIDTH as u16),
+            Constraint::Length(SPARKLINE_WIDTH as u16),
+        ];
+        let table = Table::new(table_rows, widths)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL))
+            .highlight_style(Style::default().bg(Color::DarkGray))
+            .highlight_symbol("> ");
+
+        let mut state = TableState::default();
+        if !rows.is_empty() {
+            state.select(Some(self.selected_index(rows)));
+        }
+        f.render_stateful_widget(table, area, &mut state);
+    }
+
+    // 도움말 (호스트를 열지 못했거나 연결에 실패한 호스트를 선택했으면 그 에러)
+    fn render_help(&self, f: &mut Frame, area: Rect, statuses: &[HostStatus], rows: &[TableRow]) {
+        let failed =
+            rows.get(self.selected_index(rows))
+                .and_then(|&(host, _)| match &statuses[host] {
+                    HostStatus::Failed(error) => {
+                        Some(format!("{}: {}", self.hosts[host].name, error))
+                    }
+                    _ => None,
+                });
+        let (text, color) = match self.message.clone().or(failed) {
+            Some(message) => (message, Color::Red),
+            None => (
+                tf(
+                    Msg::HelpDashboard,
+                    &[
+                        &KeyBindings::label(self.keys.previous_interface),
+                        &KeyBindings::label(self.keys.next_interface),
+                        &KeyBindings::label(self.keys.quit),
+                    ],
+                ),
+                Color::Gray,
+            ),
+        };
+        let help = Paragraph::new(text)
+            .style(Style::default().fg(color))
+            .block(Block::default().borders(Borders::ALL));
+        f.render_widget(help, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(interface: &str, download: f64, upload: f64) -> InterfaceRow {
+        InterfaceRow {
+            interface: interface.to_string(),
+            download,
+            upload,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rows_sorted_by_busiest() {
+        let statuses = [
+            HostStatus::Connected(vec![row("eth0", 10.0, 5.0), row("eth1", 0.0, 0.0)]),
+            HostStatus::Failed("connection refused".to_string()),
+            HostStatus::Connected(vec![row("eth0", 100.0, 0.0), row("wg0", 0.0, 0.0)]),
+        ];
+        let order: Vec<(usize, Option<&str>)> = table_rows(&statuses)
+            .into_iter()
+            .map(|(host, row)| (host, row.map(|row| row.interface.as_str())))
+            .collect();
+        assert_eq!(
+            order,
+            [
+                (2, Some("eth0")),
+                (0, Some("eth0")),
+                (0, Some("eth1")),
+                (2, Some("wg0")),
+                (1, None),
+            ]
+        );
+    }
+}
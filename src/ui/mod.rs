@@ -3,9 +3,13 @@
 
 pub mod app; // 기본 TUI 애플리케이션 구현 (app.rs)
 pub mod app_improved; // 향상된 TUI 애플리케이션 구현 (app_improved.rs)
+pub mod dashboard; // 여러 호스트의 에이전트를 한 표로 보는 대시보드 (dashboard.rs)
 pub mod layout; // 화면 레이아웃 관련 유틸리티 (layout.rs)
+pub mod options; // 업데이트 간격, 히스토리 크기 등 실행 옵션 (options.rs)
 pub mod widgets; // 커스텀 위젯들 (widgets/ 디렉토리)
 
 // TUI 애플리케이션 구조체들을 외부에서 쉽게 사용할 수 있도록 re-export
 pub use app::App; // 기본 TUI 애플리케이션
 pub use app_improved::ImprovedApp; // 향상된 TUI 애플리케이션 (기본값)
+pub use dashboard::Dashboard; // 여러 호스트 대시보드
+pub use options::MonitorOptions; // 실행 옵션
@@ -0,0 +1,41 @@
+// TUI와 콘솔 모드가 공통으로 사용하는 실행 옵션
+// 설정 파일(config.toml)과 커맨드라인(--interval, --history, --interface, --include 등)에서 채워져 각 애플리케이션에 전달됨
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::config::{Colors, KeyBindings};
+use crate::network::filter::InterfaceFilter;
+
+/// 모니터링 실행 옵션
+#[derive(Debug, Clone)]
+pub struct MonitorOptions {
+    /// 통계 업데이트 간격
+    pub interval: Duration,
+    /// 인터페이스마다 보관할 원본 샘플 수 (None이면 화면마다의 기본값)
+    pub history: Option<usize>,
+    /// 처음 선택할 인터페이스 이름
+    pub interface: Option<String>,
+    /// 표시할 인터페이스 필터 (이름 패턴과 종류)
+    pub filters: InterfaceFilter,
+    /// 화면 색상
+    pub colors: Colors,
+    /// 단축키
+    pub keys: KeyBindings,
+    /// 직접 샘플링하지 않고 붙을 데몬의 소켓 (--attach)
+    pub attach: Option<PathBuf>,
+}
+
+impl Default for MonitorOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            history: None,
+            interface: None,
+            filters: InterfaceFilter::default(),
+            colors: Colors::default(),
+            keys: KeyBindings::default(),
+            attach: None,
+        }
+    }
+}
@@ -1,46 +1,321 @@
 // 데이터 크기와 속도를 사용자 친화적인 문자열로 포맷팅하는 유틸리티 함수들
-use bytesize::ByteSize; // 바이트 크기를 사람이 읽기 쉬운 형태로 변환해주는 외부 라이브러리
+// 모든 화면과 출력은 프로그램 시작 시 한 번 정한 단위 체계(UnitSystem)를 따름
+//
+//   단위:  bytes (B/s) 또는 bits (bit/s)
+//   접두사: SI (k, M, G - 1000 단위) 또는 IEC (Ki, Mi, Gi - 1024 단위)
+//   배율:  auto (값에 따라) 또는 고정 (예: 항상 Mbit/s)
+use once_cell::sync::OnceCell;
+use serde::{de, Deserialize, Deserializer};
 
-// 바이트 수를 사람이 읽기 쉬운 형태로 포맷팅 (예: 1024 → "1.0 KB")
-pub fn format_bytes(bytes: u64) -> String {
-    // ByteSize::b(): u64 바이트 값으로 ByteSize 인스턴스 생성
-    // to_string_as(true): true는 이진 단위(1024) 사용을 의미
-    ByteSize::b(bytes).to_string_as(true)
+/// 속도 표시 단위 (--units)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Units {
+    /// 바이트/초 (KiB/s, MiB/s, ...)
+    #[default]
+    Bytes,
+    /// 비트/초 (kbit/s, Mbit/s, ...)
+    Bits,
 }
 
-// 초당 바이트 수를 포맷팅 (예: 1536.0 → "1.5 KB/s")
-pub fn format_bytes_per_sec(bytes_per_sec: f64) -> String {
-    // if-else if 체인: 값의 크기에 따라 적절한 단위 선택
-    if bytes_per_sec < 1024.0 {
+impl Units {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "bytes" | "b" => Some(Units::Bytes),
+            "bits" | "bit" => Some(Units::Bits),
+            _ => None,
+        }
+    }
+
+    // 값 뒤에 붙는 단위 기호
+    fn symbol(self) -> &'static str {
+        match self {
+            Units::Bytes => "B",
+            Units::Bits => "bit",
+        }
+    }
+}
+
+/// 단위 접두사 체계 (--prefix)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Prefix {
+    /// 1000 단위 (kB, MB, Mbit)
+    Si,
+    /// 1024 단위 (KiB, MiB, Mibit)
+    Iec,
+}
+
+impl Prefix {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "si" => Some(Prefix::Si),
+            "iec" => Some(Prefix::Iec),
+            _ => None,
+        }
+    }
+
+    fn base(self) -> f64 {
+        match self {
+            Prefix::Si => 1000.0,
+            Prefix::Iec => 1024.0,
+        }
+    }
+
+    // 지수별 접두사 기호 (0: 없음, 1: k/Ki, 2: M/Mi, ...)
+    fn symbol(self, exponent: usize) -> &'static str {
+        const SI: [&str; 6] = ["", "k", "M", "G", "T", "P"];
+        const IEC: [&str; 6] = ["", "Ki", "Mi", "Gi", "Ti", "Pi"];
+        match self {
+            Prefix::Si => SI[exponent],
+            Prefix::Iec => IEC[exponent],
+        }
+    }
+}
+
+// 접두사 지수의 최댓값 (P)
+const MAX_EXPONENT: usize = 5;
+
+/// 속도 표시 배율 (--scale) - 고정하면 모든 속도를 같은 접두사로 표시
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Scale {
+    /// 값의 크기에 따라 자동 선택
+    #[default]
+    Auto,
+    /// 고정 접두사 지수 (0: 없음, 1: k, 2: M, 3: G, 4: T)
+    Fixed(usize),
+}
+
+impl Scale {
+    /// auto 또는 접두사 글자 (1, k, M, G, T; 대소문자 무시)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "auto" => Some(Scale::Auto),
+            "1" | "none" => Some(Scale::Fixed(0)),
+            "k" => Some(Scale::Fixed(1)),
+            "m" => Some(Scale::Fixed(2)),
+            "g" => Some(Scale::Fixed(3)),
+            "t" => Some(Scale::Fixed(4)),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Scale {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Scale::parse(&value).ok_or_else(|| {
+            de::Error::custom(format!(
+                "invalid scale `{}` (use auto, 1, k, M, G or T)",
+                value
+            ))
+        })
+    }
+}
+
+/// 단위, 접두사, 배율을 묶은 단위 체계
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitSystem {
+    pub units: Units,
+    pub prefix: Prefix,
+    pub scale: Scale,
+}
+
+impl Default for UnitSystem {
+    fn default() -> Self {
+        Self::new(Units::Bytes, None, Scale::Auto)
+    }
+}
+
+impl UnitSystem {
+    /// 접두사를 정하지 않으면 관례를 따름 (바이트는 IEC, 비트는 SI)
+    pub fn new(units: Units, prefix: Option<Prefix>, scale: Scale) -> Self {
+        let prefix = prefix.unwrap_or(match units {
+            Units::Bytes => Prefix::Iec,
+            Units::Bits => Prefix::Si,
+        });
+        Self {
+            units,
+            prefix,
+            scale,
+        }
+    }
+
+    /// 지수에 해당하는 속도 단위 이름과 나눌 값 (예: 2 → ("Mbit/s", 125000.0))
+    /// 나눌 값은 바이트/초 기준
+    pub fn rate_unit(&self, exponent: usize) -> (String, f64) {
+        let label = format!("{}{}/s", self.prefix.symbol(exponent), self.units.symbol());
+        let mut divisor = self.prefix.base().powi(exponent as i32);
+        if self.units == Units::Bits {
+            divisor /= 8.0;
+        }
+        (label, divisor)
+    }
+
+    /// 초당 바이트 수를 현재 단위로 포맷팅 (예: 1536.0 → "1.5 KiB/s", 비트 SI면 "12.3 kbit/s")
+    pub fn format_rate(&self, bytes_per_sec: f64) -> String {
+        let exponent = match self.scale {
+            Scale::Fixed(exponent) => exponent,
+            Scale::Auto => {
+                let value = match self.units {
+                    Units::Bytes => bytes_per_sec,
+                    Units::Bits => bytes_per_sec * 8.0,
+                };
+                auto_exponent(value, self.prefix)
+            }
+        };
+        let (label, divisor) = self.rate_unit(exponent);
         // {:.1}: 소수점 첫째자리까지 표시
-        format!("{:.1} B/s", bytes_per_sec)
-    } else if bytes_per_sec < 1024.0 * 1024.0 {
-        // 1 MB 미만
-        format!("{:.1} KB/s", bytes_per_sec / 1024.0)
-    } else if bytes_per_sec < 1024.0 * 1024.0 * 1024.0 {
-        // 1 GB 미만
-        format!("{:.1} MB/s", bytes_per_sec / (1024.0 * 1024.0))
-    } else {
-        // 1 GB 이상
-        format!("{:.1} GB/s", bytes_per_sec / (1024.0 * 1024.0 * 1024.0))
+        format!("{:.1} {}", bytes_per_sec / divisor, label)
     }
+
+    /// 데이터 양(누적 바이트)을 포맷팅 - 양은 항상 바이트, 접두사만 따름 (예: 1024 → "1.0 KiB")
+    pub fn format_size(&self, bytes: u64) -> String {
+        let exponent = auto_exponent(bytes as f64, self.prefix);
+        let value = bytes as f64 / self.prefix.base().powi(exponent as i32);
+        if exponent == 0 {
+            format!("{} B", bytes)
+        } else {
+            format!("{:.1} {}B", value, self.prefix.symbol(exponent))
+        }
+    }
+}
+
+// 값이 1 이상이 되는 가장 큰 접두사 지수
+fn auto_exponent(value: f64, prefix: Prefix) -> usize {
+    let mut exponent = 0;
+    while exponent < MAX_EXPONENT && value >= prefix.base().powi(exponent as i32 + 1) {
+        exponent += 1;
+    }
+    exponent
+}
+
+// 프로그램 시작 시 한 번 정한 단위 체계 (정하지 않으면 바이트, IEC, 자동 배율)
+static UNITS: OnceCell<UnitSystem> = OnceCell::new();
+
+/// 단위 체계를 설정 (첫 호출만 적용됨)
+pub fn set_units(units: UnitSystem) {
+    let _ = UNITS.set(units);
+}
+
+/// 현재 단위 체계
+pub fn units() -> UnitSystem {
+    UNITS.get().copied().unwrap_or_default()
 }
 
-// 초당 비트 수를 포맷팅 - 네트워크 속도 표시용 (예: 1000000 → "1.0 Mbps")
-// 비트 단위는 십진법(1000) 사용이 일반적
+// 바이트 수를 사람이 읽기 쉬운 형태로 포맷팅 (예: 1024 → "1.0 KiB")
+pub fn format_bytes(bytes: u64) -> String {
+    units().format_size(bytes)
+}
+
+// 초당 바이트 수를 현재 단위 체계로 포맷팅 (예: 1536.0 → "1.5 KiB/s")
+pub fn format_bytes_per_sec(bytes_per_sec: f64) -> String {
+    units().format_rate(bytes_per_sec)
+}
+
+// 링크 속도 포맷팅 (예: 1000000000 → "1.0 Gbit/s")
+// 링크 속도는 단위 설정과 관계없이 관례대로 비트, SI 접두사로 표시
 pub fn format_bits_per_sec(bits_per_sec: u64) -> String {
-    if bits_per_sec < 1000 {
-        format!("{} bps", bits_per_sec) // bits per second
-    } else if bits_per_sec < 1_000_000 {
-        // 1M 미만
-        // as f64: u64를 f64로 타입 변환 (나눗셈을 위해)
-        format!("{:.1} Kbps", bits_per_sec as f64 / 1000.0)
-    } else if bits_per_sec < 1_000_000_000 {
-        // 1G 미만
-        // 1_000_000: 가독성을 위한 숫자 구분자 (1000000와 동일)
-        format!("{:.1} Mbps", bits_per_sec as f64 / 1_000_000.0)
-    } else {
-        // 1G 이상
-        format!("{:.1} Gbps", bits_per_sec as f64 / 1_000_000_000.0)
+    UnitSystem::new(Units::Bits, Some(Prefix::Si), Scale::Auto)
+        .format_rate(bits_per_sec as f64 / 8.0)
+}
+
+// 스파크라인에 사용하는 유니코드 블록 문자 (낮음 → 높음)
+const SPARK_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+// 값 목록을 한 줄짜리 미니 스파크라인 문자열로 변환 (예: [0, 5, 10] → "▁▅█")
+// max: 가장 높은 블록에 해당하는 값 (0이면 모든 값을 가장 낮은 블록으로 표시)
+pub fn format_sparkline(values: &[u64], max: u64) -> String {
+    values
+        .iter()
+        .map(|&value| {
+            if max == 0 {
+                return SPARK_BARS[0];
+            }
+            // min(): max를 넘는 값은 가장 높은 블록으로 고정
+            let level = (value.min(max) as f64 / max as f64 * (SPARK_BARS.len() - 1) as f64).round()
+                as usize;
+            SPARK_BARS[level]
+        })
+        .collect()
+}
+
+// 사람이 입력한 크기 문자열을 바이트 수로 변환 (예: "1TB" → 10^12, "500GiB" → 500×2^30)
+// 접미사가 없으면 바이트, SI 접미사(K/KB, M/MB, ...)는 1000 단위, IEC 접미사(KiB, MiB, ...)는 1024 단위
+pub fn parse_bytes(input: &str) -> Option<u64> {
+    let input = input.trim();
+    // 숫자 부분과 단위 부분 분리
+    let split = input
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(input.len());
+    let (number, unit) = input.split_at(split);
+    let number: f64 = number.parse().ok()?;
+
+    let multiplier: f64 = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1.0,
+        "k" | "kb" => 1e3,
+        "m" | "mb" => 1e6,
+        "g" | "gb" => 1e9,
+        "t" | "tb" => 1e12,
+        "p" | "pb" => 1e15,
+        "kib" => 1024.0,
+        "mib" => 1024.0_f64.powi(2),
+        "gib" => 1024.0_f64.powi(3),
+        "tib" => 1024.0_f64.powi(4),
+        "pib" => 1024.0_f64.powi(5),
+        _ => return None,
+    };
+
+    Some((number * multiplier).round() as u64)
+}
+
+// 사람이 입력한 속도 문자열을 바이트/초로 변환 (예: "800Mbit" → 10^8, "100MB/s" → 10^8)
+// bit/bits/bps로 끝나면 비트, 아니면 parse_bytes와 같은 바이트 단위 ("/s"는 생략 가능)
+pub fn parse_rate(input: &str) -> Option<f64> {
+    let input = input.trim();
+    let input = input.strip_suffix("/s").unwrap_or(input);
+    let lower = input.to_ascii_lowercase();
+
+    let bits = ["bps", "bits", "bit"]
+        .iter()
+        .find_map(|suffix| lower.strip_suffix(suffix).map(|rest| &input[..rest.len()]));
+    match bits {
+        // "800M" + "B"처럼 바이트 단위로 읽은 뒤 8로 나눔
+        Some(number) => parse_bytes(&format!("{}B", number)).map(|bits| bits as f64 / 8.0),
+        None => parse_bytes(input).map(|bytes| bytes as f64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_systems() {
+        let bytes = UnitSystem::default();
+        assert_eq!(bytes.format_rate(1536.0), "1.5 KiB/s");
+        assert_eq!(bytes.format_rate(12.0), "12.0 B/s");
+        assert_eq!(bytes.format_size(1024 * 1024), "1.0 MiB");
+        assert_eq!(bytes.format_size(512), "512 B");
+
+        let bits = UnitSystem::new(Units::Bits, None, Scale::Auto);
+        assert_eq!(bits.format_rate(125_000.0), "1.0 Mbit/s");
+        assert_eq!(bits.format_size(1_500_000), "1.5 MB");
+
+        let iec_bits = UnitSystem::new(Units::Bits, Some(Prefix::Iec), Scale::Auto);
+        assert_eq!(iec_bits.format_rate(128.0), "1.0 Kibit/s");
+
+        // 고정 배율은 값이 작거나 커도 같은 단위
+        let mbit = UnitSystem::new(Units::Bits, None, Scale::parse("M").unwrap());
+        assert_eq!(mbit.format_rate(1250.0), "0.0 Mbit/s");
+        assert_eq!(mbit.format_rate(1.25e9), "10000.0 Mbit/s");
+
+        assert_eq!(format_bits_per_sec(1_000_000_000), "1.0 Gbit/s");
+
+        assert_eq!(parse_rate("800Mbit"), Some(1e8));
+        assert_eq!(parse_rate("1Gbps"), Some(1.25e8));
+        assert_eq!(parse_rate("8Kibit/s"), Some(1024.0));
+        assert_eq!(parse_rate("100MB/s"), Some(1e8));
+        assert_eq!(parse_rate("fast"), None);
     }
 }
@@ -0,0 +1,26 @@
+// 호스트 이름 모듈
+// 여러 호스트의 메트릭을 한 곳으로 보내는 출력(Influx, Graphite 등)에서 호스트를 구분하는 데 사용
+
+/// 이 컴퓨터의 호스트 이름 (알 수 없으면 "localhost")
+pub fn hostname() -> String {
+    system_hostname()
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "localhost".to_string())
+}
+
+#[cfg(unix)]
+fn system_hostname() -> Option<String> {
+    // gethostname: 끝의 NUL까지 버퍼에 기록 (버퍼가 모자라면 잘릴 수 있으므로 넉넉하게)
+    let mut buffer = [0u8; 256];
+    let result = unsafe { libc::gethostname(buffer.as_mut_ptr().cast(), buffer.len()) };
+    if result != 0 {
+        return None;
+    }
+    let end = buffer.iter().position(|&b| b == 0).unwrap_or(buffer.len());
+    Some(String::from_utf8_lossy(&buffer[..end]).into_owned())
+}
+
+#[cfg(windows)]
+fn system_hostname() -> Option<String> {
+    std::env::var("COMPUTERNAME").ok()
+}
@@ -2,3 +2,6 @@
 // 애플리케이션 전반에서 사용되는 공통 기능들을 제공
 
 pub mod format; // 데이터 포맷팅 관련 함수들 (format.rs)
+pub mod host; // 호스트 이름 (host.rs)
+pub mod net; // 기본 포트를 붙인 주소와 키 파일 읽기 (net.rs)
+pub mod time; // 날짜/시간 계산 (time.rs)
@@ -0,0 +1,47 @@
+// 원격 연결에 쓰는 공통 함수 - 기본 포트를 붙인 주소와 키/비밀번호 파일 읽기
+// 에이전트(tui --remote, dashboard), SNMP 에이전트, MQTT 브로커가 같은 규칙을 따르도록 한 곳에 모음
+
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// host 또는 host:port에 포트가 없으면 기본 포트를 붙인 주소
+/// ([::1]처럼 대괄호로 감싼 IPv6 주소 고려)
+pub fn with_default_port(value: &str, port: u16) -> String {
+    let has_port = match value.rsplit_once(':') {
+        Some((host, port)) => {
+            port.parse::<u16>().is_ok() && (!host.contains(':') || host.ends_with(']'))
+        }
+        None => false,
+    };
+    if has_port {
+        value.to_string()
+    } else {
+        format!("{}:{}", value, port)
+    }
+}
+
+/// 키/비밀번호 파일 읽기 (앞뒤 공백과 줄바꿈 제외, 비어 있으면 에러)
+/// 명령줄이나 설정 파일에 비밀 값을 남기지 않기 위해 파일로 받음
+pub fn read_secret(path: &Path) -> Result<String> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let secret = contents.trim();
+    if secret.is_empty() {
+        bail!("key file {} is empty", path.display());
+    }
+    Ok(secret.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_default_port() {
+        assert_eq!(with_default_port("router", 9960), "router:9960");
+        assert_eq!(with_default_port("10.0.0.1:7000", 9960), "10.0.0.1:7000");
+        assert_eq!(with_default_port("[::1]", 161), "[::1]:161");
+        assert_eq!(with_default_port("[::1]:1161", 161), "[::1]:1161");
+    }
+}
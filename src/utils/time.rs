@@ -0,0 +1,278 @@
+// 날짜/시간 계산 유틸리티
+// 외부 날짜 라이브러리 없이 Unix 시간과 달력 날짜(연-월-일 시:분:초)를 변환
+// 로컬 시간대 오프셋은 운영체제 API로 조회
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 현재 Unix 시간 (초)
+pub fn now_unix() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// 달력 날짜와 시각 (시간대 적용 후의 값)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DateTime {
+    pub year: i32,
+    pub month: u32, // 1-12
+    pub day: u32,   // 1-31
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+impl DateTime {
+    /// Unix 시간을 주어진 UTC 오프셋(초)의 달력 시간으로 변환
+    pub fn from_unix(unix: i64, offset_secs: i64) -> Self {
+        let local = unix + offset_secs;
+        // div_euclid/rem_euclid: 음수(1970년 이전)에서도 올바른 내림 나눗셈
+        let days = local.div_euclid(86_400);
+        let secs_of_day = local.rem_euclid(86_400);
+        let (year, month, day) = civil_from_days(days);
+
+        Self {
+            year,
+            month,
+            day,
+            hour: (secs_of_day / 3_600) as u32,
+            minute: (secs_of_day % 3_600 / 60) as u32,
+            second: (secs_of_day % 60) as u32,
+        }
+    }
+
+    /// Unix 시간을 로컬 시간대의 달력 시간으로 변환
+    pub fn local(unix: i64) -> Self {
+        Self::from_unix(unix, local_offset_secs(unix))
+    }
+
+    /// 달력 시간을 주어진 UTC 오프셋(초) 기준으로 Unix 시간으로 변환
+    pub fn to_unix(&self, offset_secs: i64) -> i64 {
+        let days = days_from_civil(self.year, self.month, self.day);
+        days * 86_400 + self.hour as i64 * 3_600 + self.minute as i64 * 60 + self.second as i64
+            - offset_secs
+    }
+
+    /// 로컬 시간대 기준으로 Unix 시간으로 변환
+    pub fn to_unix_local(&self) -> i64 {
+        // 먼저 UTC로 가정해 계산한 뒤 그 시점의 오프셋을 적용
+        let guess = self.to_unix(0);
+        self.to_unix(local_offset_secs(guess))
+    }
+
+    /// 시간 단위 집계 키 (예: "2025-08-23 14:00")
+    pub fn hour_key(&self) -> String {
+        format!("{} {:02}:00", self.day_key(), self.hour)
+    }
+
+    /// 일 단위 집계 키 (예: "2025-08-23")
+    pub fn day_key(&self) -> String {
+        format!("{}-{:02}", self.month_key(), self.day)
+    }
+
+    /// 월 단위 집계 키 (예: "2025-08")
+    pub fn month_key(&self) -> String {
+        format!("{:04}-{:02}", self.year, self.month)
+    }
+
+    /// "HH:MM:SS" 형식의 시각 문자열
+    pub fn time_string(&self) -> String {
+        format!("{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.day_key(), self.time_string())
+    }
+}
+
+/// 기간 문자열을 초로 변환 (예: "90s", "5m", "2h", "7d", "1w", 단위가 없으면 초)
+pub fn parse_duration(input: &str) -> Option<i64> {
+    let input = input.trim();
+    let split = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    let (number, unit) = input.split_at(split);
+    let number: i64 = number.parse().ok()?;
+
+    let multiplier = match unit {
+        "" | "s" => 1,
+        "m" | "min" => 60,
+        "h" => 3_600,
+        "d" => 86_400,
+        "w" => 7 * 86_400,
+        _ => return None,
+    };
+    number.checked_mul(multiplier)
+}
+
+/// 시각 지정 문자열을 Unix 시간으로 변환
+/// - "now"
+/// - 기간: 지금으로부터 그만큼 전 (예: "2h")
+/// - "@1700000000": Unix 시간
+/// - "YYYY-MM-DD", "YYYY-MM-DD HH:MM[:SS]", "YYYY-MM-DDTHH:MM[:SS]": 로컬 시간
+/// - "HH:MM[:SS]": 오늘의 로컬 시간
+pub fn parse_time_spec(input: &str, now: i64) -> Option<i64> {
+    let input = input.trim();
+    if input == "now" {
+        return Some(now);
+    }
+    if let Some(unix) = input.strip_prefix('@') {
+        return unix.parse().ok();
+    }
+    if let Some(duration) = parse_duration(input) {
+        return Some(now - duration);
+    }
+
+    let (date, clock) = match input.split_once(['T', ' ']) {
+        Some((date, clock)) => (Some(date), Some(clock)),
+        None if input.contains(':') => (None, Some(input)),
+        None => (Some(input), None),
+    };
+
+    let mut dt = match date {
+        Some(date) => {
+            let mut parts = date.split('-');
+            let dt = DateTime {
+                year: parts.next()?.parse().ok()?,
+                month: parts.next()?.parse().ok()?,
+                day: parts.next()?.parse().ok()?,
+                hour: 0,
+                minute: 0,
+                second: 0,
+            };
+            if parts.next().is_some()
+                || !(1..=12).contains(&dt.month)
+                || !(1..=days_in_month(dt.year, dt.month)).contains(&dt.day)
+            {
+                return None;
+            }
+            dt
+        }
+        None => DateTime::local(now),
+    };
+
+    (dt.hour, dt.minute, dt.second) = (0, 0, 0);
+    if let Some(clock) = clock {
+        let mut parts = clock.split(':');
+        dt.hour = parts.next()?.parse().ok()?;
+        dt.minute = parts.next()?.parse().ok()?;
+        dt.second = parts.next().map_or(Some(0), |second| second.parse().ok())?;
+        if parts.next().is_some() || dt.hour > 23 || dt.minute > 59 || dt.second > 59 {
+            return None;
+        }
+    }
+    Some(dt.to_unix_local())
+}
+
+/// 해당 월의 일 수 (윤년 고려)
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        _ => {
+            let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+            if leap {
+                29
+            } else {
+                28
+            }
+        }
+    }
+}
+
+// 1970-01-01부터의 일 수 → (연, 월, 일)
+// Howard Hinnant의 civil_from_days 알고리즘
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = (yoe + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+    (year, month, day)
+}
+
+// (연, 월, 일) → 1970-01-01부터의 일 수
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year } as i64;
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// 주어진 시점의 로컬 시간대 UTC 오프셋 (초)
+#[cfg(unix)]
+pub fn local_offset_secs(unix: i64) -> i64 {
+    // localtime_r: 스레드 안전한 로컬 시간 변환, tm_gmtoff에 오프셋이 담김
+    let time = unix as libc::time_t;
+    let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+    let result = unsafe { libc::localtime_r(&time, &mut tm) };
+    if result.is_null() {
+        0
+    } else {
+        tm.tm_gmtoff as i64
+    }
+}
+
+/// 주어진 시점의 로컬 시간대 UTC 오프셋 (초)
+/// Windows는 현재 시간대 설정(일광 절약 시간 포함)을 기준으로 함
+#[cfg(windows)]
+pub fn local_offset_secs(_unix: i64) -> i64 {
+    use winapi::um::timezoneapi::{GetTimeZoneInformation, TIME_ZONE_INFORMATION};
+
+    const TIME_ZONE_ID_DAYLIGHT: u32 = 2;
+
+    unsafe {
+        let mut info: TIME_ZONE_INFORMATION = std::mem::zeroed();
+        let id = GetTimeZoneInformation(&mut info);
+        // Bias는 "UTC = 로컬 + Bias" (분 단위)
+        let mut bias = info.Bias;
+        if id == TIME_ZONE_ID_DAYLIGHT {
+            bias += info.DaylightBias;
+        }
+        -(bias as i64) * 60
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        // 2024-02-29 23:59:30 UTC (윤년)
+        let unix = 1_709_251_170;
+        let dt = DateTime::from_unix(unix, 0);
+        assert_eq!((dt.year, dt.month, dt.day), (2024, 2, 29));
+        assert_eq!(dt.time_string(), "23:59:30");
+        assert_eq!(dt.to_unix(0), unix);
+
+        // +09:00 시간대에서는 다음 날
+        let kst = DateTime::from_unix(unix, 9 * 3_600);
+        assert_eq!(kst.day_key(), "2024-03-01");
+        assert_eq!(kst.to_unix(9 * 3_600), unix);
+    }
+
+    #[test]
+    fn test_parse_time_spec() {
+        let now = 1_700_000_000;
+        assert_eq!(parse_duration("5m"), Some(300));
+        assert_eq!(parse_duration("5x"), None);
+        assert_eq!(parse_time_spec("now", now), Some(now));
+        assert_eq!(parse_time_spec("2h", now), Some(now - 7_200));
+        assert_eq!(parse_time_spec("@1600000000", now), Some(1_600_000_000));
+
+        let at = parse_time_spec("2024-03-01 02:30", now).unwrap();
+        assert_eq!(DateTime::local(at).to_string(), "2024-03-01 02:30:00");
+        assert_eq!(parse_time_spec("2024-02-30", now), None);
+    }
+}